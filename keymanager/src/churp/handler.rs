@@ -634,7 +634,7 @@ impl<S: Suite> Instance<S> {
             }
 
             let vm = VerificationMatrix::from_bytes(&vm)
-                .ok_or(Error::VerificationMatrixDecodingFailed)?;
+                .map_err(|_| Error::VerificationMatrixDecodingFailed)?;
             handoff.set_verification_matrix(vm)?;
         }
 
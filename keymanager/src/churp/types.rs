@@ -188,7 +188,7 @@ where
     fn try_from(encoded: EncodedVerifiableSecretShare) -> Result<Self, Self::Error> {
         let share = encoded.share.try_into()?;
         let vm = VerificationMatrix::from_bytes(&encoded.verification_matrix)
-            .ok_or(Error::VerificationMatrixDecodingFailed)?;
+            .map_err(|_| Error::VerificationMatrixDecodingFailed)?;
         let verifiable_share = VerifiableSecretShare::new(share, vm);
         Ok(verifiable_share)
     }
@@ -0,0 +1,128 @@
+//! Encodings of shareholder IDs to abscissae.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use anyhow::Result;
+use group::ff::PrimeField;
+
+use crate::suites::FieldDigest;
+
+use super::encode_shareholder;
+
+/// Encoding of shareholder IDs to abscissae, i.e. the x-coordinates
+/// at which shares are evaluated.
+///
+/// Encodings must map distinct IDs to distinct non-zero field elements,
+/// as the zero abscissa holds the shared secret. All members of a committee
+/// must agree on the encoding.
+pub trait AbscissaEncoding<F: PrimeField> {
+    /// Encodes the given shareholder ID to an abscissa.
+    fn abscissa(&self, id: &[u8]) -> Result<F>;
+}
+
+/// Encoding that hashes shareholder IDs to the prime field using
+/// the given domain separation tag.
+///
+/// This is the encoding used by [`encode_shareholder`].
+pub struct HashAbscissa<H: FieldDigest> {
+    dst: Vec<u8>,
+    _digest: PhantomData<H>,
+}
+
+impl<H> HashAbscissa<H>
+where
+    H: FieldDigest,
+{
+    /// Creates a new encoding with the given domain separation tag.
+    pub fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: dst.to_vec(),
+            _digest: PhantomData,
+        }
+    }
+}
+
+impl<H> AbscissaEncoding<H::Output> for HashAbscissa<H>
+where
+    H: FieldDigest,
+{
+    fn abscissa(&self, id: &[u8]) -> Result<H::Output> {
+        encode_shareholder::<H>(id, &self.dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, encode_shareholder, Error, HandoffKind, Player},
+        suites::{self, p384},
+    };
+
+    use super::{AbscissaEncoding, HashAbscissa};
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+
+    /// Encoding that interprets IDs as big-endian one-based indices.
+    struct IndexAbscissa;
+
+    impl AbscissaEncoding<PrimeField> for IndexAbscissa {
+        fn abscissa(&self, id: &[u8]) -> Result<PrimeField> {
+            let index = id.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+            if index == 0 {
+                return Err(Error::ZeroValueShareholder.into());
+            }
+            Ok(PrimeField::from_u64(index))
+        }
+    }
+
+    fn recover_secret(enc: &impl AbscissaEncoding<PrimeField>) -> PrimeField {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+
+        let shares: Vec<_> = (1..=3u8)
+            .map(|i| enc.abscissa(&[0, i]).unwrap())
+            .map(|x| dealer.make_share(x, kind))
+            .collect();
+
+        Player::new(threshold, kind)
+            .recover_secret(&shares)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hash_abscissa() {
+        let enc = HashAbscissa::<Suite>::new(b"shareholder");
+        let id = [1u8; 32];
+        let x = enc.abscissa(&id).unwrap();
+        assert_eq!(x, encode_shareholder::<Suite>(&id, b"shareholder").unwrap());
+        assert_ne!(x, enc.abscissa(&[2u8; 32]).unwrap());
+
+        let other = HashAbscissa::<Suite>::new(b"other");
+        assert_ne!(x, other.abscissa(&id).unwrap());
+    }
+
+    #[test]
+    fn test_reconstruction() {
+        let secret = PrimeField::from_u64(100);
+        assert_eq!(recover_secret(&HashAbscissa::<Suite>::new(b"dst")), secret);
+        assert_eq!(recover_secret(&IndexAbscissa), secret);
+
+        // Zero abscissae are rejected.
+        let res = IndexAbscissa.abscissa(&[0, 0]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ZeroValueShareholder.to_string()
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Additive secret sharing.
+
+use alloc::vec::Vec;
+use core::ptr;
+
+use anyhow::Result;
+use group::{ff::Field, Group};
+use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::poly::{scalar_from_bytes, scalar_to_bytes};
+
+use super::Error;
+
+/// Additive share of a secret.
+///
+/// The secret is the sum of all shares, so every share is required
+/// to recover it, and any proper subset of the shares reveals nothing
+/// about it. This makes additive sharing a lightweight alternative
+/// to polynomial sharing for tiny committees, where the threshold
+/// equals the size of the committee anyway.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AdditiveShare<G: Group> {
+    /// The value of the share.
+    value: G::Scalar,
+}
+
+impl<G> AdditiveShare<G>
+where
+    G: Group,
+{
+    /// Creates a new additive share with the given value.
+    pub fn new(value: G::Scalar) -> Self {
+        Self { value }
+    }
+
+    /// Splits the given secret into the given number of additive shares.
+    ///
+    /// All but the last share are random, and the last share makes
+    /// the shares sum up to the secret. No shares are returned
+    /// if the number of shares is zero.
+    pub fn split<R: RngCore>(secret: &G::Scalar, n: usize, rng: &mut R) -> Vec<Self> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut shares = Vec::with_capacity(n);
+        let mut last = *secret;
+        for _ in 1..n {
+            let value = G::Scalar::random(&mut *rng);
+            last -= value;
+            shares.push(Self::new(value));
+        }
+        shares.push(Self::new(last));
+
+        shares
+    }
+
+    /// Recovers the secret from the given shares, i.e. returns their sum.
+    ///
+    /// The result is the secret only if all shares are given.
+    pub fn combine(shares: &[Self]) -> G::Scalar {
+        shares
+            .iter()
+            .fold(G::Scalar::ZERO, |acc, share| acc + share.value)
+    }
+
+    /// Returns the value of the share.
+    pub fn value(&self) -> &G::Scalar {
+        &self.value
+    }
+
+    /// Returns the byte representation of the share.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        scalar_to_bytes(&self.value)
+    }
+
+    /// Attempts to create a share from its byte representation.
+    ///
+    /// This method is not constant time if the length of the slice is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let value = scalar_from_bytes(bytes).ok_or(Error::InvalidEncoding)?;
+
+        Ok(Self::new(value))
+    }
+}
+
+impl<G> ConstantTimeEq for AdditiveShare<G>
+where
+    G: Group,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.value.ct_eq(&other.value)
+    }
+}
+
+impl<G> Zeroize for AdditiveShare<G>
+where
+    G: Group,
+{
+    fn zeroize(&mut self) {
+        // SAFETY: The pointer is valid, properly aligned and points to
+        // an initialized value. Volatile writes prevent the compiler
+        // from optimizing the zeroing away.
+        unsafe { ptr::write_volatile(&mut self.value, G::Scalar::ZERO) };
+    }
+}
+
+impl<G> Drop for AdditiveShare<G>
+where
+    G: Group,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+    use zeroize::Zeroize;
+
+    use crate::{
+        churp::{self, Error},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type AdditiveShare = churp::AdditiveShare<Group>;
+
+    #[test]
+    fn test_split_and_combine() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::random(&mut rng);
+
+        for n in 1..5 {
+            let shares = AdditiveShare::split(&secret, n, &mut rng);
+            assert_eq!(shares.len(), n);
+            assert_eq!(AdditiveShare::combine(&shares), secret);
+
+            // Proper subsets.
+            for i in 0..n {
+                assert_ne!(AdditiveShare::combine(&shares[..i]), secret);
+                assert_ne!(AdditiveShare::combine(&shares[i + 1..]), secret);
+            }
+        }
+
+        let shares = AdditiveShare::split(&secret, 0, &mut rng);
+        assert!(shares.is_empty());
+        assert_eq!(AdditiveShare::combine(&shares), PrimeField::ZERO);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::random(&mut rng);
+
+        for share in AdditiveShare::split(&secret, 2, &mut rng) {
+            let bytes = share.to_bytes();
+            let restored = AdditiveShare::from_bytes(&bytes).unwrap();
+            assert!(restored == share);
+
+            let res = AdditiveShare::from_bytes(&bytes[1..]);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidEncoding.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut share = AdditiveShare::new(PrimeField::ONE);
+        share.zeroize();
+        assert_eq!(share.value(), &PrimeField::ZERO);
+    }
+}
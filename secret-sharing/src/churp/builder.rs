@@ -0,0 +1,211 @@
+//! Assembly of full shares from their row and column.
+
+use anyhow::Result;
+use group::ff::PrimeField;
+use zeroize::Zeroize;
+
+use crate::poly::Polynomial;
+
+use super::{Error, SecretShare};
+
+/// Builder of the full share `B(me,y)` of a shareholder from the row
+/// `B(me,y)` and the column `B(x,me)` of the bivariate polynomial received
+/// during a handoff.
+///
+/// The row and column intersect at `B(me,me)`, so a pair that doesn't agree
+/// there can't stem from the same bivariate polynomial. The builder also
+/// verifies that the row has degree `2t` if the column has degree `t`.
+///
+/// Pieces are zeroized when the builder is dropped.
+pub struct FullShareBuilder<F: PrimeField> {
+    /// The identity of the shareholder.
+    x: F,
+    /// The row `B(me,y)`.
+    row: Option<Polynomial<F>>,
+    /// The column `B(x,me)`.
+    column: Option<Polynomial<F>>,
+}
+
+impl<F> FullShareBuilder<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new builder for the shareholder with the given identity.
+    pub fn new(x: F) -> Self {
+        Self {
+            x,
+            row: None,
+            column: None,
+        }
+    }
+
+    /// Sets the row `B(me,y)`, replacing any previously set row.
+    pub fn set_row(&mut self, row: Polynomial<F>) -> &mut Self {
+        if let Some(mut old) = self.row.replace(row) {
+            old.zeroize();
+        }
+        self
+    }
+
+    /// Sets the column `B(x,me)`, replacing any previously set column.
+    pub fn set_column(&mut self, column: Polynomial<F>) -> &mut Self {
+        if let Some(mut old) = self.column.replace(column) {
+            old.zeroize();
+        }
+        self
+    }
+
+    /// Builds the full share from the row, after verifying that it is
+    /// consistent with the column.
+    ///
+    /// Fails if either piece is missing, if the size of the row doesn't
+    /// match the size of the column, or if the pieces disagree at their
+    /// intersection.
+    pub fn build(mut self) -> Result<SecretShare<F>> {
+        let (row, column) = match (&self.row, &self.column) {
+            (Some(row), Some(column)) => (row, column),
+            _ => return Err(Error::IncompleteShare.into()),
+        };
+
+        let expected = 2 * (column.size() - 1);
+        if row.size() != expected + 1 {
+            return Err(Error::PolynomialDegreeMismatch {
+                expected,
+                actual: row.size() - 1,
+            }
+            .into());
+        }
+
+        // Both pieces evaluate to B(me,me) at the identity.
+        let consistent = row.eval(&self.x).ct_eq(&column.eval(&self.x));
+        if !bool::from(consistent) {
+            return Err(Error::InconsistentShare.into());
+        }
+
+        let row = self.row.take().expect("row should be set");
+        Ok(SecretShare::new(self.x, row))
+    }
+}
+
+impl<F> Drop for FullShareBuilder<F>
+where
+    F: PrimeField,
+{
+    fn drop(&mut self) {
+        for p in self.row.iter_mut().chain(self.column.iter_mut()) {
+            p.zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, Error, HandoffKind},
+        poly,
+        suites::{self, p384},
+    };
+
+    use super::FullShareBuilder;
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type Polynomial = poly::Polynomial<PrimeField>;
+
+    #[test]
+    fn test_build() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(2);
+
+        for threshold in [0, 2] {
+            let dealer = Dealer::new(threshold, &mut rng).unwrap();
+            let row = dealer.make_share(x, HandoffKind::DealingPhase);
+            let column = dealer.make_share(x, HandoffKind::CommitteeChanged);
+
+            // Consistent pieces.
+            let mut builder = FullShareBuilder::new(x);
+            builder
+                .set_row(row.polynomial().clone())
+                .set_column(column.polynomial().clone());
+            let share = builder.build().unwrap();
+            assert!(share == row);
+
+            // Pieces of another bivariate polynomial.
+            let other = Dealer::new(threshold, &mut rng).unwrap();
+            let other_column = other.make_share(x, HandoffKind::CommitteeChanged);
+            let mut builder = FullShareBuilder::new(x);
+            builder
+                .set_row(row.polynomial().clone())
+                .set_column(other_column.polynomial().clone());
+            let res = builder.build();
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InconsistentShare.to_string()
+            );
+
+            // Pieces of another shareholder.
+            let y = PrimeField::from_u64(3);
+            let mut builder = FullShareBuilder::new(y);
+            builder
+                .set_row(row.polynomial().clone())
+                .set_column(column.polynomial().clone());
+            assert_eq!(builder.build().is_ok(), threshold == 0);
+        }
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(2);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let row = dealer.make_share(x, HandoffKind::DealingPhase);
+        let column = dealer.make_share(x, HandoffKind::CommitteeChanged);
+
+        // Missing pieces.
+        let builder = FullShareBuilder::<PrimeField>::new(x);
+        let res = builder.build();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::IncompleteShare.to_string()
+        );
+
+        let mut builder = FullShareBuilder::new(x);
+        builder.set_row(row.polynomial().clone());
+        let res = builder.build();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::IncompleteShare.to_string()
+        );
+
+        // Swapped pieces.
+        let mut builder = FullShareBuilder::new(x);
+        builder
+            .set_row(column.polynomial().clone())
+            .set_column(row.polynomial().clone());
+        let res = builder.build();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 8,
+                actual: 2
+            }
+            .to_string()
+        );
+
+        // A replaced piece takes effect.
+        let mut builder = FullShareBuilder::new(x);
+        builder
+            .set_row(Polynomial::random(4, &mut rng))
+            .set_column(column.polynomial().clone())
+            .set_row(row.polynomial().clone());
+        assert!(builder.build().unwrap() == row);
+    }
+}
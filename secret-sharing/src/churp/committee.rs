@@ -0,0 +1,200 @@
+//! CHURP committee.
+
+use alloc::vec::Vec;
+use core::{iter::FromIterator, slice};
+
+use anyhow::Result;
+use group::ff::PrimeField;
+
+use crate::suites::FieldDigest;
+
+use super::{cmp_shareholders, committee_digest, required_points};
+
+/// Set of encoded shareholder IDs of a committee.
+///
+/// The IDs are deduplicated and kept in the canonical order, see
+/// [`cmp_shareholders`], so that two sets with the same members are equal
+/// regardless of the order in which the members were inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareholderSet<F: PrimeField> {
+    /// The encoded shareholder IDs in the canonical order.
+    ids: Vec<F>,
+}
+
+impl<F> ShareholderSet<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new empty set.
+    pub fn new() -> Self {
+        Self { ids: Vec::new() }
+    }
+
+    /// Inserts the given shareholder into the set.
+    ///
+    /// Returns false if the shareholder is already a member, in which case
+    /// the set is left unchanged.
+    pub fn insert(&mut self, id: F) -> bool {
+        match self.ids.binary_search_by(|x| cmp_shareholders(x, &id)) {
+            Ok(_) => false,
+            Err(i) => {
+                self.ids.insert(i, id);
+                true
+            }
+        }
+    }
+
+    /// Returns true if the given shareholder is a member of the set.
+    pub fn contains(&self, id: &F) -> bool {
+        self.ids
+            .binary_search_by(|x| cmp_shareholders(x, id))
+            .is_ok()
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns true if the set has no members.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns true if the members suffice to interpolate a polynomial
+    /// of degree `threshold`, i.e. if there are at least `threshold + 1`
+    /// of them.
+    pub fn meets_threshold(&self, threshold: u8) -> bool {
+        self.ids.len() >= required_points(threshold as usize)
+    }
+
+    /// Returns an iterator over the members in the canonical order.
+    pub fn iter(&self) -> slice::Iter<'_, F> {
+        self.ids.iter()
+    }
+
+    /// Returns the members in the canonical order.
+    pub fn as_slice(&self) -> &[F] {
+        &self.ids
+    }
+
+    /// Computes the digest of the committee, see [`committee_digest`].
+    pub fn digest<H>(&self) -> Result<H::Output>
+    where
+        H: FieldDigest,
+    {
+        committee_digest::<H, F>(&self.ids)
+    }
+}
+
+impl<F> Default for ShareholderSet<F>
+where
+    F: PrimeField,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> FromIterator<F> for ShareholderSet<F>
+where
+    F: PrimeField,
+{
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for id in iter {
+            set.insert(id);
+        }
+        set
+    }
+}
+
+impl<'a, F> IntoIterator for &'a ShareholderSet<F>
+where
+    F: PrimeField,
+{
+    type Item = &'a F;
+    type IntoIter = slice::Iter<'a, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        churp::{self, committee_digest, sort_committee},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type ShareholderSet = churp::ShareholderSet<PrimeField>;
+
+    fn ids(xs: &[u64]) -> Vec<PrimeField> {
+        xs.iter().copied().map(PrimeField::from_u64).collect()
+    }
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut set = ShareholderSet::new();
+        assert!(set.is_empty());
+
+        for (i, id) in ids(&[3, 1, 2]).into_iter().enumerate() {
+            assert!(!set.contains(&id));
+            assert!(set.insert(id));
+            assert!(set.contains(&id));
+            assert_eq!(set.len(), i + 1);
+        }
+
+        // Duplicates are ignored.
+        for id in ids(&[2, 3, 1]) {
+            assert!(!set.insert(id));
+        }
+        assert_eq!(set.len(), 3);
+        assert!(!set.contains(&PrimeField::from_u64(4)));
+
+        // Members are kept in the canonical order.
+        let mut expected = ids(&[1, 2, 3]);
+        sort_committee(&mut expected);
+        assert_eq!(set.as_slice(), &expected[..]);
+        assert!(set.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let set: ShareholderSet = ids(&[5, 1, 5, 3, 1]).into_iter().collect();
+        assert_eq!(set.len(), 3);
+
+        // The order of insertion doesn't matter.
+        let other: ShareholderSet = ids(&[3, 5, 1]).into_iter().collect();
+        assert_eq!(set, other);
+        assert_eq!(
+            set.digest::<Suite>().unwrap(),
+            committee_digest::<Suite, _>(&ids(&[1, 3, 5])).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_meets_threshold() {
+        let threshold = 2;
+        let mut set = ShareholderSet::new();
+        assert!(!set.meets_threshold(0));
+
+        set.insert(PrimeField::from_u64(1));
+        assert!(set.meets_threshold(0));
+        assert!(!set.meets_threshold(threshold));
+
+        set.insert(PrimeField::from_u64(2));
+        assert!(!set.meets_threshold(threshold));
+
+        // Duplicates don't count toward the threshold.
+        set.insert(PrimeField::from_u64(2));
+        assert!(!set.meets_threshold(threshold));
+
+        set.insert(PrimeField::from_u64(3));
+        assert!(set.meets_threshold(threshold));
+        assert!(!set.meets_threshold(threshold + 1));
+    }
+}
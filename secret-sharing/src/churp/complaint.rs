@@ -0,0 +1,159 @@
+//! Complaints about invalid switch points.
+
+use group::{Group, GroupEncoding};
+
+use crate::vss::VerificationMatrix;
+
+use super::{switch_verification_vector, Shareholder};
+
+/// Complaint about an invalid switch point, which can be broadcast during
+/// the dispute phase of a handoff and verified by any third party holding
+/// the verification matrix, see [`verify_complaint`].
+///
+/// Complaints can only be created for points that fail to verify,
+/// see [`Shareholder::complain`]. They don't authenticate the accuser,
+/// so they should be signed when broadcast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Complaint<G: Group + GroupEncoding> {
+    /// The encoded identity of the shareholder who received the point.
+    accuser: G::Scalar,
+
+    /// Indicates whether the accuser holds a full share.
+    full_share: bool,
+
+    /// The encoded identity of the shareholder who sent the point.
+    accused: G::Scalar,
+
+    /// The switch point sent by the accused shareholder.
+    point: G::Scalar,
+}
+
+impl<G> Complaint<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the encoded identity of the shareholder who received
+    /// the point.
+    pub fn accuser(&self) -> &G::Scalar {
+        &self.accuser
+    }
+
+    /// Returns true if and only if the accuser holds a full share.
+    pub fn is_full_share(&self) -> bool {
+        self.full_share
+    }
+
+    /// Returns the encoded identity of the shareholder who sent the point.
+    pub fn accused(&self) -> &G::Scalar {
+        &self.accused
+    }
+
+    /// Returns the switch point sent by the accused shareholder.
+    pub fn point(&self) -> &G::Scalar {
+        &self.point
+    }
+}
+
+impl<G> Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Creates a complaint about the switch point received from the given
+    /// shareholder.
+    ///
+    /// Returns none if the point is valid, see [`Self::verify_switch_point`],
+    /// so that frivolous complaints cannot be created.
+    pub fn complain(&self, x: &G::Scalar, bij: &G::Scalar) -> Option<Complaint<G>> {
+        if self.verify_switch_point(x, bij) {
+            return None;
+        }
+
+        let share = self.verifiable_share().secret_share();
+        let (_, cols) = self.verifiable_share().verification_matrix().dimensions();
+
+        Some(Complaint {
+            accuser: *share.coordinate_x(),
+            full_share: share.polynomial().size() == cols,
+            accused: *x,
+            point: *bij,
+        })
+    }
+}
+
+/// Verifies that the switch point accused by the given complaint fails
+/// to verify against the given verification matrix.
+///
+/// Any third party holding the matrix can confirm the complaint without
+/// knowing the secret share of the accuser.
+pub fn verify_complaint<G>(complaint: &Complaint<G>, vm: &VerificationMatrix<G>) -> bool
+where
+    G: Group + GroupEncoding,
+{
+    let vv = switch_verification_vector(vm, &complaint.accuser, complaint.full_share);
+    !vv.verify(&complaint.accused, &complaint.point)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, verify_complaint, HandoffKind},
+        suites::{self, p384},
+    };
+
+    use super::Complaint;
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type Shareholder = churp::Shareholder<Group>;
+
+    fn prepare_shareholder(dealer: &Dealer, x: u64, kind: HandoffKind) -> Shareholder {
+        let x = PrimeField::from_u64(x);
+        let share = dealer.make_share(x, kind);
+        let vm = dealer.verification_matrix().clone();
+        Shareholder::new(share, vm)
+    }
+
+    #[test]
+    fn test_complaint() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 1, kind);
+            let x = *me.verifiable_share().secret_share().coordinate_x();
+            let peer = prepare_shareholder(&dealer, 2, kind);
+            let y = *peer.verifiable_share().secret_share().coordinate_x();
+            let bij = peer.switch_point(&x);
+
+            // Frivolous complaints about valid points cannot be created,
+            // and forged ones don't verify.
+            assert!(me.complain(&y, &bij).is_none());
+            let forged = Complaint {
+                accuser: x,
+                full_share: kind == HandoffKind::DealingPhase,
+                accused: y,
+                point: bij,
+            };
+            assert!(!verify_complaint(&forged, vm));
+
+            // Complaints about invalid points verify.
+            let tampered = bij + PrimeField::ONE;
+            let complaint = me.complain(&y, &tampered).unwrap();
+            assert_eq!(complaint.accuser(), &x);
+            assert_eq!(complaint.accused(), &y);
+            assert_eq!(complaint.point(), &tampered);
+            assert_eq!(complaint.is_full_share(), kind == HandoffKind::DealingPhase);
+            assert!(verify_complaint(&complaint, vm));
+
+            // A valid point attributed to another shareholder is invalid.
+            let z = PrimeField::from_u64(3);
+            let complaint = me.complain(&z, &bij).unwrap();
+            assert!(verify_complaint(&complaint, vm));
+        }
+    }
+}
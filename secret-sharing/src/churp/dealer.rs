@@ -1,13 +1,55 @@
 //! CHURP dealer.
 
+use alloc::vec::Vec;
+use core::{iter::zip, ptr};
+
 use anyhow::Result;
 use group::{ff::Field, Group, GroupEncoding};
+#[cfg(any(test, feature = "deterministic"))]
+use rand_chacha::ChaCha20Rng;
 use rand_core::RngCore;
+#[cfg(any(test, feature = "deterministic"))]
+use rand_core::SeedableRng;
+use zeroize::Zeroize;
 
-use crate::{poly::BivariatePolynomial, vss::VerificationMatrix};
+use crate::{
+    poly::{BivariatePolynomial, CheckedRng},
+    vss::VerificationMatrix,
+};
 
 use super::{Error, HandoffKind, SecretShare};
 
+/// Verifies the secret points `(x_k, B(x_k,0))` dealt to a committee
+/// against the verification matrix of the dealer.
+///
+/// The points are first verified at once using a random linear combination,
+/// see [`VerificationVector::verify_batch`]. If the batch fails, the points
+/// are verified one by one, and the index of the first invalid point
+/// is reported.
+///
+/// [`VerificationVector::verify_batch`]: crate::vss::VerificationVector::verify_batch
+pub fn verify_dealing<G>(
+    vm: &VerificationMatrix<G>,
+    shares: &[(G::Scalar, G::Scalar)],
+    rng: &mut impl RngCore,
+) -> Result<()>
+where
+    G: Group + GroupEncoding,
+{
+    // The commitment to B(x,0), i.e. the first column of the matrix.
+    let vv = vm.verification_vector_for_x(&G::Scalar::ZERO);
+    if vv.verify_batch(shares, rng) {
+        return Ok(());
+    }
+
+    // The batch also fails if the random number generator fails,
+    // in which case all points may still be valid.
+    match shares.iter().position(|(x, s)| !vv.verify(x, s)) {
+        Some(index) => Err(Error::InvalidShare { index }.into()),
+        None => Ok(()),
+    }
+}
+
 /// Dealer is responsible for generating a secret bivariate polynomial,
 /// computing a verification matrix, and deriving secret shares for other
 /// participants.
@@ -73,6 +115,24 @@ where
         Ok(bp.into())
     }
 
+    /// Creates a new dealer of secret bivariate shares, where the secret
+    /// bivariate polynomial is derived deterministically from the given seed.
+    ///
+    /// The polynomial is generated using a ChaCha20 RNG seeded with the given
+    /// seed, so the same seed and threshold always yield the same dealer,
+    /// regardless of the platform.
+    ///
+    /// This function is intended for reproducible test vectors only.
+    /// Anyone who knows the seed can recover the secret, so it must never
+    /// be used to share real secrets.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    #[cfg(any(test, feature = "deterministic"))]
+    pub fn new_deterministic(threshold: u8, seed: &[u8; 32]) -> Result<Self> {
+        let mut rng = ChaCha20Rng::from_seed(*seed);
+        Self::new(threshold, &mut rng)
+    }
+
     /// Creates a new dealer of secret bivariate shares, which can be used
     /// to recover a predefined shared secret.
     ///
@@ -99,6 +159,13 @@ where
         &self.vm
     }
 
+    /// Returns the shared secret `B(0, 0)`.
+    pub fn secret(&self) -> &G::Scalar {
+        self.bp
+            .coefficient(0, 0)
+            .expect("bivariate polynomial has at least one term")
+    }
+
     /// Generates shares of the secret for the given shareholders.
     pub fn make_shares(
         &self,
@@ -119,10 +186,40 @@ where
         SecretShare::new(x, p)
     }
 
+    /// Computes commitments to the secret points of the given shareholders.
+    ///
+    /// Returns the pairs `(ID, B(ID, 0) * G)` in the same order as the IDs,
+    /// which let auditors confirm that the shares dealt to the committee lie
+    /// on the secret polynomial `B(x, 0)`. The polynomial is evaluated at all
+    /// IDs in a single pass.
+    pub fn share_commitments(&self, ids: &[G::Scalar]) -> Result<Vec<(G::Scalar, G)>> {
+        if ids.iter().any(|id| id.is_zero().into()) {
+            return Err(Error::ZeroValueShareholder.into());
+        }
+
+        let mut p = self.bp.eval_y(&G::Scalar::ZERO);
+        let mut points = p.eval_many(ids);
+        p.zeroize();
+
+        let commitments = zip(ids, &points)
+            .map(|(id, point)| (*id, G::generator() * point))
+            .collect();
+        for point in points.iter_mut() {
+            // SAFETY: The pointer is valid, properly aligned and points to
+            // an initialized point. Volatile writes prevent the compiler
+            // from optimizing the zeroing away.
+            unsafe { ptr::write_volatile(point, G::Scalar::ZERO) };
+        }
+
+        Ok(commitments)
+    }
+
     /// Generates a random bivariate polynomial `B(x, y)` such that
     /// the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)` have non-zero
     /// leading term, and the secret `B(0, 0)` is non-zero.
     ///
+    /// Fails if the random number generator fails.
+    ///
     /// This function is not constant time because it uses rejection
     /// sampling to ensure that the polynomials have the maximum degree.
     /// Additionally, the underlying prime field implementation may also
@@ -133,13 +230,17 @@ where
     ) -> Result<BivariatePolynomial<G::Scalar>> {
         let deg_x = threshold;
         let deg_y = threshold.checked_mul(2).ok_or(Error::ThresholdTooLarge)?;
+        let mut rng = CheckedRng::new(rng);
 
         // When using a random RNG and a large prime field, this loop
         // should execute once with an extremely high probability,
         // so there is no need to optimize it by randomly selecting
         // only the problematic coefficients.
         for _ in 0..5 {
-            let bp = BivariatePolynomial::<G::Scalar>::random(deg_x, deg_y, rng);
+            let bp = BivariatePolynomial::<G::Scalar>::random(deg_x, deg_y, &mut rng);
+            if rng.failed() {
+                return Err(Error::RngFailure.into());
+            }
 
             let i = deg_x as usize;
             let j = deg_y as usize;
@@ -172,13 +273,19 @@ where
 
 #[cfg(test)]
 mod tests {
+    use core::{iter::zip, num::NonZeroU32};
+
+    use group::Group as _;
     use rand::{rngs::StdRng, Error, RngCore, SeedableRng};
 
-    use super::{BivariatePolynomial, HandoffKind};
+    use crate::{churp, suites};
+
+    use super::{verify_dealing, BivariatePolynomial, HandoffKind};
 
     type PrimeField = p384::Scalar;
     type Group = p384::ProjectivePoint;
     type Dealer = super::Dealer<Group>;
+    type SecretShare = super::SecretShare<PrimeField>;
 
     #[test]
     fn test_new() {
@@ -224,6 +331,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_deterministic() {
+        let seed = [1u8; 32];
+
+        for threshold in [0, 2] {
+            let d1 = Dealer::new_deterministic(threshold, &seed).unwrap();
+            let d2 = Dealer::new_deterministic(threshold, &seed).unwrap();
+            assert!(d1.bivariate_polynomial() == d2.bivariate_polynomial());
+            assert!(d1.verification_matrix() == d2.verification_matrix());
+
+            // Different seeds yield different dealers.
+            let d3 = Dealer::new_deterministic(threshold, &[2u8; 32]).unwrap();
+            assert!(d1.bivariate_polynomial() != d3.bivariate_polynomial());
+        }
+    }
+
     #[test]
     fn test_new_with_secret() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -266,6 +389,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_share_commitments() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let ids: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+
+        let commitments = dealer.share_commitments(&ids).unwrap();
+        assert_eq!(commitments.len(), ids.len());
+        for (id, (cid, commitment)) in zip(&ids, commitments) {
+            assert_eq!(*id, cid);
+
+            // Key share of the generator derived by the shareholder.
+            let share = dealer.make_share(*id, HandoffKind::DealingPhase);
+            let shareholder = churp::Shareholder::new(share, vm.clone());
+            let key_share = shareholder.key_share(Group::generator()).unwrap();
+            assert_eq!(commitment, key_share);
+        }
+
+        assert!(dealer.share_commitments(&[]).unwrap().is_empty());
+
+        let res = dealer.share_commitments(&[ids[0], PrimeField::ZERO]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::ZeroValueShareholder.to_string()
+        );
+    }
+
+    #[test]
+    fn test_verify_dealing() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let mut shares: Vec<_> = (1..=5)
+            .map(PrimeField::from_u64)
+            .map(|x| dealer.make_share(x, HandoffKind::DealingPhase))
+            .map(|share| (*share.coordinate_x(), *share.coordinate_y()))
+            .collect();
+
+        // Correct dealing.
+        assert!(verify_dealing(vm, &shares, &mut rng).is_ok());
+        assert!(verify_dealing::<Group>(vm, &[], &mut rng).is_ok());
+
+        // Corrupted share.
+        shares[3].1 += PrimeField::ONE;
+        let res = verify_dealing(vm, &shares, &mut rng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::InvalidShare { index: 3 }.to_string()
+        );
+
+        // Share of another dealing.
+        shares[3].1 -= PrimeField::ONE;
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let res = verify_dealing(other.verification_matrix(), &shares, &mut rng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::InvalidShare { index: 0 }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_secret() {
+        let threshold = 2;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+        assert_eq!(dealer.secret(), &secret);
+
+        // Shares dealt to more than a threshold number of distinct
+        // shareholders reconstruct the secret.
+        let points: Vec<_> = (1..=threshold as u64 + 1)
+            .map(PrimeField::from_u64)
+            .map(|x| dealer.make_share(x, HandoffKind::DealingPhase))
+            .map(|share| (*share.coordinate_x(), *share.coordinate_y()))
+            .collect();
+        let recovered = SecretShare::recover_from_points(&points, threshold).unwrap();
+        assert_eq!(recovered, secret);
+
+        // A threshold number of shares is not enough.
+        let res = SecretShare::recover_from_points(&points[1..], threshold);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_generate_bivariate_polynomial() {
         /// A custom RNG that fills the first few slices with zeros,
@@ -298,8 +508,9 @@ mod tests {
                 panic!("not implemented")
             }
 
-            fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
-                panic!("not implemented")
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                self.fill_bytes(dest);
+                Ok(())
             }
 
             fn fill_bytes(&mut self, dest: &mut [u8]) {
@@ -335,6 +546,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rng_failure() {
+        /// A custom RNG that always fails.
+        struct FailingRng;
+
+        impl RngCore for FailingRng {
+            fn next_u32(&mut self) -> u32 {
+                panic!("not implemented")
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                panic!("not implemented")
+            }
+
+            fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+                Err(NonZeroU32::new(Error::CUSTOM_START).unwrap().into())
+            }
+
+            fn fill_bytes(&mut self, _dest: &mut [u8]) {
+                panic!("not implemented")
+            }
+        }
+
+        for threshold in [0, 2] {
+            let res = Dealer::new(threshold, &mut FailingRng);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                churp::Error::RngFailure.to_string()
+            );
+
+            let res = Dealer::new_proactive(threshold, &mut FailingRng);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                churp::Error::RngFailure.to_string()
+            );
+        }
+
+        // Shareholders propagate the failure as well.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let res = share.new_proactivization::<Group>((3, 5), &mut FailingRng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::RngFailure.to_string()
+        );
+
+        let vm = dealer.verification_matrix().clone();
+        let shareholder = churp::Shareholder::new(share, vm);
+        let committee = [PrimeField::from_u64(2), PrimeField::from_u64(3)];
+        let res = shareholder.random_masked_switch_point(&committee[0], &mut FailingRng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::RngFailure.to_string()
+        );
+
+        let res = shareholder.rerandomize(&mut FailingRng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::RngFailure.to_string()
+        );
+
+        let res = shareholder.reshare(&committee, 1, &mut FailingRng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::RngFailure.to_string()
+        );
+
+        let res = shareholder.prove_membership::<suites::p384::Sha3_384>(&mut FailingRng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            churp::Error::RngFailure.to_string()
+        );
+
+        // Dealings are still verified point by point.
+        let shares = [(
+            x,
+            *shareholder.verifiable_share().secret_share().coordinate_y(),
+        )];
+        let vm = dealer.verification_matrix();
+        assert!(verify_dealing(vm, &shares, &mut FailingRng).is_ok());
+    }
+
     #[test]
     fn test_from() {
         let bp = BivariatePolynomial::zero(2, 3);
@@ -1,9 +1,41 @@
+/// CHURP errors.
+///
+/// Failures are returned as [`anyhow::Error`], from which the variant
+/// can be recovered with [`anyhow::Error::downcast_ref`]. Where an error
+/// wraps an underlying failure, the latter is available as its source.
+///
+/// New variants may be added in future releases, so consumers that need
+/// to tell failures apart without matching exhaustively should use
+/// [`Error::code`].
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
+    #[error("buffer too small")]
+    BufferTooSmall,
+    #[error("declared size too large")]
+    DeclaredSizeTooLarge,
+    #[error("decryption failed")]
+    DecryptionFailed,
+    #[error("degenerate polynomial")]
+    DegeneratePolynomial,
     #[error("duplicate shareholder")]
     DuplicateShareholder,
+    #[error("empty polynomial")]
+    EmptyPolynomial,
+    #[error("epoch mismatch")]
+    EpochMismatch,
+    #[error("incomplete share")]
+    IncompleteShare,
+    #[error("inconsistent share")]
+    InconsistentShare,
+    #[error("insufficient key shares")]
+    InsufficientKeyShares,
+    #[error("invalid encoding")]
+    InvalidEncoding,
     #[error("invalid handoff kind")]
     InvalidKind,
+    #[error("invalid share at index {index}")]
+    InvalidShare { index: usize },
     #[error("invalid polynomial")]
     InvalidPolynomial,
     #[error("insecure bivariate polynomial")]
@@ -12,6 +44,8 @@ pub enum Error {
     InvalidSwitchPoint,
     #[error("invalid state")]
     InvalidState,
+    #[error("null pointer")]
+    NullPointer,
     #[error("not enough bivariate shares")]
     NotEnoughBivariateShares,
     #[error("not enough shareholders")]
@@ -20,26 +54,43 @@ pub enum Error {
     NotEnoughSwitchPoints,
     #[error("merging not finished")]
     MergingNotFinished,
-    #[error("polynomial degree mismatch")]
-    PolynomialDegreeMismatch,
+    #[error("polynomial degree mismatch: expected {expected}, got {actual}")]
+    PolynomialDegreeMismatch { expected: usize, actual: usize },
+    #[error("polynomial count mismatch")]
+    PolynomialCountMismatch,
     #[error("polynomial generation failed")]
     PolynomialGenerationFailed,
+    #[error("polynomial matrix mismatch")]
+    PolynomialMatrixMismatch,
+    #[error("random number generator failure")]
+    RngFailure,
+    #[error("shareholder encoding exhausted")]
+    ShareholderEncodingExhausted,
     #[error("shareholder encoding failed")]
     ShareholderEncodingFailed,
+    #[error("shareholder proactivization failed at index {index}")]
+    ShareholderProactivizationFailed { index: usize },
     #[error("shareholder proactivization already completed")]
     ShareholderProactivizationCompleted,
     #[error("shareholder identity mismatch")]
     ShareholderIdentityMismatch,
     #[error("shareholder identity required")]
     ShareholderIdentityRequired,
+    #[error("suite mismatch")]
+    SuiteMismatch,
     #[error("threshold too large")]
     ThresholdTooLarge,
     #[error("too many switch points")]
     TooManySwitchPoints,
     #[error("unknown shareholder")]
     UnknownShareholder,
-    #[error("verification matrix dimension mismatch")]
-    VerificationMatrixDimensionMismatch,
+    #[error("unsupported encoding version")]
+    UnsupportedEncodingVersion,
+    #[error("verification matrix dimension mismatch: expected {expected:?}, got {actual:?}")]
+    VerificationMatrixDimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
     #[error("verification matrix zero-hole mismatch")]
     VerificationMatrixZeroHoleMismatch,
     #[error("verification matrix required")]
@@ -47,3 +98,129 @@ pub enum Error {
     #[error("zero value shareholder")]
     ZeroValueShareholder,
 }
+
+impl Error {
+    /// Returns the numeric code of the error, e.g. for FFI or logging.
+    ///
+    /// Codes are stable: once assigned, a code is never changed or reused,
+    /// and new variants receive the next unassigned code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Error::DeclaredSizeTooLarge => 1,
+            Error::DegeneratePolynomial => 2,
+            Error::DuplicateShareholder => 3,
+            Error::InsufficientKeyShares => 4,
+            Error::InvalidEncoding => 5,
+            Error::InvalidKind => 6,
+            Error::InvalidPolynomial => 7,
+            Error::InsecureBivariatePolynomial => 8,
+            Error::InvalidSwitchPoint => 9,
+            Error::InvalidState => 10,
+            Error::NotEnoughBivariateShares => 11,
+            Error::NotEnoughShareholders => 12,
+            Error::NotEnoughSwitchPoints => 13,
+            Error::MergingNotFinished => 14,
+            Error::PolynomialDegreeMismatch { .. } => 15,
+            Error::PolynomialCountMismatch => 16,
+            Error::PolynomialGenerationFailed => 17,
+            Error::PolynomialMatrixMismatch => 18,
+            Error::RngFailure => 19,
+            Error::ShareholderEncodingFailed => 20,
+            Error::ShareholderProactivizationFailed { .. } => 21,
+            Error::ShareholderProactivizationCompleted => 22,
+            Error::ShareholderIdentityMismatch => 23,
+            Error::ShareholderIdentityRequired => 24,
+            Error::SuiteMismatch => 25,
+            Error::ThresholdTooLarge => 26,
+            Error::TooManySwitchPoints => 27,
+            Error::UnknownShareholder => 28,
+            Error::UnsupportedEncodingVersion => 29,
+            Error::VerificationMatrixDimensionMismatch { .. } => 30,
+            Error::VerificationMatrixZeroHoleMismatch => 31,
+            Error::VerificationMatrixRequired => 32,
+            Error::ZeroValueShareholder => 33,
+            Error::BufferTooSmall => 34,
+            Error::NullPointer => 35,
+            Error::IncompleteShare => 36,
+            Error::InconsistentShare => 37,
+            Error::EmptyPolynomial => 38,
+            Error::InvalidShare { .. } => 39,
+            Error::EpochMismatch => 40,
+            Error::ShareholderEncodingExhausted => 41,
+            Error::DecryptionFailed => 42,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Error;
+
+    #[test]
+    fn test_code() {
+        let errors = [
+            Error::DeclaredSizeTooLarge,
+            Error::DegeneratePolynomial,
+            Error::DuplicateShareholder,
+            Error::InsufficientKeyShares,
+            Error::InvalidEncoding,
+            Error::InvalidKind,
+            Error::InvalidPolynomial,
+            Error::InsecureBivariatePolynomial,
+            Error::InvalidSwitchPoint,
+            Error::InvalidState,
+            Error::NotEnoughBivariateShares,
+            Error::NotEnoughShareholders,
+            Error::NotEnoughSwitchPoints,
+            Error::MergingNotFinished,
+            Error::PolynomialDegreeMismatch {
+                expected: 0,
+                actual: 0,
+            },
+            Error::PolynomialCountMismatch,
+            Error::PolynomialGenerationFailed,
+            Error::PolynomialMatrixMismatch,
+            Error::RngFailure,
+            Error::ShareholderEncodingFailed,
+            Error::ShareholderProactivizationFailed { index: 0 },
+            Error::ShareholderProactivizationCompleted,
+            Error::ShareholderIdentityMismatch,
+            Error::ShareholderIdentityRequired,
+            Error::SuiteMismatch,
+            Error::ThresholdTooLarge,
+            Error::TooManySwitchPoints,
+            Error::UnknownShareholder,
+            Error::UnsupportedEncodingVersion,
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (0, 0),
+                actual: (0, 0),
+            },
+            Error::VerificationMatrixZeroHoleMismatch,
+            Error::VerificationMatrixRequired,
+            Error::ZeroValueShareholder,
+            Error::BufferTooSmall,
+            Error::NullPointer,
+            Error::IncompleteShare,
+            Error::InconsistentShare,
+            Error::EmptyPolynomial,
+            Error::InvalidShare { index: 0 },
+            Error::EpochMismatch,
+            Error::ShareholderEncodingExhausted,
+            Error::DecryptionFailed,
+        ];
+
+        // Codes are distinct and contiguous, starting at one.
+        let codes: HashSet<_> = errors.iter().map(Error::code).collect();
+        assert_eq!(codes.len(), errors.len());
+        for (i, err) in errors.iter().enumerate() {
+            assert_eq!(err.code(), i as u32 + 1);
+        }
+
+        // Some codes are pinned to catch accidental renumbering.
+        assert_eq!(Error::DeclaredSizeTooLarge.code(), 1);
+        assert_eq!(Error::RngFailure.code(), 19);
+        assert_eq!(Error::ZeroValueShareholder.code(), 33);
+    }
+}
@@ -5,78 +5,7 @@ use group::{Group, GroupEncoding};
 
 use crate::vss::VerificationMatrix;
 
-use super::{DimensionSwitch, Error, Shareholder, VerifiableSecretShare};
-
-/// Handoff kind.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum HandoffKind {
-    /// The dealing phase is the initial setup phase where all (or some)
-    /// participants act as dealers, sending polynomials (bivariate/dealer
-    /// shares) and the verification matrix to all other participants.
-    /// These polynomials and the matrix are derived from privately
-    /// chosen non-zero-hole bivariate polynomial.
-    ///
-    /// The number of dealers during the dealing phase must be at least
-    /// threshold + 2. This ensures that even if the threshold number
-    /// of potentially Byzantine dealers reveal their privately chosen
-    /// bivariate polynomial, the combined bivariate polynomial `B(x,y)`
-    /// cannot be known by any single party. It's important to note that
-    /// if only threshold + 1 shares have been combined, then the secret
-    /// could be known by one honest shareholder.
-    ///
-    /// In this phase, dimension switching is not needed, so the handoff
-    /// can be simplified. The share reduction phase is skipped entirely,
-    /// and only the proactivization part of the full share distribution
-    /// phase needs to be completed.
-    DealingPhase,
-    /// Handoffs, in which the committee doesn't change, are similar to
-    /// the dealing phase. Here also, all (or some) participants act
-    /// as dealers, sending polynomials (bivariate/dealer shares) and
-    /// the verification matrix to all other participants. The difference,
-    /// however, is that these polynomials and the matrix are derived from
-    /// privately chosen zero-hole bivariate polynomial, ensuring that
-    /// the shared secret is not changed when the distributed key shares
-    /// are updated.
-    ///
-    /// In this phase, dimension switching is not needed, so the handoff
-    /// can be simplified. The share reduction phase is skipped entirely,
-    /// and only the proactivization part of the full share distribution
-    /// phase needs to be completed.
-    CommitteeUnchanged,
-    /// Handoffs, during which the committee changes, use the dimension
-    /// switching technique to transfer the secret from the old committee
-    /// to a new one. Here also, all (or some) participants act as dealers,
-    /// sending polynomials (bivariate/dealer shares) and the verification
-    /// matrix to all other participants. These polynomials and the matrix
-    /// must be derived from privately chosen zero-hole bivariate polynomial.
-    ///
-    /// The full handoff consists of two phases: share reduction and
-    /// full share distribution. In the first phase, the new committee
-    /// temporarily switches to a (2t, n)-threshold scheme by retrieving
-    /// share reduction points from the old committee. These points are used
-    /// to construct reduced shares which are then proactively updated
-    /// to obtain proactive reduced shares. Once all participants construct
-    /// those, the second phase begins in which the new committee switches
-    /// back to a (t, n)-threshold scheme by exchanging full share distribution
-    /// points and constructing full shares.
-    CommitteeChanged,
-}
-
-impl HandoffKind {
-    /// Indicates whether bivariate shares should be derived from a zero-hole
-    /// bivariate polynomial.
-    ///
-    /// Should return false only in the dealing phase, where the sum of the
-    /// bivariate shares defines the shared secret. In other handoffs, this
-    /// should be true so that the shared secret remains unchanged.
-    pub fn require_zero_hole(&self) -> bool {
-        match &self {
-            HandoffKind::DealingPhase => false,
-            HandoffKind::CommitteeUnchanged => true,
-            HandoffKind::CommitteeChanged => true,
-        }
-    }
-}
+use super::{DimensionSwitch, Error, HandoffKind, Shareholder, VerifiableSecretShare};
 
 /// Handoff generates a new shared secret and distributes the associated
 /// shares among committee members, or proactivizes an existing secret by
@@ -115,6 +44,12 @@ pub trait Handoff<G: Group + GroupEncoding>: Send + Sync {
         Err(Error::InvalidKind.into())
     }
 
+    /// Returns the shareholders that sent invalid or conflicting switch
+    /// points during share reduction.
+    fn share_reduction_blame(&self) -> Result<Vec<G::Scalar>> {
+        Err(Error::InvalidKind.into())
+    }
+
     /// Checks if full share distribution needs a switch point from the given
     /// shareholder.
     fn needs_full_share_distribution_switch_point(&self, _x: &G::Scalar) -> Result<bool> {
@@ -130,6 +65,12 @@ pub trait Handoff<G: Group + GroupEncoding>: Send + Sync {
         Err(Error::InvalidKind.into())
     }
 
+    /// Returns the shareholders that sent invalid or conflicting switch
+    /// points during full share distribution.
+    fn full_share_distribution_blame(&self) -> Result<Vec<G::Scalar>> {
+        Err(Error::InvalidKind.into())
+    }
+
     /// Checks if bivariate share is needed from the given shareholder.
     fn needs_bivariate_share(&self, _x: &G::Scalar) -> Result<bool> {
         Err(Error::InvalidKind.into())
@@ -323,6 +264,10 @@ where
         self.share_reduction.add_switch_point(x, bij)
     }
 
+    fn share_reduction_blame(&self) -> Result<Vec<G::Scalar>> {
+        Ok(self.share_reduction.blamed_shareholders())
+    }
+
     fn needs_full_share_distribution_switch_point(&self, x: &G::Scalar) -> Result<bool> {
         self.share_distribution.needs_switch_point(x)
     }
@@ -335,6 +280,10 @@ where
         self.share_distribution.add_switch_point(x, bij)
     }
 
+    fn full_share_distribution_blame(&self) -> Result<Vec<G::Scalar>> {
+        Ok(self.share_distribution.blamed_shareholders())
+    }
+
     fn needs_bivariate_share(&self, x: &G::Scalar) -> Result<bool> {
         self.share_reduction.needs_bivariate_share(x)
     }
@@ -379,7 +328,7 @@ mod tests {
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     use crate::{
-        churp::{self, Handoff, HandoffKind, VerifiableSecretShare},
+        churp::{self, Error, Handoff, HandoffKind, VerifiableSecretShare},
         suites::{self, p384},
     };
 
@@ -650,4 +599,85 @@ mod tests {
 
         verify_shareholders(&shareholders, threshold, true);
     }
+
+    #[test]
+    fn test_blame() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+
+        // Full shareholders of the old committee.
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let shareholders: Vec<_> = prepare_shareholders(&[1, 2, 3, 4])
+            .into_iter()
+            .map(|x| Shareholder::new(dealer.make_share(x, HandoffKind::DealingPhase), vm.clone()))
+            .collect();
+
+        let committee = prepare_shareholders(&[5, 6, 7, 8, 9]);
+        let alice = committee[0];
+        let handoff = CommitteeChanged::new(threshold, alice, committee.clone()).unwrap();
+        handoff.set_verification_matrix(vm).unwrap();
+        assert!(handoff.share_reduction_blame().unwrap().is_empty());
+
+        let points: Vec<_> = shareholders
+            .iter()
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&alice)))
+            .collect();
+        let (x1, y1) = points[0];
+        let (x2, y2) = points[1];
+
+        // Valid point.
+        assert!(!handoff.add_share_reduction_switch_point(x1, y1).unwrap());
+
+        // Invalid point.
+        let res = handoff.add_share_reduction_switch_point(x2, y2 + PrimeField::ONE);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidSwitchPoint.to_string()
+        );
+        assert_eq!(handoff.share_reduction_blame().unwrap(), vec![x2]);
+
+        // Resending the same point is rejected, but not blamed.
+        let res = handoff.add_share_reduction_switch_point(x1, y1);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+        assert_eq!(handoff.share_reduction_blame().unwrap(), vec![x2]);
+
+        // Conflicting point.
+        let res = handoff.add_share_reduction_switch_point(x1, y2);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+        assert_eq!(handoff.share_reduction_blame().unwrap(), vec![x2, x1]);
+
+        // The handoff proceeds with valid points.
+        for &(x, y) in &points[1..3] {
+            assert!(handoff.needs_share_reduction_switch_point(&x).unwrap());
+            handoff.add_share_reduction_switch_point(x, y).unwrap();
+        }
+
+        let dealers = prepare_dealers(threshold, false, committee.len(), &mut rng);
+        for (bob, dealer) in zip(committee.iter(), dealers.iter()) {
+            let share = dealer.make_share(alice, HandoffKind::CommitteeChanged);
+            let vm = dealer.verification_matrix().clone();
+            let verifiable_share = VerifiableSecretShare::new(share, vm);
+            handoff.add_bivariate_share(bob, verifiable_share).unwrap();
+        }
+
+        let shareholder = handoff.get_reduced_shareholder().unwrap();
+        verify_shareholders(&[shareholder], threshold, false);
+        assert_eq!(handoff.share_reduction_blame().unwrap(), vec![x2, x1]);
+        assert!(handoff.full_share_distribution_blame().unwrap().is_empty());
+
+        // Blame is only tracked for handoffs with switch points.
+        let handoff = DealingPhase::new(threshold, alice, committee).unwrap();
+        assert!(handoff.share_reduction_blame().is_err());
+        assert!(handoff.full_share_distribution_blame().is_err());
+    }
 }
@@ -0,0 +1,70 @@
+/// Handoff kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandoffKind {
+    /// The dealing phase is the initial setup phase where all (or some)
+    /// participants act as dealers, sending polynomials (bivariate/dealer
+    /// shares) and the verification matrix to all other participants.
+    /// These polynomials and the matrix are derived from privately
+    /// chosen non-zero-hole bivariate polynomial.
+    ///
+    /// The number of dealers during the dealing phase must be at least
+    /// threshold + 2. This ensures that even if the threshold number
+    /// of potentially Byzantine dealers reveal their privately chosen
+    /// bivariate polynomial, the combined bivariate polynomial `B(x,y)`
+    /// cannot be known by any single party. It's important to note that
+    /// if only threshold + 1 shares have been combined, then the secret
+    /// could be known by one honest shareholder.
+    ///
+    /// In this phase, dimension switching is not needed, so the handoff
+    /// can be simplified. The share reduction phase is skipped entirely,
+    /// and only the proactivization part of the full share distribution
+    /// phase needs to be completed.
+    DealingPhase,
+    /// Handoffs, in which the committee doesn't change, are similar to
+    /// the dealing phase. Here also, all (or some) participants act
+    /// as dealers, sending polynomials (bivariate/dealer shares) and
+    /// the verification matrix to all other participants. The difference,
+    /// however, is that these polynomials and the matrix are derived from
+    /// privately chosen zero-hole bivariate polynomial, ensuring that
+    /// the shared secret is not changed when the distributed key shares
+    /// are updated.
+    ///
+    /// In this phase, dimension switching is not needed, so the handoff
+    /// can be simplified. The share reduction phase is skipped entirely,
+    /// and only the proactivization part of the full share distribution
+    /// phase needs to be completed.
+    CommitteeUnchanged,
+    /// Handoffs, during which the committee changes, use the dimension
+    /// switching technique to transfer the secret from the old committee
+    /// to a new one. Here also, all (or some) participants act as dealers,
+    /// sending polynomials (bivariate/dealer shares) and the verification
+    /// matrix to all other participants. These polynomials and the matrix
+    /// must be derived from privately chosen zero-hole bivariate polynomial.
+    ///
+    /// The full handoff consists of two phases: share reduction and
+    /// full share distribution. In the first phase, the new committee
+    /// temporarily switches to a (2t, n)-threshold scheme by retrieving
+    /// share reduction points from the old committee. These points are used
+    /// to construct reduced shares which are then proactively updated
+    /// to obtain proactive reduced shares. Once all participants construct
+    /// those, the second phase begins in which the new committee switches
+    /// back to a (t, n)-threshold scheme by exchanging full share distribution
+    /// points and constructing full shares.
+    CommitteeChanged,
+}
+
+impl HandoffKind {
+    /// Indicates whether bivariate shares should be derived from a zero-hole
+    /// bivariate polynomial.
+    ///
+    /// Should return false only in the dealing phase, where the sum of the
+    /// bivariate shares defines the shared secret. In other handoffs, this
+    /// should be true so that the shared secret remains unchanged.
+    pub fn require_zero_hole(&self) -> bool {
+        match &self {
+            HandoffKind::DealingPhase => false,
+            HandoffKind::CommitteeUnchanged => true,
+            HandoffKind::CommitteeChanged => true,
+        }
+    }
+}
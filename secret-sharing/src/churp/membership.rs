@@ -0,0 +1,166 @@
+//! Proofs of committee membership.
+
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use rand_core::RngCore;
+
+use crate::{
+    poly::sample_nonzero_scalar,
+    suites::FieldDigest,
+    vss::{VerificationMatrix, VerificationVector},
+};
+
+use super::{Error, Shareholder};
+
+/// Domain separation tag for membership proof challenges.
+const MEMBERSHIP_PROOF_DST: &[u8] = b"oasis-core/secret-sharing: membership proof";
+
+/// Proof that a shareholder holds the share of the committed bivariate
+/// polynomial at its identity.
+///
+/// The proof is a non-interactive Schnorr proof of knowledge of the value
+/// `B(id,id)`, whose commitment `B(id,id) * G` anyone can derive from
+/// the verification matrix. Since only committee members received shares,
+/// outsiders can't produce a valid proof for any identity, and members
+/// can only produce proofs for their own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipProof<G: Group> {
+    /// The commitment `k * G` to the random nonce `k`.
+    r: G,
+    /// The response `k + c * B(id,id)` to the challenge `c`.
+    z: G::Scalar,
+}
+
+impl<G> Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Proves that the shareholder holds the share of the bivariate
+    /// polynomial committed to by its verification matrix at its identity.
+    ///
+    /// Fails if the random number generator fails.
+    pub fn prove_membership<H>(&self, rng: &mut impl RngCore) -> Result<MembershipProof<G>>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+    {
+        let share = self.verifiable_share().secret_share();
+        let vm = self.verifiable_share().verification_matrix();
+        let id = share.coordinate_x();
+
+        // Both full and reduced shares evaluate to B(id,id) at the identity.
+        let s = share.polynomial().eval(id);
+        let commitment = G::generator() * s;
+
+        let k: G::Scalar =
+            sample_nonzero_scalar(rng).map_err(|err| err.context(Error::RngFailure))?;
+        let r = G::generator() * k;
+        let c = membership_challenge::<G, H>(vm, id, &commitment, &r)?;
+        let z = k + c * s;
+
+        Ok(MembershipProof { r, z })
+    }
+}
+
+/// Verifies that the proof was produced by the holder of the share
+/// of the bivariate polynomial committed to by the given verification
+/// matrix at the given identity.
+pub fn verify_membership<G, H>(
+    proof: &MembershipProof<G>,
+    vm: &VerificationMatrix<G>,
+    id: &G::Scalar,
+) -> bool
+where
+    G: Group + GroupEncoding,
+    H: FieldDigest<Output = G::Scalar>,
+{
+    // The commitment B(id,id) * G.
+    let commitment = VerificationVector::new(vm.eval_at(id)).eval(id);
+
+    let c = match membership_challenge::<G, H>(vm, id, &commitment, &proof.r) {
+        Ok(c) => c,
+        Err(_) => return false,
+    };
+    let diff = G::generator() * proof.z - proof.r - commitment * c;
+
+    diff.is_identity().into()
+}
+
+/// Derives the challenge of a membership proof from the verification
+/// matrix, the identity, the commitment and the nonce commitment.
+fn membership_challenge<G, H>(
+    vm: &VerificationMatrix<G>,
+    id: &G::Scalar,
+    commitment: &G,
+    r: &G,
+) -> Result<G::Scalar>
+where
+    G: Group + GroupEncoding,
+    H: FieldDigest<Output = G::Scalar>,
+{
+    let mut context = Vec::new();
+    context.extend_from_slice(MEMBERSHIP_PROOF_DST);
+    context.extend_from_slice(id.to_repr().as_ref());
+    context.extend_from_slice(commitment.to_bytes().as_ref());
+    context.extend_from_slice(r.to_bytes().as_ref());
+
+    vm.transcript_challenge::<H>(&context)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, HandoffKind, SecretShare},
+        poly::Polynomial,
+        suites::{self, p384},
+    };
+
+    use super::{verify_membership, MembershipProof};
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type Shareholder = churp::Shareholder<Group>;
+
+    #[test]
+    fn test_membership() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let x = PrimeField::from_u64(1);
+        let y = PrimeField::from_u64(2);
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = Shareholder::new(dealer.make_share(x, kind), vm.clone());
+            let proof = shareholder.prove_membership::<Suite>(&mut rng).unwrap();
+
+            // Member.
+            assert!(verify_membership::<_, Suite>(&proof, vm, &x));
+
+            // Fabricated identity.
+            assert!(!verify_membership::<_, Suite>(&proof, vm, &y));
+
+            // Another verification matrix.
+            let other_vm = other.verification_matrix();
+            assert!(!verify_membership::<_, Suite>(&proof, other_vm, &x));
+
+            // Tampered proof.
+            let tampered = MembershipProof {
+                r: proof.r,
+                z: proof.z + PrimeField::ONE,
+            };
+            assert!(!verify_membership::<_, Suite>(&tampered, vm, &x));
+        }
+
+        // Outsider with a fabricated share.
+        let share = SecretShare::new(y, Polynomial::random(4, &mut rng));
+        let outsider = Shareholder::new(share, vm.clone());
+        let proof = outsider.prove_membership::<Suite>(&mut rng).unwrap();
+        assert!(!verify_membership::<_, Suite>(&proof, vm, &y));
+    }
+}
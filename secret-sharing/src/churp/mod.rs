@@ -1,11 +1,36 @@
 //! CHUrn-Robust Proactive secret sharing.
 
+mod abscissa;
+mod additive;
+mod builder;
+mod committee;
+mod complaint;
 mod dealer;
 mod errors;
+#[cfg(feature = "std")]
 mod handoff;
+mod kind;
+mod membership;
 mod player;
+mod public;
+#[cfg(feature = "seal")]
+mod seal;
 mod shareholder;
+#[cfg(feature = "async")]
+mod source;
+mod suite;
+#[cfg(feature = "std")]
 mod switch;
+mod weighted;
 
 // Re-exports.
-pub use self::{dealer::*, errors::*, handoff::*, player::*, shareholder::*, switch::*};
+#[cfg(feature = "seal")]
+pub use self::seal::*;
+#[cfg(feature = "async")]
+pub use self::source::*;
+pub use self::{
+    abscissa::*, additive::*, builder::*, committee::*, complaint::*, dealer::*, errors::*,
+    kind::*, membership::*, player::*, public::*, shareholder::*, suite::*, weighted::*,
+};
+#[cfg(feature = "std")]
+pub use self::{handoff::*, switch::*};
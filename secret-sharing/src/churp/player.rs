@@ -1,11 +1,43 @@
-use std::iter::zip;
+use alloc::vec::Vec;
+use core::iter::zip;
 
 use anyhow::{bail, Result};
-use group::ff::PrimeField;
+use group::{ff::PrimeField, Group};
 
-use crate::{kdc::KeyRecoverer, poly::lagrange};
+use crate::{
+    kdc::KeyRecoverer,
+    poly::{distinct_abscissae, lagrange},
+};
 
-use super::{HandoffKind, SecretShare};
+use super::{cmp_shareholders, Error, HandoffKind, SecretShare};
+
+/// Combines the key shares `(x_i, B(0,x_i) * H)` of at least `threshold + 1`
+/// distinct shareholders into the key `B(0,0) * H` by applying Lagrange
+/// coefficients to the group elements.
+///
+/// Reduced shares require `2 * threshold + 1` key shares, so for those
+/// the threshold of the reduced scheme must be given.
+///
+/// The key shares are sorted in the canonical order of the shareholders
+/// before the Lagrange coefficients are computed, so the result doesn't
+/// depend on the order of the given key shares.
+pub fn combine_key_shares<G: Group>(shares: &[(G::Scalar, G)], threshold: usize) -> Result<G> {
+    if shares.len() <= threshold {
+        return Err(Error::InsufficientKeyShares.into());
+    }
+    if !distinct_abscissae(shares) {
+        return Err(Error::DuplicateShareholder.into());
+    }
+
+    let mut shares = shares.to_vec();
+    shares.sort_unstable_by(|a, b| cmp_shareholders(&a.0, &b.0));
+
+    let (xs, zs): (Vec<_>, Vec<_>) = shares.into_iter().unzip();
+    let cs = lagrange::coefficients(&xs);
+    let key = zip(cs, zs).map(|(c, z)| z * c).sum();
+
+    Ok(key)
+}
 
 /// A constructor of the shared secret.
 pub struct Player {
@@ -20,6 +52,10 @@ impl Player {
     }
 
     /// Recovers the secret from the provided shares.
+    ///
+    /// The shares are sorted in the canonical order of the shareholders
+    /// before the Lagrange coefficients are computed, so the result doesn't
+    /// depend on the order of the given shares.
     pub fn recover_secret<F: PrimeField>(&self, shares: &[SecretShare<F>]) -> Result<F> {
         if shares.len() < self.min_shares() {
             bail!("not enough shares");
@@ -28,6 +64,9 @@ impl Player {
             bail!("not distinct shares");
         }
 
+        let mut shares: Vec<_> = shares.iter().collect();
+        shares.sort_unstable_by(|a, b| cmp_shareholders(a.coordinate_x(), b.coordinate_x()));
+
         let (xs, ys): (Vec<F>, Vec<&F>) = shares
             .iter()
             .map(|s| (s.coordinate_x(), s.coordinate_y()))
@@ -73,12 +112,12 @@ mod tests {
     use rand_core::OsRng;
 
     use crate::{
-        churp::{self, HandoffKind, Shareholder},
+        churp::{self, sort_committee, Error, HandoffKind, Shareholder},
         kdc::{KeyRecoverer, KeySharer},
         suites::{self, p384, GroupDigest},
     };
 
-    use super::Player;
+    use super::{combine_key_shares, Player};
 
     // Types used in tests.
     type Suite = p384::Sha3_384;
@@ -225,4 +264,99 @@ mod tests {
             assert_eq!(key, recovered);
         }
     }
+
+    #[test]
+    fn test_combine_key_shares() {
+        let key_id = b"key id";
+        let dst = b"encode key share";
+        let secret = PrimeField::from_u64(100);
+        let hash = Suite::hash_to_group(key_id, dst).unwrap();
+        let key = hash * secret;
+
+        for (kind, threshold) in [
+            (HandoffKind::DealingPhase, 2),
+            (HandoffKind::CommitteeChanged, 4), // Reduced shares.
+        ] {
+            let dealer = Dealer::new_with_secret(2, secret, &mut OsRng).unwrap();
+            let xs = (1..=7).map(PrimeField::from_u64).collect();
+            let shares = dealer.make_shares(xs, kind);
+            let vm = dealer.verification_matrix();
+            let key_shares: Vec<_> = shares
+                .into_iter()
+                .map(|share| Shareholder::new(share, vm.clone()))
+                .map(|sh| sh.make_key_share::<Suite>(key_id, dst).unwrap())
+                .map(|p| (*p.x(), *p.z()))
+                .collect();
+
+            // Not enough shares.
+            let res = combine_key_shares(&key_shares[..threshold], threshold);
+            assert!(res.is_err());
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::InsufficientKeyShares.to_string()
+            );
+
+            // Duplicate shares.
+            let mut duplicates = key_shares[..threshold].to_vec();
+            duplicates.push(key_shares[0]);
+            let res = combine_key_shares(&duplicates, threshold);
+            assert!(res.is_err());
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::DuplicateShareholder.to_string()
+            );
+
+            // Exact number of shares.
+            let combined = combine_key_shares(&key_shares[..threshold + 1], threshold).unwrap();
+            assert_eq!(combined, key);
+
+            // Too many shares.
+            let combined = combine_key_shares(&key_shares, threshold).unwrap();
+            assert_eq!(combined, key);
+        }
+    }
+
+    #[test]
+    fn test_order_independence() {
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let key_id = b"key id";
+        let dst = b"encode key share";
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+        let player = Player::new(threshold, kind);
+
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+        let mut reversed = xs.clone();
+        reversed.reverse();
+
+        // Secrets.
+        let shares = dealer.make_shares(xs.clone(), kind);
+        let reversed_shares = dealer.make_shares(reversed.clone(), kind);
+        let recovered = player.recover_secret(&shares).unwrap();
+        let reversed_recovered = player.recover_secret(&reversed_shares).unwrap();
+        assert_eq!(recovered, reversed_recovered);
+        assert_eq!(recovered, secret);
+
+        // Keys.
+        let vm = dealer.verification_matrix();
+        let key_shares: Vec<_> = shares
+            .into_iter()
+            .map(|share| Shareholder::new(share, vm.clone()))
+            .map(|sh| sh.make_key_share::<Suite>(key_id, dst).unwrap())
+            .map(|p| (*p.x(), *p.z()))
+            .collect();
+        let mut reversed_key_shares = key_shares.clone();
+        reversed_key_shares.reverse();
+        let key = combine_key_shares(&key_shares, threshold as usize).unwrap();
+        let reversed_key = combine_key_shares(&reversed_key_shares, threshold as usize).unwrap();
+        assert_eq!(key, reversed_key);
+
+        // Canonical order.
+        let mut sorted = reversed;
+        sort_committee(&mut sorted);
+        let mut expected = xs;
+        sort_committee(&mut expected);
+        assert_eq!(sorted, expected);
+    }
 }
@@ -0,0 +1,399 @@
+//! Public part of a CHURP shareholder.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use rand_core::RngCore;
+
+use crate::{
+    poly::{scalar_from_bytes, Polynomial},
+    vss::{VerificationMatrix, VerificationVector},
+};
+
+use super::{Error, Shareholder};
+
+/// The version of the public share byte representation.
+const PUBLIC_SHARE_ENCODING_VERSION: u8 = 1;
+
+/// The size of the header of the public share byte representation,
+/// i.e. the version tag, the kind of the share, the epoch and
+/// the dimensions of the verification matrix.
+const PUBLIC_SHARE_HEADER_SIZE: usize = 14;
+
+/// Public part of a shareholder, which holds everything except the secret
+/// polynomial.
+///
+/// Public shares can be handed out to auditors, who can verify switch points
+/// received by the shareholder, but can't compute any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicShare<G: Group + GroupEncoding> {
+    /// The encoded identity of the shareholder.
+    x: G::Scalar,
+
+    /// Indicates whether the shareholder holds a full share.
+    full_share: bool,
+
+    /// The verification matrix of the shareholder.
+    vm: VerificationMatrix<G>,
+
+    /// The epoch of the shareholder.
+    epoch: u64,
+}
+
+impl<G> PublicShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the encoded identity of the shareholder.
+    pub fn coordinate_x(&self) -> &G::Scalar {
+        &self.x
+    }
+
+    /// Returns true if and only if the shareholder holds a full share.
+    pub fn is_full_share(&self) -> bool {
+        self.full_share
+    }
+
+    /// Returns the verification matrix.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        &self.vm
+    }
+
+    /// Returns the dimensions of the verification matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.vm.dimensions()
+    }
+
+    /// Returns the epoch of the shareholder.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Verifies the switch point received from the given shareholder,
+    /// see [`Shareholder::verify_switch_point`].
+    pub fn verify_switch_point(&self, x: &G::Scalar, bij: &G::Scalar) -> bool {
+        let vv = self.switch_verification_vector();
+        vv.verify(x, bij)
+    }
+
+    /// Verifies the masked switch point received from the given shareholder,
+    /// see [`Shareholder::verify_masked_point`].
+    pub fn verify_masked_point(&self, x: &G::Scalar, masked: &G::Scalar, commitment: &G) -> bool {
+        let vv = self.switch_verification_vector();
+        let diff = vv.eval(x) - (G::generator() * masked - commitment);
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies switch points received from other shareholders and returns
+    /// the indices of the points which failed to verify,
+    /// see [`Shareholder::identify_bad_points`].
+    pub fn identify_bad_points(&self, points: &[(G::Scalar, G::Scalar)]) -> Vec<usize> {
+        let vv = self.switch_verification_vector();
+
+        points
+            .iter()
+            .enumerate()
+            .filter(|(_, (x, bij))| !vv.verify(x, bij))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Verifies switch points received from other shareholders at once,
+    /// see [`Shareholder::verify_switch_points_batch`].
+    pub fn verify_switch_points_batch(
+        &self,
+        points: &[(G::Scalar, G::Scalar)],
+        rng: &mut impl RngCore,
+    ) -> bool {
+        let vv = self.switch_verification_vector();
+        vv.verify_batch(points, rng)
+    }
+
+    /// Returns the verification vector for switch points received from
+    /// other shareholders.
+    fn switch_verification_vector(&self) -> VerificationVector<G> {
+        switch_verification_vector(&self.vm, &self.x, self.full_share)
+    }
+
+    /// Returns the byte representation of the public share.
+    ///
+    /// The representation consists of a version tag, the kind of the share,
+    /// the epoch, encoded as a big-endian 64-bit integer, and the dimensions
+    /// of the verification matrix, encoded as big-endian 16-bit integers,
+    /// followed by the identity and the elements of the verification matrix.
+    ///
+    /// Fails if any dimension of the matrix doesn't fit into the header.
+    /// Matrices derived from a threshold that fits into a byte always fit.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let (rows, cols) = self.vm.dimensions();
+
+        let mut header = [0u16; 2];
+        for (h, n) in header.iter_mut().zip([rows, cols]) {
+            *h = u16::try_from(n).map_err(|_| Error::DeclaredSizeTooLarge)?;
+        }
+        let cap = Self::byte_size(rows, cols)?;
+
+        let mut bytes = Vec::with_capacity(cap);
+        bytes.push(PUBLIC_SHARE_ENCODING_VERSION);
+        bytes.push(self.full_share as u8);
+        bytes.extend_from_slice(&self.epoch.to_be_bytes());
+        for n in header {
+            bytes.extend_from_slice(&n.to_be_bytes());
+        }
+        bytes.extend_from_slice(self.x.to_repr().as_ref());
+        self.vm.write_elements(&mut bytes);
+
+        Ok(bytes)
+    }
+
+    /// Attempts to create a public share from its byte representation.
+    ///
+    /// This method is not constant time since the public share doesn't
+    /// contain sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_limit(bytes, (usize::MAX, usize::MAX))
+    }
+
+    /// Attempts to create a public share from its byte representation,
+    /// rejecting representations whose encoded verification matrix
+    /// dimensions exceed the given maximum dimensions.
+    ///
+    /// The dimensions are checked before anything is decoded.
+    ///
+    /// This method is not constant time since the public share doesn't
+    /// contain sensitive information.
+    pub fn from_bytes_with_limit(bytes: &[u8], max_dimensions: (usize, usize)) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(Error::InvalidEncoding.into());
+        }
+        if bytes[0] != PUBLIC_SHARE_ENCODING_VERSION {
+            return Err(Error::UnsupportedEncodingVersion.into());
+        }
+        if bytes.len() < PUBLIC_SHARE_HEADER_SIZE {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let full_share = match bytes[1] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::InvalidEncoding.into()),
+        };
+        let mut epoch = [0u8; 8];
+        epoch.copy_from_slice(&bytes[2..10]);
+        let epoch = u64::from_be_bytes(epoch);
+
+        // Verify that the length of the slice matches the declared
+        // dimensions of the verification matrix.
+        let read_u16 = |i: usize| u16::from_be_bytes([bytes[i], bytes[i + 1]]) as usize;
+        let rows = read_u16(10);
+        let cols = read_u16(12);
+
+        VerificationMatrix::<G>::check_dimensions((rows, cols), max_dimensions)
+            .map_err(|_| Error::DeclaredSizeTooLarge)?;
+        if bytes.len() != Self::byte_size(rows, cols)? {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let x_start = PUBLIC_SHARE_HEADER_SIZE;
+        let vm_start = x_start + Polynomial::<G::Scalar>::coefficient_byte_size();
+
+        let x = scalar_from_bytes(&bytes[x_start..vm_start]).ok_or(Error::InvalidEncoding)?;
+        let vm = VerificationMatrix::from_element_bytes(rows, cols, &bytes[vm_start..])
+            .map_err(|_| Error::InvalidEncoding)?;
+
+        Ok(PublicShare {
+            x,
+            full_share,
+            vm,
+            epoch,
+        })
+    }
+
+    /// Returns the size of the byte representation of a public share
+    /// with the given matrix dimensions.
+    ///
+    /// Fails if the size overflows, which untrusted headers can cause
+    /// on targets with a narrow `usize`.
+    fn byte_size(rows: usize, cols: usize) -> Result<usize> {
+        let vm_size = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(VerificationMatrix::<G>::element_byte_size()))
+            .ok_or(Error::DeclaredSizeTooLarge)?;
+
+        (PUBLIC_SHARE_HEADER_SIZE + Polynomial::<G::Scalar>::coefficient_byte_size())
+            .checked_add(vm_size)
+            .ok_or(Error::DeclaredSizeTooLarge.into())
+    }
+}
+
+impl<G> Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the public part of the shareholder, i.e. its identity,
+    /// the kind of its share, its verification matrix and its epoch,
+    /// without the secret polynomial.
+    pub fn public_part(&self) -> PublicShare<G> {
+        let share = self.verifiable_share().secret_share();
+        let vm = self.verifiable_share().verification_matrix();
+        let (_, cols) = vm.dimensions();
+
+        PublicShare {
+            x: *share.coordinate_x(),
+            full_share: share.polynomial().size() == cols,
+            vm: vm.clone(),
+            epoch: self.epoch(),
+        }
+    }
+}
+
+/// Returns the verification vector for switch points received by
+/// the shareholder with the given identity and kind of share.
+pub(crate) fn switch_verification_vector<G>(
+    vm: &VerificationMatrix<G>,
+    me: &G::Scalar,
+    full_share: bool,
+) -> VerificationVector<G>
+where
+    G: Group + GroupEncoding,
+{
+    match full_share {
+        true => vm.verification_vector_for_x(me), // Points B(x,me).
+        false => vm.verification_vector_for_y(me), // Points B(me,y).
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, Error, HandoffKind},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type PublicShare = churp::PublicShare<Group>;
+    type Shareholder = churp::Shareholder<Group>;
+
+    fn prepare_shareholder(dealer: &Dealer, x: u64, kind: HandoffKind) -> Shareholder {
+        let x = PrimeField::from_u64(x);
+        let share = dealer.make_share(x, kind);
+        let vm = dealer.verification_matrix().clone();
+        Shareholder::new(share, vm)
+    }
+
+    #[test]
+    fn test_public_part() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for threshold in [0, 2] {
+            let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+            for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+                let me = prepare_shareholder(&dealer, 1, kind).with_epoch(7);
+                let public = me.public_part();
+                let x = *public.coordinate_x();
+                let peer = prepare_shareholder(&dealer, 2, kind);
+                let y = *peer.verifiable_share().secret_share().coordinate_x();
+
+                assert_eq!(public.epoch(), 7);
+                // Full and reduced shares coincide under threshold zero.
+                let full_share = kind != HandoffKind::CommitteeChanged || threshold == 0;
+                assert_eq!(public.is_full_share(), full_share);
+                assert_eq!(
+                    public.dimensions(),
+                    dealer.verification_matrix().dimensions()
+                );
+
+                // The public share verifies points exactly like the full
+                // shareholder, but has no means to compute one, so the point
+                // must be computed by the peer.
+                let bij = peer.switch_point(&x);
+                assert!(public.verify_switch_point(&y, &bij));
+                assert!(me.verify_switch_point(&y, &bij));
+
+                let tampered = bij + PrimeField::ONE;
+                assert!(!public.verify_switch_point(&y, &tampered));
+                assert!(!me.verify_switch_point(&y, &tampered));
+
+                let points = [(y, bij), (y, tampered)];
+                assert_eq!(public.identify_bad_points(&points), vec![1]);
+                assert!(!public.verify_switch_points_batch(&points, &mut rng));
+                assert!(public.verify_switch_points_batch(&points[..1], &mut rng));
+
+                let mask = PrimeField::from_u64(5);
+                let (masked, commitment) = peer.masked_switch_point(&x, &mask);
+                assert!(public.verify_masked_point(&y, &masked, &commitment));
+                assert!(!public.verify_masked_point(&y, &tampered, &commitment));
+            }
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let public = prepare_shareholder(&dealer, 1, kind)
+                .with_epoch(u64::MAX)
+                .public_part();
+            let bytes = public.to_bytes().unwrap();
+            let restored = PublicShare::from_bytes(&bytes).unwrap();
+            assert_eq!(restored, public);
+
+            // The secret polynomial is not encoded.
+            let (rows, cols) = public.dimensions();
+            assert_eq!(bytes.len(), 14 + 48 + rows * cols * 49);
+
+            let res = PublicShare::from_bytes_with_limit(&bytes, (rows, cols - 1));
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DeclaredSizeTooLarge.to_string()
+            );
+
+            let mut invalid = bytes.clone();
+            invalid[0] = 2;
+            let res = PublicShare::from_bytes(&invalid);
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::UnsupportedEncodingVersion.to_string()
+            );
+
+            let mut invalid = bytes.clone();
+            invalid[1] = 2;
+            let res = PublicShare::from_bytes(&invalid);
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidEncoding.to_string()
+            );
+
+            for invalid in [&[][..], &bytes[..13], &bytes[..bytes.len() - 1]] {
+                let res = PublicShare::from_bytes(invalid);
+                assert!(res.is_err());
+                assert_eq!(
+                    res.err().unwrap().to_string(),
+                    Error::InvalidEncoding.to_string()
+                );
+            }
+        }
+
+        // Sizes that overflow.
+        for (rows, cols) in [(usize::MAX, 2), (2, usize::MAX)] {
+            let res = PublicShare::byte_size(rows, cols);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DeclaredSizeTooLarge.to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Sealing of secret shares for transport.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use anyhow::Result;
+use deoxysii::{DeoxysII, KEY_SIZE, NONCE_SIZE, TAG_SIZE};
+use group::{ff::PrimeField, Group, GroupEncoding};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::{
+    poly::{scalar_from_bytes, Polynomial},
+    vss::VerificationMatrix,
+};
+
+use super::{check_shape, Error, SecretShare, VerifiableSecretShare};
+
+/// The version of the encrypted polynomial byte representation.
+const ENCRYPTED_POLYNOMIAL_ENCODING_VERSION: u8 = 1;
+
+/// The domain separation tag of the additional data, which binds sealed
+/// shares to their verification matrices.
+const SEAL_DST: &[u8] = b"oasis-core/secret-sharing: sealed share";
+
+/// Secret share sealed with Deoxys-II-256-128, so that it can be sent over
+/// untrusted channels alongside its verification matrix in the clear.
+///
+/// The share can only be opened with the key and the verification matrix
+/// it was sealed with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedPolynomial<F: PrimeField> {
+    /// The nonce used to seal the share.
+    nonce: [u8; NONCE_SIZE],
+
+    /// The sealed identity and secret polynomial, followed by the tag.
+    ciphertext: Vec<u8>,
+
+    _marker: PhantomData<F>,
+}
+
+impl<F> EncryptedPolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Seals the given secret share with the given key, using a random
+    /// nonce, and binds it to the given verification matrix.
+    ///
+    /// Fails if the random number generator fails.
+    pub fn seal<G>(
+        share: &SecretShare<F>,
+        vm: &VerificationMatrix<G>,
+        key: &[u8; KEY_SIZE],
+        rng: &mut impl RngCore,
+    ) -> Result<Self>
+    where
+        G: Group<Scalar = F> + GroupEncoding,
+    {
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.try_fill_bytes(&mut nonce)
+            .map_err(|_| Error::RngFailure)?;
+
+        let cap = Polynomial::<F>::byte_size(1 + share.p.size());
+        let mut plaintext = Vec::with_capacity(cap);
+        plaintext.extend_from_slice(share.x.to_repr().as_ref());
+        for ai in &share.p.a {
+            plaintext.extend_from_slice(ai.to_repr().as_ref());
+        }
+
+        let ciphertext = DeoxysII::new(key).seal(&nonce, &plaintext, additional_data(vm));
+        plaintext.zeroize();
+
+        Ok(Self {
+            nonce,
+            ciphertext,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Opens the sealed secret share with the given key and verifies that
+    /// it was bound to the given verification matrix.
+    ///
+    /// Fails if the key or the matrix differ from those used to seal
+    /// the share, or if the share doesn't fit the shape of the matrix.
+    pub fn open<G>(
+        &self,
+        vm: &VerificationMatrix<G>,
+        key: &[u8; KEY_SIZE],
+    ) -> Result<SecretShare<F>>
+    where
+        G: Group<Scalar = F> + GroupEncoding,
+    {
+        let mut plaintext = DeoxysII::new(key)
+            .open(&self.nonce, self.ciphertext.clone(), additional_data(vm))
+            .map_err(|_| Error::DecryptionFailed)?;
+
+        let res = Self::decode(&plaintext, vm);
+        plaintext.zeroize();
+
+        res
+    }
+
+    /// Decodes the identity and the secret polynomial of an opened share.
+    fn decode<G>(plaintext: &[u8], vm: &VerificationMatrix<G>) -> Result<SecretShare<F>>
+    where
+        G: Group<Scalar = F> + GroupEncoding,
+    {
+        let size = Polynomial::<F>::coefficient_byte_size();
+        if plaintext.len() < size {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let (_, cols) = vm.dimensions();
+        let x = scalar_from_bytes(&plaintext[..size]).ok_or(Error::InvalidEncoding)?;
+        let p = Polynomial::from_bytes_with_limit(&plaintext[size..], cols.saturating_sub(1))
+            .ok_or(Error::InvalidEncoding)?;
+        check_shape(&p, vm)?;
+
+        Ok(SecretShare::new(x, p))
+    }
+
+    /// Returns the byte representation of the sealed share.
+    ///
+    /// The representation consists of a version tag and the nonce,
+    /// followed by the ciphertext and the tag.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + NONCE_SIZE + self.ciphertext.len());
+        bytes.push(ENCRYPTED_POLYNOMIAL_ENCODING_VERSION);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.ciphertext);
+
+        bytes
+    }
+
+    /// Attempts to create a sealed share from its byte representation.
+    ///
+    /// The ciphertext is authenticated only when the share is opened.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(Error::InvalidEncoding.into());
+        }
+        if bytes[0] != ENCRYPTED_POLYNOMIAL_ENCODING_VERSION {
+            return Err(Error::UnsupportedEncodingVersion.into());
+        }
+        if bytes.len() < 1 + NONCE_SIZE + TAG_SIZE {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let mut nonce = [0u8; NONCE_SIZE];
+        nonce.copy_from_slice(&bytes[1..1 + NONCE_SIZE]);
+        let ciphertext = bytes[1 + NONCE_SIZE..].to_vec();
+
+        Ok(Self {
+            nonce,
+            ciphertext,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<G> VerifiableSecretShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Splits the verifiable share into the secret share, sealed with
+    /// the given key, and the verification matrix, which can be sent
+    /// in the clear, see [`EncryptedPolynomial::seal`].
+    ///
+    /// Fails if the random number generator fails.
+    pub fn into_parts(
+        self,
+        key: &[u8; KEY_SIZE],
+        rng: &mut impl RngCore,
+    ) -> Result<(EncryptedPolynomial<G::Scalar>, VerificationMatrix<G>)> {
+        let (share, vm): (SecretShare<G::Scalar>, VerificationMatrix<G>) = self.into();
+        let sealed = EncryptedPolynomial::seal(&share, &vm, key, rng)?;
+
+        Ok((sealed, vm))
+    }
+
+    /// Recombines the verifiable share from the sealed secret share
+    /// and the verification matrix, see [`EncryptedPolynomial::open`].
+    ///
+    /// Fails if the share cannot be opened with the given key and matrix.
+    pub fn from_parts(
+        sealed: &EncryptedPolynomial<G::Scalar>,
+        vm: VerificationMatrix<G>,
+        key: &[u8; KEY_SIZE],
+    ) -> Result<Self> {
+        let share = sealed.open(&vm, key)?;
+
+        Ok(Self::new(share, vm))
+    }
+}
+
+/// Returns the additional data binding a sealed share to the given
+/// verification matrix.
+fn additional_data<G>(vm: &VerificationMatrix<G>) -> Vec<u8>
+where
+    G: Group + GroupEncoding,
+{
+    let mut ad = SEAL_DST.to_vec();
+    ad.extend_from_slice(&vm.to_bytes());
+
+    ad
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, Error, HandoffKind},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type EncryptedPolynomial = churp::EncryptedPolynomial<PrimeField>;
+    type VerifiableSecretShare = churp::VerifiableSecretShare<Group>;
+
+    #[test]
+    fn test_seal_open() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let key = [7u8; 32];
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let dealer = Dealer::new(2, &mut rng).unwrap();
+            let x = PrimeField::from_u64(1);
+            let share = dealer.make_share(x, kind);
+            let vm = dealer.verification_matrix().clone();
+            let verifiable_share = VerifiableSecretShare::new(share.clone(), vm.clone());
+
+            // Round trip through the byte representation.
+            let (sealed, public_vm) = verifiable_share.into_parts(&key, &mut rng).unwrap();
+            assert!(public_vm == vm);
+            let sealed = EncryptedPolynomial::from_bytes(&sealed.to_bytes()).unwrap();
+            let restored = VerifiableSecretShare::from_parts(&sealed, public_vm, &key).unwrap();
+            assert!(*restored.secret_share() == share);
+            assert!(*restored.verification_matrix() == vm);
+
+            // Wrong key.
+            let res = sealed.open(&vm, &[8u8; 32]);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DecryptionFailed.to_string()
+            );
+
+            // Wrong verification matrix.
+            let other = Dealer::new(2, &mut rng).unwrap();
+            let res = sealed.open(other.verification_matrix(), &key);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DecryptionFailed.to_string()
+            );
+
+            // Tampered ciphertext.
+            let mut bytes = sealed.to_bytes();
+            let last = bytes.len() - 1;
+            bytes[last] ^= 1;
+            let tampered = EncryptedPolynomial::from_bytes(&bytes).unwrap();
+            let res = tampered.open(&vm, &key);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DecryptionFailed.to_string()
+            );
+
+            // Fresh nonces yield distinct ciphertexts.
+            let again = EncryptedPolynomial::seal(&share, &vm, &key, &mut rng).unwrap();
+            assert_ne!(again, sealed);
+            assert!(again.open(&vm, &key).unwrap() == share);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let res = EncryptedPolynomial::from_bytes(&[]);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        let res = EncryptedPolynomial::from_bytes(&[2; 64]);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::UnsupportedEncodingVersion.to_string()
+        );
+
+        let res = EncryptedPolynomial::from_bytes(&[1; 31]);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+    }
+}
@@ -1,20 +1,40 @@
 //! CHURP shareholder.
 
+use alloc::vec::Vec;
+use core::{cmp::Ordering, convert::TryFrom, fmt, iter::zip, mem};
+
 use anyhow::Result;
 use group::{
     ff::{Field, PrimeField},
     Group, GroupEncoding,
 };
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use crate::{
-    kdc::PointShareholder, poly::Polynomial, suites::FieldDigest, vss::VerificationMatrix,
+    kdc::PointShareholder,
+    poly::{
+        distinct_abscissae,
+        lagrange::{recover_with_basis, LagrangeBasis},
+        sample_nonzero_scalar, scalar_from_bytes, BivariatePolynomial, CheckedRng, Polynomial,
+    },
+    suites::FieldDigest,
+    vss::{VerificationMatrix, VerificationVector},
 };
 
-use super::Error;
+#[cfg(any(test, feature = "deterministic"))]
+use super::Dealer;
+use super::{switch_verification_vector, Error, HandoffKind};
 
 /// Encodes the given shareholder ID to a non-zero element of the prime field.
+///
+/// The domain separation tag scopes the encoding, so that applications
+/// can use a distinct tag per protocol, version or instance to prevent
+/// encodings from colliding across deployments.
 pub fn encode_shareholder<H: FieldDigest>(id: &[u8], dst: &[u8]) -> Result<H::Output> {
-    let s = H::hash_to_field(id, dst).map_err(|_| Error::ShareholderEncodingFailed)?;
+    let s =
+        H::hash_to_field(id, dst).map_err(|err| err.context(Error::ShareholderEncodingFailed))?;
 
     if s.is_zero().into() {
         return Err(Error::ZeroValueShareholder.into());
@@ -23,12 +43,210 @@ pub fn encode_shareholder<H: FieldDigest>(id: &[u8], dst: &[u8]) -> Result<H::Ou
     Ok(s)
 }
 
+/// Encodes the given shareholder ID to a non-zero element of the prime field,
+/// retrying with a counter instead of failing on the zero element.
+///
+/// Each try hashes the ID with a single-byte counter appended, starting
+/// at zero, and the first non-zero element is returned. Since the counter
+/// has a fixed size, distinct pairs of IDs and counters never hash the same
+/// input, yet the encodings differ from those of [`encode_shareholder`].
+///
+/// Fails if all `max_tries` tries yield zero.
+pub fn encode_shareholder_nonzero<H: FieldDigest>(
+    id: &[u8],
+    dst: &[u8],
+    max_tries: u8,
+) -> Result<H::Output> {
+    let mut msg = Vec::with_capacity(id.len() + 1);
+    msg.extend_from_slice(id);
+    msg.push(0);
+
+    for counter in 0..max_tries {
+        msg[id.len()] = counter;
+        let s = H::hash_to_field(&msg, dst)
+            .map_err(|err| err.context(Error::ShareholderEncodingFailed))?;
+
+        if !bool::from(s.is_zero()) {
+            return Ok(s);
+        }
+    }
+
+    Err(Error::ShareholderEncodingExhausted.into())
+}
+
+/// Compares the given encoded shareholder IDs by their byte representations.
+///
+/// The ordering is canonical, so that all nodes agree on the order
+/// of the committee members regardless of the order in which they
+/// learned about them.
+pub fn cmp_shareholders<F: PrimeField>(a: &F, b: &F) -> Ordering {
+    a.to_repr().as_ref().cmp(b.to_repr().as_ref())
+}
+
+/// Sorts the given encoded shareholder IDs in the canonical order.
+///
+/// See [`cmp_shareholders`] for details.
+pub fn sort_committee<F: PrimeField>(ids: &mut [F]) {
+    ids.sort_unstable_by(cmp_shareholders);
+}
+
+/// The domain separation tag used to derive committee digests.
+const COMMITTEE_DIGEST_DST: &[u8] = b"oasis-core/secret-sharing: committee digest";
+
+/// Computes a digest of the committee with the given encoded shareholder IDs.
+///
+/// The IDs are absorbed in the canonical order, prefixed with their number,
+/// so that the digest does not depend on the order of the input.
+pub fn committee_digest<H, F>(ids: &[F]) -> Result<H::Output>
+where
+    H: FieldDigest,
+    F: PrimeField,
+{
+    let mut ids = ids.to_vec();
+    sort_committee(&mut ids);
+
+    let size = F::Repr::default().as_ref().len();
+    let mut transcript = Vec::with_capacity(8 + ids.len() * size);
+    transcript.extend_from_slice(&(ids.len() as u64).to_be_bytes());
+    for id in &ids {
+        transcript.extend_from_slice(id.to_repr().as_ref());
+    }
+
+    H::hash_to_field(&transcript, COMMITTEE_DIGEST_DST)
+}
+
+/// Returns the number of distinct points required to interpolate
+/// a polynomial of degree `threshold`, i.e. `threshold + 1`.
+pub fn required_points(threshold: usize) -> usize {
+    threshold + 1
+}
+
+/// Selects the first `threshold + 1` of the given switch points and
+/// returns their abscissae and ordinates.
+///
+/// Fails if there are not enough points.
+fn select_points<F: PrimeField>(points: &[(F, F)], threshold: u8) -> Result<(Vec<F>, Vec<F>)> {
+    let n = required_points(threshold as usize);
+    if points.len() < n {
+        return Err(Error::NotEnoughSwitchPoints.into());
+    }
+
+    Ok(points[..n].iter().cloned().unzip())
+}
+
+/// Creates the Lagrange basis for the abscissae of the selected switch
+/// points, see [`select_points`].
+///
+/// Fails if the abscissae are not distinct, i.e. if a shareholder sent
+/// more than one of the selected points.
+fn interpolation_basis<F: PrimeField>(xs: &[F]) -> Result<LagrangeBasis<F>> {
+    LagrangeBasis::new(xs).map_err(|_| Error::DuplicateShareholder.into())
+}
+
+/// Verifies that the given polynomial can be a full or a reduced share
+/// for the given verification matrix, i.e. that its size matches either
+/// dimension of the matrix.
+///
+/// This is the single place where the compatibility of polynomial degrees
+/// and matrix dimensions is defined. It does not verify that the matrix
+/// commits to the polynomial.
+pub fn check_shape<G>(p: &Polynomial<G::Scalar>, vm: &VerificationMatrix<G>) -> Result<()>
+where
+    G: Group + GroupEncoding,
+{
+    check_share_size(p.size(), vm.dimensions())
+}
+
+/// Verifies that a polynomial of the given size can be a full or
+/// a reduced share for a verification matrix of the given dimensions,
+/// see [`check_shape`].
+fn check_share_size(size: usize, (rows, cols): (usize, usize)) -> Result<()> {
+    if size != rows && size != cols {
+        return Err(Error::PolynomialDegreeMismatch {
+            expected: cols.saturating_sub(1),
+            actual: size.saturating_sub(1),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the common epoch of the given shareholders.
+///
+/// Reconstruction combines points derived from shares of the same handoff
+/// only, so callers can check the contributing shareholders beforehand
+/// to catch stale shares.
+///
+/// Fails if no shareholders are given or if their epochs differ.
+pub fn common_epoch<'a, G, I>(shareholders: I) -> Result<u64>
+where
+    G: Group + GroupEncoding,
+    I: IntoIterator<Item = &'a Shareholder<G>>,
+{
+    let mut shareholders = shareholders.into_iter();
+    let epoch = shareholders
+        .next()
+        .ok_or(Error::NotEnoughShareholders)?
+        .epoch();
+    if shareholders.any(|s| s.epoch() != epoch) {
+        return Err(Error::EpochMismatch.into());
+    }
+
+    Ok(epoch)
+}
+
+/// Verifies the opening of a switch point, see
+/// [`Shareholder::switch_point_with_proof`].
+///
+/// The switch point `B(x,y)` lies at the given coordinates of the bivariate
+/// polynomial, i.e. at `(me, id)` if the sender `me` holds a full share
+/// `B(me,y)`, and at `(id, me)` if it holds a reduced share `B(x,me)`.
+/// The opening is valid if the commitment commits to the point and matches
+/// the evaluation of the verification matrix at the coordinates, so that
+/// the receiver needs only the matrix of the dealing, not a shareholder.
+pub fn verify_switch_point_proof<G>(
+    point: &G::Scalar,
+    commitment: &G,
+    vm: &VerificationMatrix<G>,
+    x: &G::Scalar,
+    y: &G::Scalar,
+) -> bool
+where
+    G: Group + GroupEncoding,
+{
+    let vv = VerificationVector::new(vm.eval_at(x));
+    let opened = (G::generator() * point - commitment).is_identity();
+    let committed = (vv.eval(y) - commitment).is_identity();
+
+    (opened & committed).into()
+}
+
+/// The version of the shareholder byte representation.
+const SHAREHOLDER_ENCODING_VERSION: u8 = 1;
+
+/// The size of the header of the shareholder byte representation,
+/// i.e. the version tag, the size of the polynomial and the dimensions
+/// of the verification matrix.
+const SHAREHOLDER_HEADER_SIZE: usize = 7;
+
 /// Shareholder is responsible for deriving key shares and generating
 /// switch points during handoffs when the committee is trying
 /// to switch to the other dimension.
+///
+/// Clones are deep copies of the secret share, and equality is not
+/// constant time, see [`SecretShare`].
+#[derive(Clone, PartialEq, Eq)]
 pub struct Shareholder<G: Group + GroupEncoding> {
     /// Verifiable secret (full or reduced) share of the shared secret.
     verifiable_share: VerifiableSecretShare<G>,
+
+    /// The encoded identity of the shareholder, if bound at construction.
+    id: Option<G::Scalar>,
+
+    /// The number of times the share has been proactivized since
+    /// construction, unless set explicitly.
+    epoch: u64,
 }
 
 impl<G> Shareholder<G>
@@ -40,211 +258,3476 @@ where
         VerifiableSecretShare::new(share, vm).into()
     }
 
+    /// Creates a new shareholder, verifying that the size of the polynomial
+    /// matches either dimension of the verification matrix, i.e. that it
+    /// can be a full or a reduced share.
+    ///
+    /// Unlike [`Self::verify`], this does not verify that the matrix
+    /// commits to the polynomial.
+    pub fn try_new(share: SecretShare<G::Scalar>, vm: VerificationMatrix<G>) -> Result<Self> {
+        check_shape(&share.p, &vm)?;
+        Ok(Self::new(share, vm))
+    }
+
+    /// Creates a new shareholder with a share of the given bivariate
+    /// polynomial, deriving the verification matrix from the polynomial.
+    pub fn from_polynomial(
+        bp: &BivariatePolynomial<G::Scalar>,
+        x: G::Scalar,
+        kind: HandoffKind,
+    ) -> Self {
+        let p = match kind {
+            HandoffKind::DealingPhase => bp.eval_x(&x),
+            HandoffKind::CommitteeUnchanged => bp.eval_x(&x),
+            HandoffKind::CommitteeChanged => bp.eval_y(&x),
+        };
+        let share = SecretShare::new(x, p);
+        let vm = VerificationMatrix::from_polynomial(bp);
+
+        Self::new(share, vm)
+    }
+
+    /// Creates a new shareholder bound to the given encoded identity.
+    ///
+    /// Fails if the share doesn't belong to the shareholder with the given
+    /// identity, i.e. if its x-coordinate differs from the identity.
+    pub fn new_with_id(
+        id: G::Scalar,
+        share: SecretShare<G::Scalar>,
+        vm: VerificationMatrix<G>,
+    ) -> Result<Self> {
+        if share.x != id {
+            return Err(Error::ShareholderIdentityMismatch.into());
+        }
+
+        Ok(Shareholder {
+            verifiable_share: VerifiableSecretShare::new(share, vm),
+            id: Some(id),
+            epoch: 0,
+        })
+    }
+
+    /// Returns the encoded identity of the shareholder, if it was bound
+    /// at construction.
+    pub fn id(&self) -> Option<&G::Scalar> {
+        self.id.as_ref()
+    }
+
+    /// Returns the epoch of the share, i.e. the handoff it belongs to.
+    ///
+    /// New shareholders start at epoch zero, and every proactivization
+    /// or rerandomization advances the epoch by one.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Sets the epoch of the share, e.g. when restoring a shareholder
+    /// from its byte representation, which doesn't include the epoch.
+    pub fn with_epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Creates a new shareholder whose share is derived from a dealer
+    /// generated deterministically from the given seed.
+    ///
+    /// See [`Dealer::new_deterministic`] for details.
+    #[cfg(any(test, feature = "deterministic"))]
+    pub fn generate_deterministic(
+        threshold: u8,
+        seed: &[u8; 32],
+        x: G::Scalar,
+        kind: HandoffKind,
+    ) -> Result<Self> {
+        let dealer = Dealer::new_deterministic(threshold, seed)?;
+        let share = dealer.make_share(x, kind);
+        let vm = dealer.verification_matrix().clone();
+        Ok(Self::new(share, vm))
+    }
+
     /// Returns the verifiable secret share.
     pub fn verifiable_share(&self) -> &VerifiableSecretShare<G> {
         &self.verifiable_share
     }
 
+    /// Computes the key share for the given key hash, i.e. the product
+    /// of the hash and the constant term of the secret polynomial.
+    ///
+    /// The result is none if the secret polynomial has no constant term.
+    /// The key share is computed and selected in constant time, so that
+    /// the absence of the constant term doesn't introduce a data-dependent
+    /// branch.
+    pub fn key_share_ct(&self, hash: G) -> CtOption<G> {
+        let s = self.verifiable_share.share.p.coefficient(0);
+        let is_some = Choice::from(s.is_some() as u8);
+        let s = s.copied().unwrap_or(G::Scalar::ZERO);
+
+        CtOption::new(hash * s, is_some)
+    }
+
+    /// Computes the key share for the given key hash, i.e. the product
+    /// of the hash and the constant term of the secret polynomial.
+    ///
+    /// Fails if the secret polynomial has no constant term.
+    pub fn key_share(&self, hash: G) -> Result<G> {
+        Option::from(self.key_share_ct(hash)).ok_or(Error::EmptyPolynomial.into())
+    }
+
+    /// Computes the key shares for the given key hashes, i.e. the products
+    /// of the hashes and the constant term of the secret polynomial.
+    ///
+    /// The constant term is looked up once for the whole batch.
+    /// Fails if the secret polynomial has no constant term.
+    pub fn key_shares(&self, hashes: &[G]) -> Result<Vec<G>> {
+        let s = self
+            .verifiable_share
+            .share
+            .p
+            .coefficient(0)
+            .ok_or(Error::EmptyPolynomial)?;
+
+        Ok(hashes.iter().map(|hash| *hash * s).collect())
+    }
+
     /// Computes switch point for the given shareholder.
+    ///
+    /// The secret polynomial is evaluated in constant time.
     pub fn switch_point(&self, x: &G::Scalar) -> G::Scalar {
         self.verifiable_share.share.p.eval(x)
     }
 
-    /// Creates a new shareholder with a proactivized secret polynomial.
-    pub fn proactivize(
+    /// Computes switch point for the given shareholder, together with
+    /// the commitment `point * G` to the point.
+    ///
+    /// The commitment lets the receiver verify the point against
+    /// the verification matrix alone, see [`verify_switch_point_proof`].
+    pub fn switch_point_with_proof(&self, id: &G::Scalar) -> (G::Scalar, G) {
+        let point = self.switch_point(id);
+        (point, G::generator() * point)
+    }
+
+    /// Computes switch points for the given shareholders.
+    ///
+    /// The returned points are in the same order as the shareholders.
+    pub fn switch_points(&self, xs: &[G::Scalar]) -> Vec<G::Scalar> {
+        self.verifiable_share.share.p.eval_many(xs)
+    }
+
+    /// Reshares the secret point of the shareholder among the members
+    /// of the new committee under the given threshold.
+    ///
+    /// The secret point `s = B(me,0)` or `s = B(0,me)` is shared using
+    /// a fresh random polynomial `g(x)` of degree `new_threshold` with
+    /// `g(0) = s`, and the returned points `(z, g(z))` are meant for
+    /// the members `z` of the new committee. Once every member of the new
+    /// committee receives points from enough old shareholders, it combines
+    /// them using the Lagrange coefficients of the old shareholders
+    /// to obtain its share of the same secret under the new threshold.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn reshare<R: RngCore>(
         &self,
-        p: &Polynomial<G::Scalar>,
-        vm: &VerificationMatrix<G>,
-    ) -> Result<Shareholder<G>> {
-        if p.size() != self.verifiable_share.share.p.size() {
-            return Err(Error::PolynomialDegreeMismatch.into());
+        new_committee: &[G::Scalar],
+        new_threshold: usize,
+        rng: &mut R,
+    ) -> Result<Vec<(G::Scalar, G::Scalar)>> {
+        let deg = u8::try_from(new_threshold).map_err(|_| Error::ThresholdTooLarge)?;
+        if new_threshold >= new_committee.len() {
+            return Err(Error::ThresholdTooLarge.into());
         }
-        if !vm.is_zero_hole() {
-            return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+        if new_committee.iter().any(|z| z.is_zero().into()) {
+            return Err(Error::ZeroValueShareholder.into());
         }
-        if vm.dimensions() != self.verifiable_share.vm.dimensions() {
-            return Err(Error::VerificationMatrixDimensionMismatch.into());
+        let points: Vec<_> = new_committee.iter().map(|z| (*z, ())).collect();
+        if !distinct_abscissae(&points) {
+            return Err(Error::DuplicateShareholder.into());
         }
 
-        let x = self.verifiable_share.share.x;
-        let p = p + &self.verifiable_share.share.p;
-        let vm = vm + &self.verifiable_share.vm;
-        let share = SecretShare::new(x, p);
-        let shareholder = Shareholder::new(share, vm);
-
-        Ok(shareholder)
-    }
-}
+        let s = *self.verifiable_share.share.coordinate_y();
+        let mut rng = CheckedRng::new(rng);
+        let mut g = Polynomial::random_with_constant(s, deg, &mut rng);
+        if rng.failed() {
+            g.zeroize();
+            return Err(Error::RngFailure.into());
+        }
+        let points = new_committee.iter().map(|z| (*z, g.eval(z))).collect();
+        g.zeroize();
 
-impl<G> From<VerifiableSecretShare<G>> for Shareholder<G>
-where
-    G: Group + GroupEncoding,
-{
-    fn from(verifiable_share: VerifiableSecretShare<G>) -> Shareholder<G> {
-        Shareholder { verifiable_share }
+        Ok(points)
     }
-}
 
-impl<G> PointShareholder<G::Scalar> for Shareholder<G>
-where
-    G: Group + GroupEncoding,
-{
-    fn coordinate_x(&self) -> &G::Scalar {
-        self.verifiable_share.share.coordinate_x()
+    /// Verifies the switch point received from the given shareholder.
+    ///
+    /// The other shareholder holds a share of the same kind, so the received
+    /// point `B(x,me)` or `B(me,y)` lies in the other dimension. The point
+    /// is verified against the verification matrix, so that a point which
+    /// doesn't belong to the bivariate polynomial `B(x,y)` is rejected.
+    pub fn verify_switch_point(&self, x: &G::Scalar, bij: &G::Scalar) -> bool {
+        let vv = self.switch_verification_vector();
+        vv.verify(x, bij)
     }
 
-    fn coordinate_y(&self) -> &G::Scalar {
-        self.verifiable_share.share.coordinate_y()
+    /// Computes a masked switch point for the given shareholder.
+    ///
+    /// Returns the switch point offset by the given mask, together with
+    /// the commitment `mask * G` to the mask. The masked point reveals
+    /// nothing about the switch point until the mask is disclosed, yet it
+    /// can be verified against the verification matrix in advance, see
+    /// [`Self::verify_masked_point`].
+    pub fn masked_switch_point(&self, x: &G::Scalar, mask: &G::Scalar) -> (G::Scalar, G) {
+        let bij = self.switch_point(x);
+        (bij + mask, G::generator() * mask)
     }
-}
 
-/// Secret share of the shared secret.
-pub struct SecretShare<F: PrimeField> {
-    /// The encoded identity of the shareholder.
+    /// Computes a masked switch point for the given shareholder using
+    /// a fresh uniformly random non-zero mask.
     ///
-    /// The identity is the x-coordinate of a point on the secret-sharing
-    /// univariate polynomial B(x,0) or B(0,y).
-    pub(crate) x: F,
-
-    /// The secret polynomial B(id,y) or B(x,id).
+    /// Returns the mask, followed by the masked point and the commitment
+    /// to the mask, see [`Self::masked_switch_point`].
     ///
-    /// The constant term of the polynomial is the y-coordinate of a point
-    /// on the secret-sharing univariate polynomial B(x,0) or B(0,y).
-    pub(crate) p: Polynomial<F>,
-}
+    /// Fails if the random number generator fails.
+    pub fn random_masked_switch_point(
+        &self,
+        x: &G::Scalar,
+        rng: &mut impl RngCore,
+    ) -> Result<(G::Scalar, G::Scalar, G)> {
+        let mask = sample_nonzero_scalar(rng).map_err(|err| err.context(Error::RngFailure))?;
+        let (masked, commitment) = self.masked_switch_point(x, &mask);
 
-impl<F> SecretShare<F>
-where
-    F: PrimeField,
-{
-    /// Creates a new secret share.
-    pub fn new(x: F, p: Polynomial<F>) -> Self {
-        Self { x, p }
+        Ok((mask, masked, commitment))
     }
 
-    /// Returns the polynomial.
-    pub fn polynomial(&self) -> &Polynomial<F> {
-        &self.p
+    /// Verifies the masked switch point received from the given shareholder.
+    ///
+    /// The point is valid if it is the switch point `B(x,me)` or `B(me,y)`
+    /// offset by the mask committed to by the given commitment, i.e. if
+    /// `masked * G - commitment` is the commitment to the switch point.
+    pub fn verify_masked_point(&self, x: &G::Scalar, masked: &G::Scalar, commitment: &G) -> bool {
+        let vv = self.switch_verification_vector();
+        let diff = vv.eval(x) - (G::generator() * masked - commitment);
+
+        diff.is_identity().into()
     }
 
-    /// Returns the x-coordinate of a point on the secret-sharing
-    /// univariate polynomial B(x,0) or B(0,y).
-    pub fn coordinate_x(&self) -> &F {
-        &self.x
+    /// Verifies switch points received from other shareholders and returns
+    /// the indices of the points which failed to verify.
+    ///
+    /// Other shareholders hold shares of the same kind, so the received
+    /// points `B(x,me)` or `B(me,y)` lie in the other dimension. They are
+    /// verified against the verification matrix, so that the shareholders
+    /// who submitted invalid points can be identified and excluded.
+    pub fn identify_bad_points(&self, points: &[(G::Scalar, G::Scalar)]) -> Vec<usize> {
+        let vv = self.switch_verification_vector();
+
+        points
+            .iter()
+            .enumerate()
+            .filter(|(_, (x, bij))| !vv.verify(x, bij))
+            .map(|(i, _)| i)
+            .collect()
     }
 
-    /// Returns the y-coordinate of a point on the secret-sharing
-    /// univariate polynomial B(x,0) or B(0,y).
-    pub fn coordinate_y(&self) -> &F {
-        self.p
-            .coefficient(0)
-            .expect("polynomial has at least one term")
+    /// Verifies switch points received from other shareholders at once
+    /// using a random linear combination of the points.
+    ///
+    /// If the method returns false, at least one point is invalid,
+    /// and the invalid points can be identified using
+    /// [`Self::identify_bad_points`].
+    pub fn verify_switch_points_batch(
+        &self,
+        points: &[(G::Scalar, G::Scalar)],
+        rng: &mut impl RngCore,
+    ) -> bool {
+        let vv = self.switch_verification_vector();
+        vv.verify_batch(points, rng)
     }
-}
 
-/// Verifiable secret share of the shared secret.
-pub struct VerifiableSecretShare<G: Group + GroupEncoding> {
-    /// Secret (full or reduced) share of the shared secret.
-    pub(crate) share: SecretShare<G::Scalar>,
+    /// Verifies switch points received from other shareholders at once
+    /// using a linear combination of the points with coefficients derived
+    /// from a transcript challenge instead of a random number generator.
+    ///
+    /// The challenge binds the verification matrix, the points and the given
+    /// context, so the verification is deterministic and can be reproduced
+    /// by anyone holding the same data.
+    pub fn verify_switch_points_batch_fs<H>(
+        &self,
+        points: &[(G::Scalar, G::Scalar)],
+        context: &[u8],
+    ) -> Result<bool>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+    {
+        let size = Polynomial::<G::Scalar>::coefficient_byte_size();
+        let mut transcript = Vec::with_capacity(8 + 2 * size * points.len() + context.len());
+        transcript.extend_from_slice(&(points.len() as u64).to_be_bytes());
+        for (x, bij) in points {
+            transcript.extend_from_slice(x.to_repr().as_ref());
+            transcript.extend_from_slice(bij.to_repr().as_ref());
+        }
+        transcript.extend_from_slice(context);
 
-    /// Verification matrix used to verify that the polynomial in the secret
-    /// share is an evaluation B(x,id) or B(id,y) of the secret bivariate
-    /// polynomial B(x,y).
-    pub(crate) vm: VerificationMatrix<G>,
-}
+        let vm = &self.verifiable_share.vm;
+        let c = vm.transcript_challenge::<H>(&transcript)?;
+        let vv = self.switch_verification_vector();
 
-impl<G> VerifiableSecretShare<G>
-where
-    G: Group + GroupEncoding,
-{
-    /// Creates a new verifiable secret share.
-    pub fn new(share: SecretShare<G::Scalar>, vm: VerificationMatrix<G>) -> Self {
-        Self { share, vm }
+        Ok(vv.verify_batch_with_challenge(points, &c))
     }
 
-    /// Returns the secret share.
-    pub fn secret_share(&self) -> &SecretShare<G::Scalar> {
-        &self.share
+    /// Returns the verification vector for switch points received from
+    /// other shareholders.
+    fn switch_verification_vector(&self) -> VerificationVector<G> {
+        let me = &self.verifiable_share.share.x;
+        let vm = &self.verifiable_share.vm;
+        let (_, cols) = vm.dimensions();
+
+        switch_verification_vector(vm, me, self.verifiable_share.share.p.size() == cols)
     }
 
-    /// Returns the verification matrix.
-    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
-        &self.vm
+    /// Creates a new shareholder with a proactivized secret polynomial.
+    ///
+    /// The proactivization polynomial must be a share of a zero-hole
+    /// bivariate polynomial committed to by the given verification matrix.
+    pub fn proactivize(
+        &self,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Shareholder<G>> {
+        let shareholder = self.proactivize_unverified(p, vm)?;
+        self.verify_proactivization(p, vm)?;
+
+        Ok(shareholder)
     }
 
-    /// Verifies the secret share and the verification matrix.
-    pub fn verify(&self, threshold: u8, zero_hole: bool, full_share: bool) -> Result<()> {
-        self.verify_verification_matrix(threshold, zero_hole)?;
-        self.verify_secret_share(threshold, full_share)?;
+    /// Proactivizes the secret polynomial of the shareholder in place.
+    ///
+    /// This is equivalent to [`Self::proactivize`], but avoids allocating
+    /// a new polynomial and verification matrix. The shareholder is left
+    /// unchanged if the proactivization fails.
+    pub fn proactivize_in_place(
+        &mut self,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()> {
+        self.validate_proactivization(p, vm)?;
+        self.verify_proactivization(p, vm)?;
+
+        self.verifiable_share.share.p += p;
+        self.verifiable_share.vm += vm;
+        self.epoch += 1;
+
         Ok(())
     }
 
-    /// Verifies the verification matrix.
-    fn verify_verification_matrix(&self, threshold: u8, zero_hole: bool) -> Result<()> {
-        let (rows, cols) = Self::calculate_dimensions(threshold);
-
-        if self.vm.dimensions() != (rows, cols) {
-            return Err(Error::VerificationMatrixDimensionMismatch.into());
+    /// Creates new shareholders with proactivized secret polynomials.
+    ///
+    /// Every shareholder is proactivized with the polynomial at the same
+    /// index, which must be its share of the zero-hole bivariate polynomial
+    /// committed to by the given verification matrix. The verification
+    /// matrix is validated once for the whole batch.
+    ///
+    /// Fails if any of the shareholders can't be proactivized, in which
+    /// case the error records the index of the offending shareholder.
+    pub fn proactivize_many(
+        shareholders: &[Shareholder<G>],
+        ps: &[Polynomial<G::Scalar>],
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Vec<Shareholder<G>>> {
+        if shareholders.len() != ps.len() {
+            return Err(Error::PolynomialCountMismatch.into());
         }
-        if self.vm.is_zero_hole() != zero_hole {
+        if !vm.is_zero_hole() {
             return Err(Error::VerificationMatrixZeroHoleMismatch.into());
         }
 
-        // Verify that the bivariate polynomial `B(x, y)`, from which
-        // the verification matrix was constructed and the share was derived,
-        // satisfies the non-zero leading term requirements. Specifically,
-        // the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)` must have
-        // non-zero leading terms.
-        if threshold > 0 {
-            // Skipping the special case where the bivariate polynomial has only
-            // one coefficient. The verification of this coefficient has already
-            // been done above, when we checked if the verification matrix
-            // is zero-hole.
-            let i = rows - 1;
-            let j = cols - 1;
+        zip(shareholders, ps)
+            .enumerate()
+            .map(|(index, (shareholder, p))| {
+                shareholder
+                    .proactivize(p, vm)
+                    .map_err(|err| err.context(Error::ShareholderProactivizationFailed { index }))
+            })
+            .collect()
+    }
 
-            if self.vm.element(i, j).unwrap().is_identity().into() {
-                return Err(Error::InsecureBivariatePolynomial.into());
-            }
-            if self.vm.element(i, 0).unwrap().is_identity().into() {
-                return Err(Error::InsecureBivariatePolynomial.into());
-            }
-            if self.vm.element(0, j).unwrap().is_identity().into() {
-                return Err(Error::InsecureBivariatePolynomial.into());
+    /// Creates a new shareholder whose secret polynomial is masked with
+    /// a random polynomial `q` with zero constant term.
+    ///
+    /// The masking polynomial is committed to by the zero-hole verification
+    /// matrix of `Q(x,y) = q(y)` for full shares and `Q(x,y) = q(x)` for
+    /// reduced shares, and added via [`Self::proactivize`]. The constant
+    /// term, and hence the point on B(x,0) or B(0,y), is preserved, while
+    /// evaluations at other abscissae change, so leaked share data becomes
+    /// useless. Since only this shareholder is rerandomized, its switch
+    /// points no longer match the verification matrices of the others.
+    ///
+    /// Fails if the random number generator fails.
+    pub fn rerandomize<R: RngCore>(&self, rng: &mut R) -> Result<Shareholder<G>> {
+        let share = &self.verifiable_share.share;
+        let (rows, cols) = self.verifiable_share.vm.dimensions();
+        let size = share.p.size();
+        let deg = u8::try_from(size - 1).map_err(|_| Error::ThresholdTooLarge)?;
+        let deg_x = u8::try_from(rows - 1).map_err(|_| Error::ThresholdTooLarge)?;
+        let deg_y = u8::try_from(cols - 1).map_err(|_| Error::ThresholdTooLarge)?;
+
+        let mut rng = CheckedRng::new(rng);
+        let mut q = Polynomial::random_with_constant(G::Scalar::ZERO, deg, &mut rng);
+        if rng.failed() {
+            q.zeroize();
+            return Err(Error::RngFailure.into());
+        }
+
+        let mut bp = BivariatePolynomial::zero(deg_x, deg_y);
+        for (k, qk) in q.coefficients().iter().enumerate() {
+            let updated = match size == cols {
+                true => bp.set_coefficient(0, k, *qk),  // Full share B(me,y).
+                false => bp.set_coefficient(k, 0, *qk), // Reduced share B(x,me).
+            };
+            if !updated {
+                return Err(Error::PolynomialDegreeMismatch {
+                    expected: cols - 1,
+                    actual: size - 1,
+                }
+                .into());
             }
         }
+        let vm = VerificationMatrix::from(&bp);
 
-        Ok(())
+        let res = self.proactivize(&q, &vm);
+        q.zeroize();
+
+        res
     }
 
-    /// Verifies the secret share.
-    fn verify_secret_share(&self, threshold: u8, full_share: bool) -> Result<()> {
-        let (rows, cols) = Self::calculate_dimensions(threshold);
+    /// Creates a new shareholder with a proactivized secret polynomial,
+    /// without verifying that the polynomial is committed to by the given
+    /// verification matrix.
+    ///
+    /// The caller must have verified the proactivization polynomial,
+    /// e.g. by verifying the bivariate shares it was combined from.
+    pub(crate) fn proactivize_unverified(
+        &self,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Shareholder<G>> {
+        self.validate_proactivization(p, vm)?;
 
-        if full_share {
-            if self.share.p.size() != cols {
-                return Err(Error::PolynomialDegreeMismatch.into());
+        let x = self.verifiable_share.share.x;
+        let p = p + &self.verifiable_share.share.p;
+        let vm = vm + &self.verifiable_share.vm;
+        let share = SecretShare::new(x, p);
+        let shareholder = Shareholder {
+            verifiable_share: VerifiableSecretShare::new(share, vm),
+            id: self.id,
+            epoch: self.epoch + 1,
+        };
+
+        Ok(shareholder)
+    }
+
+    /// Merges the given shareholders holding additive sub-shares of the same
+    /// share into a single shareholder.
+    ///
+    /// The secret polynomials and the verification matrices are added,
+    /// as in proactivization, except that neither share needs to be derived
+    /// from a zero-hole bivariate polynomial. The merged share is verified
+    /// only if both sub-shares were, so callers should verify the sub-shares
+    /// or the result before use.
+    ///
+    /// Fails if the shareholders have different identities or epochs,
+    /// or if their polynomials or matrices are of different sizes.
+    pub fn merge(a: &Shareholder<G>, b: &Shareholder<G>) -> Result<Shareholder<G>> {
+        let x = a.verifiable_share.share.x;
+        if b.verifiable_share.share.x != x {
+            return Err(Error::ShareholderIdentityMismatch.into());
+        }
+        if b.epoch != a.epoch {
+            return Err(Error::EpochMismatch.into());
+        }
+        let (p, vm) = (&b.verifiable_share.share.p, &b.verifiable_share.vm);
+        a.validate_polynomial_size(p)?;
+        a.validate_matrix_dimensions(vm)?;
+
+        let p = &a.verifiable_share.share.p + p;
+        let vm = &a.verifiable_share.vm + vm;
+        let share = SecretShare::new(x, p);
+        let shareholder = Shareholder {
+            verifiable_share: VerifiableSecretShare::new(share, vm),
+            id: a.id.or(b.id),
+            epoch: a.epoch,
+        };
+
+        Ok(shareholder)
+    }
+
+    /// Verifies that the proactivization polynomial and verification matrix
+    /// are compatible with the share of the shareholder.
+    fn validate_proactivization(
+        &self,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()> {
+        self.validate_polynomial_size(p)?;
+        if !vm.is_zero_hole() {
+            return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+        }
+        self.validate_matrix_dimensions(vm)
+    }
+
+    /// Verifies that the given polynomial is of the same size as the secret
+    /// polynomial of the shareholder.
+    fn validate_polynomial_size(&self, p: &Polynomial<G::Scalar>) -> Result<()> {
+        let size = self.verifiable_share.share.p.size();
+        if p.size() != size {
+            return Err(Error::PolynomialDegreeMismatch {
+                expected: size - 1,
+                actual: p.size() - 1,
             }
-            if !self.vm.verify_x(&self.share.x, &self.share.p) {
-                return Err(Error::InvalidPolynomial.into());
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the given verification matrix is of the same dimensions
+    /// as the verification matrix of the shareholder.
+    fn validate_matrix_dimensions(&self, vm: &VerificationMatrix<G>) -> Result<()> {
+        let dimensions = self.verifiable_share.vm.dimensions();
+        if vm.dimensions() != dimensions {
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: dimensions,
+                actual: vm.dimensions(),
             }
-        } else {
-            if self.share.p.size() != rows {
-                return Err(Error::PolynomialDegreeMismatch.into());
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the proactivization polynomial is committed to by
+    /// the verification matrix, as otherwise the share would be corrupted.
+    fn verify_proactivization(
+        &self,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()> {
+        let x = &self.verifiable_share.share.x;
+        let (_, cols) = vm.dimensions();
+        let consistent = match p.size() == cols {
+            true => vm.verify_x(x, p),  // Full share B(me,y).
+            false => vm.verify_y(x, p), // Reduced share B(x,me).
+        };
+        if !consistent {
+            return Err(Error::PolynomialMatrixMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the byte representation of the shareholder.
+    ///
+    /// The representation consists of a version tag, the size of the secret
+    /// polynomial and the dimensions of the verification matrix, encoded
+    /// as big-endian 16-bit integers, followed by the identity,
+    /// the coefficients of the polynomial and the elements
+    /// of the verification matrix.
+    ///
+    /// Neither whether the identity was bound at construction nor the epoch
+    /// is encoded.
+    ///
+    /// Fails if the size of the polynomial or any dimension of the matrix
+    /// doesn't fit into the header. Polynomials and matrices derived from
+    /// a threshold that fits into a byte always fit.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let share = &self.verifiable_share.share;
+        let vm = &self.verifiable_share.vm;
+        let (rows, cols) = vm.dimensions();
+
+        let mut header = [0u16; 3];
+        for (h, n) in header.iter_mut().zip([share.p.size(), rows, cols]) {
+            *h = u16::try_from(n).map_err(|_| Error::DeclaredSizeTooLarge)?;
+        }
+        let cap = Self::byte_size(share.p.size(), rows, cols)?;
+
+        let mut bytes = Vec::with_capacity(cap);
+        bytes.push(SHAREHOLDER_ENCODING_VERSION);
+        for n in header {
+            bytes.extend_from_slice(&n.to_be_bytes());
+        }
+        bytes.extend_from_slice(share.x.to_repr().as_ref());
+        for ai in &share.p.a {
+            bytes.extend_from_slice(ai.to_repr().as_ref());
+        }
+        vm.write_elements(&mut bytes);
+
+        Ok(bytes)
+    }
+
+    /// Verifies that the shareholder is internally consistent.
+    ///
+    /// The polynomial must be non-degenerate, its size must match either
+    /// dimension of the verification matrix, which must be the matrix
+    /// of a bivariate polynomial of degree `(t, 2t)`, and the matrix must
+    /// commit to the polynomial. The x-coordinate must be non-zero and equal
+    /// to the identity of the shareholder, if bound.
+    ///
+    /// Unlike [`VerifiableSecretShare::verify`], this requires no knowledge
+    /// of the threshold or the kind of the share, which makes it suitable
+    /// for checking deserialized shareholders.
+    pub fn verify(&self) -> Result<()> {
+        let share = &self.verifiable_share.share;
+        let vm = &self.verifiable_share.vm;
+        let size = share.p.size();
+        let (rows, cols) = vm.dimensions();
+
+        if size == 0 || share.p.coefficients().iter().all(|a| a.is_zero().into()) {
+            return Err(Error::DegeneratePolynomial.into());
+        }
+        if rows == 0 || cols != 2 * rows - 1 {
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: (rows, (2 * rows).saturating_sub(1)),
+                actual: (rows, cols),
             }
-            if !self.vm.verify_y(&self.share.x, &self.share.p) {
-                return Err(Error::InvalidPolynomial.into());
+            .into());
+        }
+        check_shape(&share.p, vm)?;
+
+        // The matrix is square only under threshold zero, when the full
+        // and reduced shares coincide.
+        let committed = match size == cols {
+            true => vm.verify_x(&share.x, &share.p),
+            false => vm.verify_y(&share.x, &share.p),
+        };
+        if !committed {
+            return Err(Error::InvalidPolynomial.into());
+        }
+
+        if share.x.is_zero().into() {
+            return Err(Error::ZeroValueShareholder.into());
+        }
+        if let Some(id) = &self.id {
+            if *id != share.x {
+                return Err(Error::ShareholderIdentityMismatch.into());
             }
         }
 
         Ok(())
     }
 
-    /// Calculates the number of rows and columns in the verification matrix
-    /// based on the given threshold.
-    const fn calculate_dimensions(threshold: u8) -> (usize, usize) {
-        let rows: usize = threshold as usize + 1;
-        let cols = threshold as usize * 2 + 1;
-        (rows, cols)
+    /// Attempts to create a shareholder from its byte representation.
+    ///
+    /// This method is not constant time if the length of the slice is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_limit(bytes, (usize::MAX, usize::MAX))
+    }
+
+    /// Attempts to create a shareholder from its byte representation,
+    /// rejecting representations whose encoded verification matrix
+    /// dimensions exceed the given maximum dimensions.
+    ///
+    /// The dimensions are checked before anything is decoded. Since the
+    /// polynomial must be a full or a reduced share, its size is bounded
+    /// by the dimensions as well.
+    ///
+    /// This method is not constant time if the length of the slice is invalid.
+    pub fn from_bytes_with_limit(bytes: &[u8], max_dimensions: (usize, usize)) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(Error::InvalidEncoding.into());
+        }
+        if bytes[0] != SHAREHOLDER_ENCODING_VERSION {
+            return Err(Error::UnsupportedEncodingVersion.into());
+        }
+        if bytes.len() < SHAREHOLDER_HEADER_SIZE {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        // Verify that the length of the slice matches the declared
+        // dimensions of the polynomial and the verification matrix.
+        let read_u16 = |i: usize| u16::from_be_bytes([bytes[i], bytes[i + 1]]) as usize;
+        let size = read_u16(1);
+        let rows = read_u16(3);
+        let cols = read_u16(5);
+
+        VerificationMatrix::<G>::check_dimensions((rows, cols), max_dimensions)
+            .map_err(|_| Error::DeclaredSizeTooLarge)?;
+        if bytes.len() != Self::byte_size(size, rows, cols)? {
+            return Err(Error::InvalidEncoding.into());
+        }
+        check_share_size(size, (rows, cols)).map_err(|_| Error::InvalidEncoding)?;
+
+        let x_start = SHAREHOLDER_HEADER_SIZE;
+        let p_start = x_start + Polynomial::<G::Scalar>::coefficient_byte_size();
+        let vm_start = p_start + Polynomial::<G::Scalar>::byte_size(size);
+
+        let x = scalar_from_bytes(&bytes[x_start..p_start]).ok_or(Error::InvalidEncoding)?;
+        let p = Polynomial::from_bytes(&bytes[p_start..vm_start]).ok_or(Error::InvalidEncoding)?;
+        let vm = VerificationMatrix::from_element_bytes(rows, cols, &bytes[vm_start..])
+            .map_err(|_| Error::InvalidEncoding)?;
+        let share = SecretShare::new(x, p);
+
+        Ok(Shareholder::new(share, vm))
+    }
+
+    /// Returns the size of the byte representation of a shareholder
+    /// with the given polynomial size and matrix dimensions.
+    ///
+    /// Fails if the size overflows, which untrusted headers can cause
+    /// on targets with a narrow `usize`.
+    fn byte_size(size: usize, rows: usize, cols: usize) -> Result<usize> {
+        let p_size = size
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(Polynomial::<G::Scalar>::coefficient_byte_size()))
+            .ok_or(Error::DeclaredSizeTooLarge)?;
+        let vm_size = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(VerificationMatrix::<G>::element_byte_size()))
+            .ok_or(Error::DeclaredSizeTooLarge)?;
+
+        SHAREHOLDER_HEADER_SIZE
+            .checked_add(p_size)
+            .and_then(|n| n.checked_add(vm_size))
+            .ok_or(Error::DeclaredSizeTooLarge.into())
+    }
+}
+
+impl<G> From<VerifiableSecretShare<G>> for Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn from(verifiable_share: VerifiableSecretShare<G>) -> Shareholder<G> {
+        Shareholder {
+            verifiable_share,
+            id: None,
+            epoch: 0,
+        }
+    }
+}
+
+impl<G> PointShareholder<G::Scalar> for Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn coordinate_x(&self) -> &G::Scalar {
+        self.verifiable_share.share.coordinate_x()
+    }
+
+    fn coordinate_y(&self) -> &G::Scalar {
+        self.verifiable_share.share.coordinate_y()
+    }
+}
+
+impl<G> fmt::Debug for Shareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Formats the identity, the degree of the secret polynomial,
+    /// the dimensions of the verification matrix and the epoch, but not
+    /// the coefficients.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let share = &self.verifiable_share.share;
+        f.debug_struct("Shareholder")
+            .field("x", &IdentityPrefix(&share.x))
+            .field("degree", &share.p.size().saturating_sub(1))
+            .field("dimensions", &self.verifiable_share.vm.dimensions())
+            .field("epoch", &self.epoch)
+            .field("coefficients", &format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+/// The number of leading bytes of an identity shown in debug output.
+const IDENTITY_PREFIX_SIZE: usize = 4;
+
+/// Debug formatting of an identity as a truncated hex prefix
+/// of its canonical byte representation.
+struct IdentityPrefix<'a, F: PrimeField>(&'a F);
+
+impl<'a, F> fmt::Debug for IdentityPrefix<'a, F>
+where
+    F: PrimeField,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = self.0.to_repr();
+        for b in repr.as_ref().iter().take(IDENTITY_PREFIX_SIZE) {
+            write!(f, "{:02x}", b)?;
+        }
+        f.write_str("..")
+    }
+}
+
+/// Secret share of the shared secret.
+///
+/// Clones are deep copies of the polynomial, which are zeroized on drop
+/// independently of the original. Equality is not constant time, so it
+/// should only be used in tests or on public data, while secret shares
+/// should be compared using [`ConstantTimeEq`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretShare<F: PrimeField> {
+    /// The encoded identity of the shareholder.
+    ///
+    /// The identity is the x-coordinate of a point on the secret-sharing
+    /// univariate polynomial B(x,0) or B(0,y).
+    pub(crate) x: F,
+
+    /// The secret polynomial B(id,y) or B(x,id).
+    ///
+    /// The constant term of the polynomial is the y-coordinate of a point
+    /// on the secret-sharing univariate polynomial B(x,0) or B(0,y).
+    pub(crate) p: Polynomial<F>,
+}
+
+impl<F> SecretShare<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new secret share.
+    pub fn new(x: F, p: Polynomial<F>) -> Self {
+        Self { x, p }
+    }
+
+    /// Returns `a` if `choice` is zero and `b` if `choice` is one,
+    /// selecting the identity and each coefficient in constant time.
+    ///
+    /// Panics if the polynomials of the shares are of different sizes.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let x = F::conditional_select(&a.x, &b.x, choice);
+        let p = Polynomial::conditional_select(&a.p, &b.p, choice);
+
+        Self { x, p }
+    }
+
+    /// Returns the sum of the given shares of the shareholder with
+    /// the given coordinate, starting from the zero polynomial of the given
+    /// degree.
+    ///
+    /// Summing no shares yields the zero share of the given degree.
+    /// Fails if any share belongs to a different shareholder or has
+    /// a different degree.
+    pub fn accumulate<'a, I>(x: F, deg: u8, shares: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a SecretShare<F>>,
+        F: 'a,
+    {
+        let mut p = Polynomial::zero(deg);
+
+        for share in shares {
+            if share.x != x {
+                return Err(Error::ShareholderIdentityMismatch.into());
+            }
+            if share.p.size() != p.size() {
+                return Err(Error::PolynomialDegreeMismatch {
+                    expected: deg as usize,
+                    actual: share.p.size() - 1,
+                }
+                .into());
+            }
+            p += &share.p;
+        }
+
+        Ok(Self::new(x, p))
+    }
+
+    /// Returns the polynomial.
+    pub fn polynomial(&self) -> &Polynomial<F> {
+        &self.p
+    }
+
+    /// Returns true if the polynomial is a constant, i.e. of degree zero,
+    /// which is the case for all shares under threshold zero.
+    ///
+    /// Switch points derived from a constant share are equal to its
+    /// y-coordinate for all shareholders.
+    pub fn is_constant(&self) -> bool {
+        self.p.degree() == 0
+    }
+
+    /// Returns the degree of the polynomial, i.e. the reconstruction
+    /// threshold of the share.
+    ///
+    /// Reconstructing the polynomial requires [`required_points`] distinct
+    /// points, one more than the threshold.
+    pub fn reconstruction_threshold(&self) -> usize {
+        self.p.degree()
+    }
+
+    /// Returns the x-coordinate of a point on the secret-sharing
+    /// univariate polynomial B(x,0) or B(0,y).
+    pub fn coordinate_x(&self) -> &F {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of a point on the secret-sharing
+    /// univariate polynomial B(x,0) or B(0,y).
+    pub fn coordinate_y(&self) -> &F {
+        self.p
+            .coefficient(0)
+            .expect("polynomial has at least one term")
+    }
+
+    /// Returns the secret held by the share, i.e. the constant term
+    /// of the polynomial, or none if the polynomial has no terms.
+    pub fn secret(&self) -> Option<&F> {
+        self.p.coefficient(0)
+    }
+
+    /// Consumes the share and returns its secret, i.e. the constant term
+    /// of the polynomial, or none if the polynomial has no terms.
+    ///
+    /// All coefficients of the polynomial are zeroized before the share
+    /// is dropped, so only the returned copy of the secret remains.
+    pub fn take_secret(mut self) -> Option<F> {
+        self.extract_secret()
+    }
+
+    /// Returns a copy of the constant term of the polynomial and zeroizes
+    /// all coefficients.
+    fn extract_secret(&mut self) -> Option<F> {
+        let secret = self.p.coefficient(0).copied();
+        self.zeroize();
+        secret
+    }
+
+    /// Recovers the y-coordinate of a point on the secret-sharing
+    /// univariate polynomial B(x,0) or B(0,y) from the given switch points.
+    ///
+    /// Switch points are points on the secret polynomial B(x,id) or B(id,y)
+    /// of degree `threshold`, so the first `threshold + 1` points suffice
+    /// to interpolate the polynomial at zero, see [`Self::recover_at`].
+    pub fn recover_from_points(points: &[(F, F)], threshold: u8) -> Result<F> {
+        Self::recover_at(points, threshold, &F::ZERO)
+    }
+
+    /// Evaluates the secret polynomial B(x,id) or B(id,y) of degree
+    /// `threshold`, interpolated from the first `threshold + 1` of the given
+    /// switch points, at the given abscissa.
+    ///
+    /// This is how a new shareholder derives its own point on the polynomial
+    /// from the switch points it received.
+    pub fn recover_at(points: &[(F, F)], threshold: u8, x: &F) -> Result<F> {
+        let (xs, ys) = select_points(points, threshold)?;
+        let basis = interpolation_basis(&xs)?;
+
+        Ok(basis.eval(&ys, x))
+    }
+
+    /// Recovers the y-coordinate of a point on the secret-sharing
+    /// univariate polynomial B(x,0) or B(0,y) from the given switch points,
+    /// using the `threshold + 1` points of the shareholders that come first
+    /// in the canonical order, see [`cmp_shareholders`].
+    ///
+    /// Unlike [`Self::recover_from_points`], the result doesn't depend
+    /// on the order of the points, so all nodes that receive the same
+    /// points, or the same points of the first shareholders, agree on it.
+    pub fn recover_canonical(points: &[(F, F)], threshold: u8) -> Result<F> {
+        // Ties are broken by the y-coordinates, so that the selection
+        // doesn't depend on the order of the points even if they contain
+        // duplicate shareholders.
+        let mut points = points.to_vec();
+        points.sort_unstable_by(|a, b| {
+            cmp_shareholders(&a.0, &b.0).then_with(|| cmp_shareholders(&a.1, &b.1))
+        });
+
+        let (xs, ys) = select_points(&points, threshold)?;
+        let basis = interpolation_basis(&xs)?;
+
+        Ok(recover_with_basis(&basis, &ys))
+    }
+
+    /// Recovers the secret polynomial B(x,id) or B(id,y) of degree
+    /// `threshold` from the given switch points.
+    ///
+    /// Unlike [`Self::recover_from_points`], this returns the whole
+    /// polynomial, which can be evaluated at any point, not only at zero.
+    pub fn recover_polynomial(points: &[(F, F)], threshold: u8) -> Result<Polynomial<F>> {
+        let (xs, ys) = select_points(points, threshold)?;
+        let basis = interpolation_basis(&xs)?;
+
+        Ok(basis.interpolate(&ys))
+    }
+
+    /// Converts the reduced share B(x,id) to the full share B(id,y)
+    /// of the given bivariate polynomial.
+    ///
+    /// Fails if the share was not derived from the bivariate polynomial.
+    pub fn to_full(&self, bp: &BivariatePolynomial<F>) -> Result<Self> {
+        // The degree of a reduced share is the degree in x.
+        if self.p.size() != bp.deg_x + 1 {
+            return Err(Error::PolynomialDegreeMismatch {
+                expected: bp.deg_x,
+                actual: self.p.size() - 1,
+            }
+            .into());
+        }
+        if !bool::from(bp.eval_y(&self.x).ct_eq(&self.p)) {
+            return Err(Error::InvalidPolynomial.into());
+        }
+
+        Ok(Self::new(self.x, bp.eval_x(&self.x)))
+    }
+
+    /// Converts the full share B(id,y) to the reduced share B(x,id)
+    /// of the given bivariate polynomial.
+    ///
+    /// Fails if the share was not derived from the bivariate polynomial.
+    pub fn to_reduced(&self, bp: &BivariatePolynomial<F>) -> Result<Self> {
+        // The degree of a full share is the degree in y.
+        if self.p.size() != bp.deg_y + 1 {
+            return Err(Error::PolynomialDegreeMismatch {
+                expected: bp.deg_y,
+                actual: self.p.size() - 1,
+            }
+            .into());
+        }
+        if !bool::from(bp.eval_x(&self.x).ct_eq(&self.p)) {
+            return Err(Error::InvalidPolynomial.into());
+        }
+
+        Ok(Self::new(self.x, bp.eval_y(&self.x)))
+    }
+
+    /// Verifies that the share is committed to by the given verification
+    /// matrix, i.e. that the polynomial is the row B(x,id) or the column
+    /// B(id,y) of the committed bivariate polynomial at the coordinate
+    /// of the share.
+    ///
+    /// Reconstructed shares should be verified against the expected
+    /// aggregate matrix before they are used.
+    pub fn verify_against<G>(&self, vm: &VerificationMatrix<G>) -> Result<()>
+    where
+        G: Group<Scalar = F> + GroupEncoding,
+    {
+        let (_, cols) = vm.dimensions();
+        check_shape(&self.p, vm)?;
+
+        let committed = match self.p.size() == cols {
+            true => vm.verify_x(&self.x, &self.p),  // Full share B(me,y).
+            false => vm.verify_y(&self.x, &self.p), // Reduced share B(x,me).
+        };
+        if !committed {
+            return Err(Error::PolynomialMatrixMismatch.into());
+        }
+
+        Ok(())
     }
+
+    /// Creates a random proactivization polynomial for this share, together
+    /// with the verification matrix of the given dimensions committing to it.
+    ///
+    /// The polynomial is the share of a random zero-hole bivariate polynomial
+    /// at the same coordinate and of the same size as this share, so the pair
+    /// can be passed directly to [`Shareholder::proactivize`].
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn new_proactivization<G>(
+        &self,
+        dimensions: (usize, usize),
+        rng: &mut impl RngCore,
+    ) -> Result<(Polynomial<F>, VerificationMatrix<G>)>
+    where
+        G: Group<Scalar = F> + GroupEncoding,
+    {
+        let (rows, cols) = dimensions;
+        if rows == 0 || cols == 0 {
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: (rows.max(1), cols.max(1)),
+                actual: dimensions,
+            }
+            .into());
+        }
+        check_share_size(self.p.size(), (rows, cols))?;
+        let deg_x = u8::try_from(rows - 1).map_err(|_| Error::ThresholdTooLarge)?;
+        let deg_y = u8::try_from(cols - 1).map_err(|_| Error::ThresholdTooLarge)?;
+
+        let mut rng = CheckedRng::new(rng);
+        let mut bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+        if rng.failed() {
+            return Err(Error::RngFailure.into());
+        }
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        let p = match self.p.size() == cols {
+            true => bp.eval_x(&self.x),  // Full share B(me,y).
+            false => bp.eval_y(&self.x), // Reduced share B(x,me).
+        };
+
+        Ok((p, vm))
+    }
+}
+
+impl<F> From<SecretShare<F>> for (F, Polynomial<F>)
+where
+    F: PrimeField,
+{
+    /// Decomposes the share into the identity and the secret polynomial,
+    /// moving the coefficients out without copying them.
+    fn from(mut share: SecretShare<F>) -> Self {
+        // The share is zeroized on drop, so take the coefficients out,
+        // leaving nothing behind to zeroize.
+        let a = mem::take(&mut share.p.a);
+        (share.x, Polynomial { a })
+    }
+}
+
+impl<F> From<(F, Polynomial<F>)> for SecretShare<F>
+where
+    F: PrimeField,
+{
+    /// Composes a share from the given identity and secret polynomial.
+    fn from((x, p): (F, Polynomial<F>)) -> Self {
+        Self::new(x, p)
+    }
+}
+
+impl<F> ConstantTimeEq for SecretShare<F>
+where
+    F: PrimeField,
+{
+    /// Compares the identities and the secret polynomials of the shares
+    /// in constant time, provided that the polynomials are of the same size.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.p.ct_eq(&other.p)
+    }
+}
+
+impl<F> Zeroize for SecretShare<F>
+where
+    F: PrimeField,
+{
+    fn zeroize(&mut self) {
+        self.p.zeroize();
+    }
+}
+
+impl<F> Drop for SecretShare<F>
+where
+    F: PrimeField,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F> fmt::Debug for SecretShare<F>
+where
+    F: PrimeField,
+{
+    /// Formats the identity and the degree of the polynomial, but not
+    /// its coefficients.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretShare")
+            .field("x", &IdentityPrefix(&self.x))
+            .field("degree", &self.p.size().saturating_sub(1))
+            .field("coefficients", &format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+/// Verifiable secret share of the shared secret.
+#[derive(Clone, PartialEq, Eq)]
+pub struct VerifiableSecretShare<G: Group + GroupEncoding> {
+    /// Secret (full or reduced) share of the shared secret.
+    pub(crate) share: SecretShare<G::Scalar>,
+
+    /// Verification matrix used to verify that the polynomial in the secret
+    /// share is an evaluation B(x,id) or B(id,y) of the secret bivariate
+    /// polynomial B(x,y).
+    pub(crate) vm: VerificationMatrix<G>,
+}
+
+impl<G> VerifiableSecretShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Creates a new verifiable secret share.
+    pub fn new(share: SecretShare<G::Scalar>, vm: VerificationMatrix<G>) -> Self {
+        Self { share, vm }
+    }
+
+    /// Returns `a` if `choice` is zero and `b` if `choice` is one,
+    /// selecting each coefficient and matrix element in constant time.
+    ///
+    /// Panics if the polynomials of the shares are of different sizes,
+    /// or if the matrices are of different dimensions.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self
+    where
+        G: ConditionallySelectable,
+    {
+        let share = SecretShare::conditional_select(&a.share, &b.share, choice);
+        let vm = VerificationMatrix::conditional_select(&a.vm, &b.vm, choice);
+
+        Self { share, vm }
+    }
+
+    /// Returns the secret share.
+    pub fn secret_share(&self) -> &SecretShare<G::Scalar> {
+        &self.share
+    }
+
+    /// Returns the verification matrix.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        &self.vm
+    }
+
+    /// Verifies the secret share and the verification matrix.
+    pub fn verify(&self, threshold: u8, zero_hole: bool, full_share: bool) -> Result<()> {
+        self.verify_verification_matrix(threshold, zero_hole)?;
+        self.verify_secret_share(threshold, full_share)?;
+        Ok(())
+    }
+
+    /// Verifies the verification matrix.
+    fn verify_verification_matrix(&self, threshold: u8, zero_hole: bool) -> Result<()> {
+        let (rows, cols) = Self::calculate_dimensions(threshold);
+
+        if self.vm.dimensions() != (rows, cols) {
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: (rows, cols),
+                actual: self.vm.dimensions(),
+            }
+            .into());
+        }
+        if self.vm.is_zero_hole() != zero_hole {
+            return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+        }
+
+        // Verify that the bivariate polynomial `B(x, y)`, from which
+        // the verification matrix was constructed and the share was derived,
+        // satisfies the non-zero leading term requirements. Specifically,
+        // the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)` must have
+        // non-zero leading terms.
+        if threshold > 0 {
+            // Skipping the special case where the bivariate polynomial has only
+            // one coefficient. The verification of this coefficient has already
+            // been done above, when we checked if the verification matrix
+            // is zero-hole.
+            let i = rows - 1;
+            let j = cols - 1;
+
+            if self.vm.element(i, j).unwrap().is_identity().into() {
+                return Err(Error::InsecureBivariatePolynomial.into());
+            }
+            if self.vm.element(i, 0).unwrap().is_identity().into() {
+                return Err(Error::InsecureBivariatePolynomial.into());
+            }
+            if self.vm.element(0, j).unwrap().is_identity().into() {
+                return Err(Error::InsecureBivariatePolynomial.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the secret share.
+    fn verify_secret_share(&self, threshold: u8, full_share: bool) -> Result<()> {
+        let (rows, cols) = Self::calculate_dimensions(threshold);
+
+        if full_share {
+            if self.share.p.size() != cols {
+                return Err(Error::PolynomialDegreeMismatch {
+                    expected: cols - 1,
+                    actual: self.share.p.size() - 1,
+                }
+                .into());
+            }
+            if !self.vm.verify_x(&self.share.x, &self.share.p) {
+                return Err(Error::InvalidPolynomial.into());
+            }
+        } else {
+            if self.share.p.size() != rows {
+                return Err(Error::PolynomialDegreeMismatch {
+                    expected: rows - 1,
+                    actual: self.share.p.size() - 1,
+                }
+                .into());
+            }
+            if !self.vm.verify_y(&self.share.x, &self.share.p) {
+                return Err(Error::InvalidPolynomial.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calculates the number of rows and columns in the verification matrix
+    /// based on the given threshold.
+    const fn calculate_dimensions(threshold: u8) -> (usize, usize) {
+        let rows: usize = threshold as usize + 1;
+        let cols = threshold as usize * 2 + 1;
+        (rows, cols)
+    }
+}
+
+impl<G> From<VerifiableSecretShare<G>> for (SecretShare<G::Scalar>, VerificationMatrix<G>)
+where
+    G: Group + GroupEncoding,
+{
+    /// Decomposes the verifiable share into its secret share
+    /// and verification matrix, without copying either.
+    fn from(verifiable_share: VerifiableSecretShare<G>) -> Self {
+        (verifiable_share.share, verifiable_share.vm)
+    }
+}
+
+impl<G> From<(SecretShare<G::Scalar>, VerificationMatrix<G>)> for VerifiableSecretShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Composes a verifiable share from the given secret share
+    /// and verification matrix, without verifying them.
+    fn from((share, vm): (SecretShare<G::Scalar>, VerificationMatrix<G>)) -> Self {
+        Self::new(share, vm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use std::{env, fmt::Write, fs, iter::zip};
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group as _, GroupEncoding};
+    use rand::{rngs::StdRng, seq::SliceRandom, RngCore, SeedableRng};
+    use subtle::{Choice, ConstantTimeEq};
+    use zeroize::Zeroize;
+
+    use crate::{
+        churp::{self, verify_switch_point_proof, Error, HandoffKind},
+        poly::{self, lagrange, scalar_from_bytes, scalar_to_bytes},
+        suites::{self, p384},
+        vss,
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type SecretShare = churp::SecretShare<PrimeField>;
+    type Shareholder = churp::Shareholder<Group>;
+    type VerifiableSecretShare = churp::VerifiableSecretShare<Group>;
+    type Polynomial = poly::Polynomial<PrimeField>;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    fn prepare_shareholder(dealer: &Dealer, x: u64, kind: HandoffKind) -> Shareholder {
+        let x = PrimeField::from_u64(x);
+        let share = dealer.make_share(x, kind);
+        let vm = dealer.verification_matrix().clone();
+        Shareholder::new(share, vm)
+    }
+
+    fn flip_bit(s: &PrimeField) -> PrimeField {
+        let mut bytes = scalar_to_bytes(s);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        scalar_from_bytes(&bytes).expect("scalar should be valid")
+    }
+
+    #[test]
+    fn test_key_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let hash = Group::random(&mut rng);
+
+        // Constant term present.
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let s = *shareholder.verifiable_share().secret_share().coordinate_y();
+        let key_share = shareholder.key_share_ct(hash);
+        assert!(bool::from(key_share.is_some()));
+        assert_eq!(key_share.unwrap(), hash * s);
+        assert_eq!(shareholder.key_share(hash).unwrap(), hash * s);
+
+        // Constant term absent.
+        let share = SecretShare::new(PrimeField::ONE, Polynomial { a: Vec::new() });
+        let vm = dealer.verification_matrix().clone();
+        let shareholder = Shareholder::new(share, vm);
+        let key_share = shareholder.key_share_ct(hash);
+        assert!(bool::from(key_share.is_none()));
+        let res = shareholder.key_share(hash);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::EmptyPolynomial.to_string()
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(0x01020304) * PrimeField::from_u64(1 << 32).pow([11]);
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+        let shareholder = Shareholder::new(share.clone(), vm);
+
+        let prefix = &to_hex(&scalar_to_bytes(&x))[..8];
+        assert_eq!(prefix, "01020304");
+
+        let debug = format!("{:?}", share);
+        assert_eq!(
+            debug,
+            "SecretShare { x: 01020304.., degree: 4, coefficients: <redacted> }"
+        );
+
+        let debug = format!("{:?}", shareholder);
+        assert_eq!(
+            debug,
+            "Shareholder { x: 01020304.., degree: 4, dimensions: (3, 5), epoch: 0, coefficients: <redacted> }"
+        );
+
+        // Neither the coefficients nor their prefixes leak, even when
+        // formatted with the alternate flag.
+        for debug in [format!("{:#?}", share), format!("{:#?}", shareholder)] {
+            for ai in share.polynomial().coefficients() {
+                let hex = to_hex(&scalar_to_bytes(ai));
+                assert!(!debug.contains(&hex[..8]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let hashes: Vec<_> = (0..5).map(|_| Group::random(&mut rng)).collect();
+
+        // Constant term present.
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let key_shares = shareholder.key_shares(&hashes).unwrap();
+        assert_eq!(key_shares.len(), hashes.len());
+        for (key_share, hash) in zip(key_shares, &hashes) {
+            assert_eq!(key_share, shareholder.key_share(*hash).unwrap());
+        }
+        assert!(shareholder.key_shares(&[]).unwrap().is_empty());
+
+        // Constant term absent.
+        let share = SecretShare::new(PrimeField::ONE, Polynomial { a: Vec::new() });
+        let vm = dealer.verification_matrix().clone();
+        let shareholder = Shareholder::new(share, vm);
+        let res = shareholder.key_shares(&hashes);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::EmptyPolynomial.to_string()
+        );
+
+        // Single and batched key shares fail alike.
+        let single = shareholder.key_share(hashes[0]).err().unwrap();
+        let batch = shareholder.key_shares(&hashes[..1]).err().unwrap();
+        assert_eq!(single.to_string(), batch.to_string());
+        assert_eq!(
+            single.downcast_ref::<Error>().map(Error::code),
+            batch.downcast_ref::<Error>().map(Error::code)
+        );
+    }
+
+    #[test]
+    fn test_reshare() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(2, secret, &mut rng).unwrap();
+        let kind = HandoffKind::DealingPhase;
+
+        // The old committee has threshold 2, the new one threshold 4.
+        let old: Vec<_> = (1..=3)
+            .map(|x| prepare_shareholder(&dealer, x, kind))
+            .collect();
+        let new_committee: Vec<_> = (10..=16).map(PrimeField::from_u64).collect();
+        let new_threshold = 4;
+
+        let reshares: Vec<_> = old
+            .iter()
+            .map(|sh| sh.reshare(&new_committee, new_threshold, &mut rng).unwrap())
+            .collect();
+
+        // Every new member combines its points using the Lagrange
+        // coefficients of the old shareholders.
+        let xs: Vec<_> = old.iter().map(|sh| sh.verifiable_share().share.x).collect();
+        let cs = lagrange::coefficients(&xs);
+        let points: Vec<_> = new_committee
+            .iter()
+            .enumerate()
+            .map(|(j, z)| {
+                let s = zip(&cs, &reshares).map(|(c, r)| *c * r[j].1).sum();
+                (*z, s)
+            })
+            .collect();
+
+        // The new committee reconstructs the same secret under the new
+        // threshold, but not with fewer points.
+        let p = Polynomial::interpolate(&points[..new_threshold + 1]).unwrap();
+        assert_eq!(p.eval(&PrimeField::ZERO), secret);
+        let p = Polynomial::interpolate(&points[2..]).unwrap();
+        assert_eq!(p.eval(&PrimeField::ZERO), secret);
+        let p = Polynomial::interpolate(&points[..new_threshold]).unwrap();
+        assert_ne!(p.eval(&PrimeField::ZERO), secret);
+
+        // Invalid committees.
+        for threshold in [new_threshold, 256, usize::MAX] {
+            let res = old[0].reshare(&new_committee[..new_threshold], threshold, &mut rng);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::ThresholdTooLarge.to_string()
+            );
+        }
+        let mut duplicates = new_committee.clone();
+        duplicates[1] = duplicates[0];
+        let res = old[0].reshare(&duplicates, new_threshold, &mut rng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+    }
+
+    #[test]
+    fn test_proactivize_many() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let kind = HandoffKind::DealingPhase;
+        let shareholders: Vec<_> = (1..=4)
+            .map(|x| prepare_shareholder(&dealer, x, kind))
+            .collect();
+
+        let mut bp = BivariatePolynomial::random(2, 4, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        let ps: Vec<_> = shareholders
+            .iter()
+            .map(|sh| bp.eval_x(&sh.verifiable_share().share.x))
+            .collect();
+
+        // Homogeneous batch.
+        let proactivized = Shareholder::proactivize_many(&shareholders, &ps, &vm).unwrap();
+        assert_eq!(proactivized.len(), shareholders.len());
+        for ((sh, p), proactivized) in zip(zip(&shareholders, &ps), &proactivized) {
+            let expected = sh.proactivize(p, &vm).unwrap();
+            assert!(proactivized.verifiable_share().share.p == expected.verifiable_share().share.p);
+        }
+
+        // Heterogeneous batch.
+        let mut shareholders = shareholders;
+        shareholders[2] = prepare_shareholder(&dealer, 3, HandoffKind::CommitteeChanged);
+        let res = Shareholder::proactivize_many(&shareholders, &ps, &vm);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ShareholderProactivizationFailed { index: 2 }.to_string()
+        );
+
+        // Mismatched number of polynomials.
+        let res = Shareholder::proactivize_many(&shareholders, &ps[1..], &vm);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialCountMismatch.to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_variants() {
+        // Digest that fails for every message.
+        struct FailingDigest;
+
+        impl suites::FieldDigest for FailingDigest {
+            type Output = PrimeField;
+
+            fn hash_to_field(_msg: &[u8], _dst: &[u8]) -> anyhow::Result<Self::Output> {
+                Err(anyhow::anyhow!("digest failed"))
+            }
+        }
+
+        // The underlying failure is kept as the source.
+        let err = churp::encode_shareholder::<FailingDigest>(b"id", b"dst")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::ShareholderEncodingFailed)
+        ));
+        assert_eq!(err.root_cause().to_string(), "digest failed");
+
+        // Errors can be matched along the chain.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let shareholders = [
+            prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase),
+            prepare_shareholder(&dealer, 2, HandoffKind::CommitteeChanged),
+        ];
+        let mut bp = BivariatePolynomial::random(2, 4, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        let ps: Vec<_> = shareholders
+            .iter()
+            .map(|sh| bp.eval_x(&sh.verifiable_share().share.x))
+            .collect();
+
+        let err = Shareholder::proactivize_many(&shareholders, &ps, &vm)
+            .err()
+            .unwrap();
+        match err.downcast_ref::<Error>() {
+            Some(Error::ShareholderProactivizationFailed { index }) => assert_eq!(*index, 1),
+            _ => panic!("unexpected error: {}", err),
+        }
+        match err
+            .chain()
+            .nth(1)
+            .and_then(|err| err.downcast_ref::<Error>())
+        {
+            Some(Error::PolynomialDegreeMismatch { expected, actual }) => {
+                assert_eq!((*expected, *actual), (2, 4))
+            }
+            _ => panic!("unexpected source: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_from_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+        let x = PrimeField::from_u64(1);
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = Shareholder::from_polynomial(bp, x, kind);
+            let verifiable_share = shareholder.verifiable_share();
+            assert!(verifiable_share.vm.verify_commitment(bp));
+            assert!(verifiable_share.vm == *dealer.verification_matrix());
+            assert!(verifiable_share.share.p == dealer.make_share(x, kind).p);
+        }
+    }
+
+    #[test]
+    fn test_check_shape() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Reduced and full shares.
+        for deg in [2, 4] {
+            let p = Polynomial::random(deg, &mut rng);
+            assert!(churp::check_shape(&p, &vm).is_ok());
+        }
+
+        // Off-by-one degrees.
+        for deg in [1, 3, 5] {
+            let p = Polynomial::random(deg, &mut rng);
+            let res = churp::check_shape(&p, &vm);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::PolynomialDegreeMismatch {
+                    expected: 4,
+                    actual: deg as usize,
+                }
+                .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_new() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let vm = dealer.verification_matrix().clone();
+
+        // Full and reduced shares.
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let share = dealer.make_share(x, kind);
+            let shareholder = Shareholder::try_new(share, vm.clone()).unwrap();
+            assert!(shareholder.verify().is_ok());
+        }
+
+        // Polynomial of degree 5 with a 3x3 matrix.
+        let bp = BivariatePolynomial::random(2, 2, &mut rng);
+        let share = SecretShare::new(x, Polynomial::random(5, &mut rng));
+        let res = Shareholder::try_new(share, VerificationMatrix::from(&bp));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 2,
+                actual: 5,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_new_with_id() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+
+        // Identity is not bound by default.
+        let shareholder = Shareholder::new(share, vm.clone());
+        assert!(shareholder.id().is_none());
+
+        // Bound identity.
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let shareholder = Shareholder::new_with_id(x, share, vm.clone()).unwrap();
+        assert_eq!(shareholder.id(), Some(&x));
+
+        // Proactivization preserves the identity.
+        let (p, pvm) = shareholder
+            .verifiable_share()
+            .secret_share()
+            .new_proactivization::<Group>(vm.dimensions(), &mut rng)
+            .unwrap();
+        let proactivized = shareholder.proactivize(&p, &pvm).unwrap();
+        assert_eq!(proactivized.id(), Some(&x));
+
+        // Share of another shareholder.
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let res = Shareholder::new_with_id(PrimeField::from_u64(2), share, vm);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ShareholderIdentityMismatch.to_string()
+        );
+    }
+
+    #[test]
+    fn test_verify_switch_point() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for threshold in [0, 2] {
+            let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+            for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+                let me = prepare_shareholder(&dealer, 1, kind);
+                let x = me.verifiable_share().share.x;
+                let peer = prepare_shareholder(&dealer, 2, kind);
+                let y = peer.verifiable_share().share.x;
+
+                // Valid point computed by the peer.
+                let bij = peer.switch_point(&x);
+                assert!(me.verify_switch_point(&y, &bij));
+
+                // Tampered point.
+                let tampered = flip_bit(&bij);
+                assert!(!me.verify_switch_point(&y, &tampered));
+
+                // Valid point attributed to another shareholder (constant
+                // polynomials evaluate to the same point for all shareholders).
+                let z = PrimeField::from_u64(3);
+                let valid = me.verify_switch_point(&z, &bij);
+                assert_eq!(valid, threshold == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_switch_point_with_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 1, kind);
+            let x = me.verifiable_share().share.x;
+            let peer = prepare_shareholder(&dealer, 2, kind);
+            let y = peer.verifiable_share().share.x;
+
+            // Coordinates of the point computed by the peer.
+            let (cx, cy) = match kind {
+                HandoffKind::DealingPhase => (y, x),
+                _ => (x, y),
+            };
+
+            // Valid opening.
+            let (point, commitment) = peer.switch_point_with_proof(&x);
+            assert!(point == peer.switch_point(&x));
+            assert!(verify_switch_point_proof(&point, &commitment, vm, &cx, &cy));
+
+            // Mismatched commitment.
+            let other = commitment + Group::generator();
+            assert!(!verify_switch_point_proof(&point, &other, vm, &cx, &cy));
+
+            // Tampered point with a matching commitment.
+            let tampered = flip_bit(&point);
+            let commitment = Group::generator() * tampered;
+            assert!(!verify_switch_point_proof(
+                &tampered,
+                &commitment,
+                vm,
+                &cx,
+                &cy
+            ));
+        }
+    }
+
+    #[test]
+    fn test_masked_switch_point() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 1, kind);
+            let x = me.verifiable_share().share.x;
+            let peer = prepare_shareholder(&dealer, 2, kind);
+            let y = peer.verifiable_share().share.x;
+            let mask = PrimeField::random(&mut rng);
+
+            // Unmasking recovers the switch point.
+            let (masked, commitment) = peer.masked_switch_point(&x, &mask);
+            assert_eq!(masked - mask, peer.switch_point(&x));
+            assert_eq!(commitment, Group::generator() * mask);
+            assert!(me.verify_masked_point(&y, &masked, &commitment));
+
+            // Tampered point or commitment.
+            assert!(!me.verify_masked_point(&y, &flip_bit(&masked), &commitment));
+            let other = Group::generator() * PrimeField::random(&mut rng);
+            assert!(!me.verify_masked_point(&y, &masked, &other));
+
+            // Point attributed to another shareholder.
+            let z = PrimeField::from_u64(3);
+            assert!(!me.verify_masked_point(&z, &masked, &commitment));
+
+            // Random mask.
+            let (mask, masked, commitment) = peer.random_masked_switch_point(&x, &mut rng).unwrap();
+            assert!(!bool::from(mask.is_zero()));
+            assert_eq!(masked - mask, peer.switch_point(&x));
+            assert!(me.verify_masked_point(&y, &masked, &commitment));
+        }
+    }
+
+    #[test]
+    fn test_identify_bad_points() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 10, kind);
+            let x = me.verifiable_share().share.x;
+
+            // Points computed by other shareholders.
+            let mut points: Vec<_> = (1..=6)
+                .map(|i| prepare_shareholder(&dealer, i, kind))
+                .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&x)))
+                .collect();
+            assert!(me.identify_bad_points(&points).is_empty());
+
+            // Tamper with some of the points.
+            points[1].1 = flip_bit(&points[1].1);
+            points[4].1 = flip_bit(&points[4].1);
+            assert_eq!(me.identify_bad_points(&points), vec![1, 4]);
+
+            // Claim a point belongs to another shareholder.
+            (points[2].1, points[3].1) = (points[3].1, points[2].1);
+            assert_eq!(me.identify_bad_points(&points), vec![1, 2, 3, 4]);
+
+            // No points.
+            assert!(me.identify_bad_points(&[]).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_verify_switch_points_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 10, kind);
+            let x = me.verifiable_share().share.x;
+
+            // Points computed by other shareholders.
+            let mut points: Vec<_> = (1..=6)
+                .map(|i| prepare_shareholder(&dealer, i, kind))
+                .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&x)))
+                .collect();
+            assert!(me.verify_switch_points_batch(&points, &mut rng));
+
+            // A single invalid point makes the batch fail.
+            points[3].1 = flip_bit(&points[3].1);
+            for _ in 0..10 {
+                assert!(!me.verify_switch_points_batch(&points, &mut rng));
+            }
+            assert_eq!(me.identify_bad_points(&points), vec![3]);
+        }
+    }
+
+    #[test]
+    fn test_verify_switch_points_batch_fs() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let me = prepare_shareholder(&dealer, 10, kind);
+            let x = me.verifiable_share().share.x;
+
+            // Points computed by other shareholders.
+            let mut points: Vec<_> = (1..=6)
+                .map(|i| prepare_shareholder(&dealer, i, kind))
+                .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&x)))
+                .collect();
+            let ok = me.verify_switch_points_batch_fs::<Suite>(&points, b"context");
+            assert!(ok.unwrap());
+
+            // A single invalid point makes the batch fail.
+            points[3].1 = flip_bit(&points[3].1);
+            let ok = me.verify_switch_points_batch_fs::<Suite>(&points, b"context");
+            assert!(!ok.unwrap());
+        }
+    }
+
+    fn random_scalars(n: usize, rng: &mut impl RngCore) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut *rng)).collect()
+    }
+
+    #[test]
+    fn test_switch_points() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let xs = random_scalars(10, &mut rng);
+
+        let expected: Vec<_> = xs.iter().map(|x| shareholder.switch_point(x)).collect();
+        assert_eq!(shareholder.switch_points(&xs), expected);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let bytes = shareholder.to_bytes().unwrap();
+            let restored = Shareholder::from_bytes(&bytes).expect("deserialization should succeed");
+
+            let share = shareholder.verifiable_share();
+            let restored_share = restored.verifiable_share();
+            assert_eq!(
+                restored_share.secret_share().coordinate_x(),
+                share.secret_share().coordinate_x()
+            );
+            assert!(
+                restored_share.secret_share().polynomial() == share.secret_share().polynomial()
+            );
+            assert_eq!(
+                restored_share.verification_matrix(),
+                share.verification_matrix()
+            );
+            assert_eq!(restored.to_bytes().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_serialization_large_share() {
+        // A share with more coefficients than fit into a byte. Use a matrix
+        // with a single row, as computing larger matrices is slow.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let size = 2 * u8::MAX as usize + 1;
+        let x = PrimeField::from_u64(1);
+        let p = Polynomial::with_coefficients(random_scalars(size, &mut rng));
+        let vm = VerificationMatrix::from_element_bytes(
+            1,
+            size,
+            &Group::generator().to_bytes().as_slice().repeat(size),
+        )
+        .unwrap();
+        let shareholder = Shareholder::new(SecretShare::new(x, p), vm);
+
+        let bytes = shareholder.to_bytes().unwrap();
+        let restored = Shareholder::from_bytes(&bytes).expect("deserialization should succeed");
+        assert!(
+            restored.verifiable_share().secret_share().polynomial()
+                == shareholder.verifiable_share().secret_share().polynomial()
+        );
+        assert_eq!(
+            restored.verifiable_share().verification_matrix(),
+            shareholder.verifiable_share().verification_matrix()
+        );
+        assert_eq!(restored.to_bytes().unwrap(), bytes);
+
+        // Sizes that don't fit into the header.
+        let p = Polynomial::with_coefficients(vec![PrimeField::ONE; u16::MAX as usize + 1]);
+        let vm =
+            VerificationMatrix::from_element_bytes(1, 1, &Group::generator().to_bytes()).unwrap();
+        let shareholder = Shareholder::new(SecretShare::new(x, p), vm);
+        let res = shareholder.to_bytes();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+
+        // Sizes that overflow.
+        for (size, rows, cols) in [(usize::MAX, 1, 1), (0, usize::MAX, 2), (0, 1, usize::MAX)] {
+            let res = Shareholder::byte_size(size, rows, cols);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DeclaredSizeTooLarge.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_with_limit() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let bytes = shareholder.to_bytes().unwrap();
+
+        // Within the limit.
+        let restored = Shareholder::from_bytes_with_limit(&bytes, (3, 5)).unwrap();
+        assert_eq!(restored.to_bytes().unwrap(), bytes);
+
+        // Exceeding the limit.
+        let res = Shareholder::from_bytes_with_limit(&bytes, (3, 4));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+
+        // A header claiming the largest dimensions is rejected before
+        // the length of the slice is considered.
+        let mut invalid = bytes[..super::SHAREHOLDER_HEADER_SIZE].to_vec();
+        invalid[1..].fill(u8::MAX);
+        let res = Shareholder::from_bytes_with_limit(&invalid, (3, 5));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let bytes = shareholder.to_bytes().unwrap();
+
+        // Empty slice.
+        let res = Shareholder::from_bytes(&[]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Unsupported version.
+        let mut invalid = bytes.clone();
+        invalid[0] = 2;
+        let res = Shareholder::from_bytes(&invalid);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::UnsupportedEncodingVersion.to_string()
+        );
+
+        // Truncated slice.
+        let res = Shareholder::from_bytes(&bytes[..bytes.len() - 1]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Trailing bytes.
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        let res = Shareholder::from_bytes(&invalid);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Invalid polynomial size.
+        let mut invalid = bytes.clone();
+        invalid[2] += 1;
+        let res = Shareholder::from_bytes(&invalid);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Non-canonical identity.
+        let mut invalid = bytes.clone();
+        let size = scalar_to_bytes(&PrimeField::ONE).len();
+        invalid[7..7 + size].fill(255);
+        let res = Shareholder::from_bytes(&invalid);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+    }
+
+    #[test]
+    fn test_proactivize() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let x = shareholder.verifiable_share().secret_share().coordinate_x();
+
+            // Consistent polynomial and matrix.
+            let proactive = Dealer::new_proactive(threshold, &mut rng).unwrap();
+            let p = proactive.make_share(*x, kind);
+            let vm = proactive.verification_matrix();
+            let proactivized = shareholder.proactivize(p.polynomial(), vm).unwrap();
+            let full_share = matches!(kind, HandoffKind::DealingPhase);
+            let res = proactivized
+                .verifiable_share()
+                .verify(threshold, false, full_share);
+            assert!(res.is_ok());
+
+            // Mismatched polynomial and matrix.
+            let other = Dealer::new_proactive(threshold, &mut rng).unwrap();
+            let p = other.make_share(*x, kind);
+            let res = shareholder.proactivize(p.polynomial(), vm);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::PolynomialMatrixMismatch.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_rerandomize() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for threshold in [0, 2] {
+            let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+            for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+                let shareholder = prepare_shareholder(&dealer, 1, kind);
+                let rerandomized = shareholder.rerandomize(&mut rng).unwrap();
+                let share = shareholder.verifiable_share().secret_share();
+                let fresh = rerandomized.verifiable_share().secret_share();
+
+                // Same secret, fresh share.
+                assert_eq!(fresh.coordinate_x(), share.coordinate_x());
+                assert_eq!(fresh.coordinate_y(), share.coordinate_y());
+                let x = PrimeField::from_u64(5);
+                assert_eq!(
+                    fresh.polynomial().eval(&x) == share.polynomial().eval(&x),
+                    threshold == 0
+                );
+
+                // The fresh share is committed to by its verification matrix.
+                assert!(rerandomized.verify().is_ok());
+                let full_share = matches!(kind, HandoffKind::DealingPhase);
+                let res = rerandomized
+                    .verifiable_share()
+                    .verify(threshold, false, full_share);
+                assert!(res.is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_proactivize_in_place() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let mut shareholder = prepare_shareholder(&dealer, 1, kind);
+            let x = *shareholder.verifiable_share().secret_share().coordinate_x();
+
+            // The result matches the allocating proactivization.
+            let proactive = Dealer::new_proactive(threshold, &mut rng).unwrap();
+            let p = proactive.make_share(x, kind);
+            let vm = proactive.verification_matrix();
+            let proactivized = shareholder.proactivize(p.polynomial(), vm).unwrap();
+            shareholder
+                .proactivize_in_place(p.polynomial(), vm)
+                .unwrap();
+            assert_eq!(
+                shareholder.to_bytes().unwrap(),
+                proactivized.to_bytes().unwrap()
+            );
+
+            // Failures leave the shareholder unchanged.
+            let other = Dealer::new_proactive(threshold, &mut rng).unwrap();
+            let p = other.make_share(x, kind);
+            let res = shareholder.proactivize_in_place(p.polynomial(), vm);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::PolynomialMatrixMismatch.to_string()
+            );
+            let res =
+                shareholder.proactivize_in_place(p.polynomial(), dealer.verification_matrix());
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::VerificationMatrixZeroHoleMismatch.to_string()
+            );
+            assert_eq!(
+                shareholder.to_bytes().unwrap(),
+                proactivized.to_bytes().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_epoch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let proactive = Dealer::new_proactive(threshold, &mut rng).unwrap();
+        let vm = proactive.verification_matrix();
+        let kind = HandoffKind::DealingPhase;
+
+        let shareholders: Vec<_> = (1..=3)
+            .map(|x| prepare_shareholder(&dealer, x, kind))
+            .collect();
+        assert!(shareholders.iter().all(|s| s.epoch() == 0));
+        assert_eq!(churp::common_epoch(&shareholders).unwrap(), 0);
+
+        // Proactivization advances the epoch.
+        let x = PrimeField::from_u64(1);
+        let p = proactive.make_share(x, kind);
+        let mut proactivized = shareholders[0].proactivize(p.polynomial(), vm).unwrap();
+        assert_eq!(proactivized.epoch(), 1);
+        let rerandomized = proactivized.rerandomize(&mut rng).unwrap();
+        assert_eq!(rerandomized.epoch(), 2);
+        proactivized
+            .proactivize_in_place(p.polynomial(), vm)
+            .unwrap();
+        assert_eq!(proactivized.epoch(), 2);
+
+        let ps: Vec<_> = (1..=3)
+            .map(|x| proactive.make_share(PrimeField::from_u64(x), kind))
+            .map(|share| share.polynomial().clone())
+            .collect();
+        let next = Shareholder::proactivize_many(&shareholders, &ps, vm).unwrap();
+        assert_eq!(churp::common_epoch(&next).unwrap(), 1);
+
+        // Failures leave the epoch unchanged.
+        let res = proactivized.proactivize_in_place(p.polynomial(), dealer.verification_matrix());
+        assert!(res.is_err());
+        assert_eq!(proactivized.epoch(), 2);
+
+        // Mixing epochs is rejected.
+        let mixed = [&next[0], &next[1], &shareholders[2]];
+        let res = churp::common_epoch(mixed);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::EpochMismatch.to_string()
+        );
+        let res = Shareholder::merge(&next[0], &shareholders[0]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::EpochMismatch.to_string()
+        );
+
+        // The epoch can be restored explicitly.
+        let restored = shareholders[2].clone().with_epoch(1);
+        let mixed = [&next[0], &next[1], &restored];
+        assert_eq!(churp::common_epoch(mixed).unwrap(), 1);
+        let res = churp::common_epoch::<Group, _>(Vec::new());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NotEnoughShareholders.to_string()
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+
+        // Split the bivariate polynomial B = B_1 + B_2 into two halves,
+        // neither of which is zero-hole.
+        let alice = Dealer::new(threshold, &mut rng).unwrap();
+        let bob = Dealer::new(threshold, &mut rng).unwrap();
+        let (bp1, bp2) = (alice.bivariate_polynomial(), bob.bivariate_polynomial());
+        let b = (0..=bp1.deg_x)
+            .map(|i| {
+                (0..=bp1.deg_y)
+                    .map(|j| bp1.coefficient(i, j).unwrap() + bp2.coefficient(i, j).unwrap())
+                    .collect()
+            })
+            .collect();
+        let dealer = Dealer::from(BivariatePolynomial::with_coefficients(b));
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let a = prepare_shareholder(&alice, 1, kind);
+            let b = prepare_shareholder(&bob, 1, kind);
+            let merged = Shareholder::merge(&a, &b).unwrap();
+
+            // The merged share matches the original.
+            let original = prepare_shareholder(&dealer, 1, kind);
+            assert!(merged == original);
+            let full_share = matches!(kind, HandoffKind::DealingPhase);
+            let res = merged
+                .verifiable_share()
+                .verify(threshold, false, full_share);
+            assert!(res.is_ok());
+        }
+
+        // Incompatible shareholders.
+        let a = prepare_shareholder(&alice, 1, HandoffKind::DealingPhase);
+        let res = Shareholder::merge(&a, &prepare_shareholder(&bob, 2, HandoffKind::DealingPhase));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ShareholderIdentityMismatch.to_string()
+        );
+
+        let b = prepare_shareholder(&bob, 1, HandoffKind::CommitteeChanged);
+        let res = Shareholder::merge(&a, &b);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 4,
+                actual: 2
+            }
+            .to_string()
+        );
+
+        let other = Dealer::new(threshold + 1, &mut rng).unwrap();
+        let share = bob.make_share(PrimeField::from_u64(1), HandoffKind::DealingPhase);
+        let b = Shareholder::new(share, other.verification_matrix().clone());
+        let res = Shareholder::merge(&a, &b);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (3, 5),
+                actual: (4, 7)
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_new_proactivization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let share = shareholder.verifiable_share().secret_share();
+            let dimensions = shareholder
+                .verifiable_share()
+                .verification_matrix()
+                .dimensions();
+
+            let (p, vm) = share
+                .new_proactivization::<Group>(dimensions, &mut rng)
+                .unwrap();
+            assert_eq!(p.size(), share.polynomial().size());
+            assert!(vm.is_zero_hole());
+            assert_eq!(vm.dimensions(), dimensions);
+            assert!(shareholder.proactivize(&p, &vm).is_ok());
+        }
+
+        // Incompatible dimensions.
+        let share = dealer.make_share(PrimeField::ONE, HandoffKind::DealingPhase);
+        let res = share.new_proactivization::<Group>((2, 2), &mut rng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 1,
+                actual: 4
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_shareholder() {
+        // Digest that maps every message to zero.
+        struct ZeroDigest;
+
+        impl suites::FieldDigest for ZeroDigest {
+            type Output = PrimeField;
+
+            fn hash_to_field(_msg: &[u8], _dst: &[u8]) -> anyhow::Result<Self::Output> {
+                Ok(PrimeField::ZERO)
+            }
+        }
+
+        let id = [1u8; 32];
+        let x = churp::encode_shareholder::<Suite>(&id, b"shareholder").unwrap();
+        assert_eq!(
+            x,
+            churp::encode_shareholder::<Suite>(&id, b"shareholder").unwrap()
+        );
+
+        // Different domain separation tags yield different encodings.
+        let y = churp::encode_shareholder::<Suite>(&id, b"protocol v2").unwrap();
+        assert_ne!(x, y);
+
+        // Zero encodings are rejected.
+        let res = churp::encode_shareholder::<ZeroDigest>(&id, b"shareholder");
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ZeroValueShareholder.to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_shareholder_nonzero() {
+        // Digest that maps messages with counter zero to zero.
+        struct ZeroFirstDigest;
+
+        impl suites::FieldDigest for ZeroFirstDigest {
+            type Output = PrimeField;
+
+            fn hash_to_field(msg: &[u8], dst: &[u8]) -> anyhow::Result<Self::Output> {
+                match msg.last() {
+                    Some(0) => Ok(PrimeField::ZERO),
+                    _ => <Suite as suites::FieldDigest>::hash_to_field(msg, dst),
+                }
+            }
+        }
+
+        // Digest that maps every message to zero.
+        struct ZeroDigest;
+
+        impl suites::FieldDigest for ZeroDigest {
+            type Output = PrimeField;
+
+            fn hash_to_field(_msg: &[u8], _dst: &[u8]) -> anyhow::Result<Self::Output> {
+                Ok(PrimeField::ZERO)
+            }
+        }
+
+        let id = [1u8; 32];
+        let dst = b"shareholder";
+        let mut msg = id.to_vec();
+        msg.push(0);
+
+        // The first try succeeds.
+        let x = churp::encode_shareholder_nonzero::<Suite>(&id, dst, 3).unwrap();
+        assert_eq!(
+            x,
+            <Suite as suites::FieldDigest>::hash_to_field(&msg, dst).unwrap()
+        );
+
+        // The first try yields zero, so the counter is incremented.
+        let y = churp::encode_shareholder_nonzero::<ZeroFirstDigest>(&id, dst, 3).unwrap();
+        msg[id.len()] = 1;
+        assert_eq!(
+            y,
+            <Suite as suites::FieldDigest>::hash_to_field(&msg, dst).unwrap()
+        );
+        assert_ne!(x, y);
+        assert_eq!(
+            y,
+            churp::encode_shareholder_nonzero::<ZeroFirstDigest>(&id, dst, 3).unwrap()
+        );
+
+        // Tries are exhausted.
+        for (max_tries, res) in [
+            (
+                1,
+                churp::encode_shareholder_nonzero::<ZeroFirstDigest>(&id, dst, 1),
+            ),
+            (0, churp::encode_shareholder_nonzero::<Suite>(&id, dst, 0)),
+            (
+                u8::MAX,
+                churp::encode_shareholder_nonzero::<ZeroDigest>(&id, dst, u8::MAX),
+            ),
+        ] {
+            assert!(res.is_err(), "max_tries {}", max_tries);
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::ShareholderEncodingExhausted.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_committee_digest() {
+        let ids: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+        let digest = churp::committee_digest::<Suite, _>(&ids).unwrap();
+
+        // Permutations yield the same digest.
+        let mut permuted = ids.clone();
+        permuted.reverse();
+        assert_eq!(
+            churp::committee_digest::<Suite, _>(&permuted).unwrap(),
+            digest
+        );
+        permuted.swap(0, 2);
+        assert_eq!(
+            churp::committee_digest::<Suite, _>(&permuted).unwrap(),
+            digest
+        );
+
+        // Changing, adding or removing any ID changes the digest.
+        for i in 0..ids.len() {
+            let mut changed = ids.clone();
+            changed[i] = PrimeField::from_u64(10);
+            assert_ne!(
+                churp::committee_digest::<Suite, _>(&changed).unwrap(),
+                digest
+            );
+
+            let mut removed = ids.clone();
+            removed.remove(i);
+            assert_ne!(
+                churp::committee_digest::<Suite, _>(&removed).unwrap(),
+                digest
+            );
+        }
+        let mut added = ids.clone();
+        added.push(PrimeField::from_u64(10));
+        assert_ne!(churp::committee_digest::<Suite, _>(&added).unwrap(), digest);
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let vm = dealer.verification_matrix().clone();
+
+        // Consistent shareholders.
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            assert!(shareholder.verify().is_ok());
+
+            let share = dealer.make_share(x, kind);
+            let shareholder = Shareholder::new_with_id(x, share, vm.clone()).unwrap();
+            assert!(shareholder.verify().is_ok());
+        }
+        let dealer_zero = Dealer::new(0, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer_zero, 1, HandoffKind::DealingPhase);
+        assert!(shareholder.verify().is_ok());
+
+        // Degenerate polynomial.
+        let share = SecretShare::new(x, Polynomial::zero(4));
+        let res = Shareholder::new(share, vm.clone()).verify();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DegeneratePolynomial.to_string()
+        );
+
+        // Inconsistent matrix dimensions.
+        let bp = BivariatePolynomial::random(2, 2, &mut rng);
+        let share = SecretShare::new(x, bp.eval_x(&x));
+        let res = Shareholder::new(share, VerificationMatrix::from(&bp)).verify();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (3, 5),
+                actual: (3, 3),
+            }
+            .to_string()
+        );
+
+        // Polynomial degree inconsistent with the matrix.
+        let share = SecretShare::new(x, Polynomial::random(3, &mut rng));
+        let res = Shareholder::new(share, vm.clone()).verify();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 4,
+                actual: 3,
+            }
+            .to_string()
+        );
+
+        // Matrix not committing to the polynomial.
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let share = other.make_share(x, kind);
+            let res = Shareholder::new(share, vm.clone()).verify();
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidPolynomial.to_string()
+            );
+        }
+
+        // Zero-value shareholder.
+        let share = dealer.make_share(PrimeField::ZERO, HandoffKind::DealingPhase);
+        let res = Shareholder::new(share, vm.clone()).verify();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ZeroValueShareholder.to_string()
+        );
+
+        // Mismatched identity.
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let mut shareholder = Shareholder::new_with_id(x, share, vm).unwrap();
+        shareholder.id = Some(PrimeField::from_u64(2));
+        let res = shareholder.verify();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ShareholderIdentityMismatch.to_string()
+        );
+    }
+
+    #[test]
+    fn test_constant_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+
+        // Non-constant share.
+        let dealer = Dealer::new(1, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::CommitteeChanged);
+        assert!(!shareholder.verifiable_share().secret_share().is_constant());
+
+        // Under threshold zero, all switch points equal the secret point.
+        let dealer = Dealer::new(0, &mut rng).unwrap();
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let share = shareholder.verifiable_share().secret_share();
+            assert!(share.is_constant());
+
+            let y = *share.coordinate_y();
+            assert_eq!(shareholder.switch_point(&PrimeField::ZERO), y);
+            for x in &xs {
+                assert_eq!(shareholder.switch_point(x), y);
+            }
+            assert!(shareholder.switch_points(&xs).iter().all(|bij| *bij == y));
+
+            // A single point suffices for reconstruction.
+            let points = [(xs[0], y)];
+            assert_eq!(SecretShare::recover_from_points(&points, 0).unwrap(), y);
+        }
+    }
+
+    #[test]
+    fn test_verify_against() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let x = PrimeField::from_u64(3);
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            // Correct share.
+            let share = dealer.make_share(x, kind);
+            assert!(share.verify_against(vm).is_ok());
+
+            // Tampered share.
+            let mut p = share.polynomial().clone();
+            p.set_coefficient(1, *p.coefficient(1).unwrap() + PrimeField::ONE);
+            let tampered = SecretShare::new(x, p);
+            let res = tampered.verify_against(vm);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::PolynomialMatrixMismatch.to_string()
+            );
+
+            // Share at another coordinate or of another polynomial.
+            let moved = SecretShare::new(PrimeField::from_u64(4), share.polynomial().clone());
+            assert!(moved.verify_against(vm).is_err());
+            assert!(share.verify_against(other.verification_matrix()).is_err());
+        }
+
+        // Share of invalid size.
+        let share = SecretShare::new(x, Polynomial::random(3, &mut rng));
+        let res = share.verify_against(vm);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 4,
+                actual: 3
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_accumulate() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(2);
+        let p = Polynomial::random(3, &mut rng);
+        let q = Polynomial::random(3, &mut rng);
+        let share = SecretShare::new(x, p.clone());
+
+        // No shares.
+        let sum = SecretShare::accumulate(x, 3, []).unwrap();
+        assert_eq!(sum.coordinate_x(), &x);
+        assert!(sum.polynomial() == &Polynomial::zero(3));
+
+        // Single share.
+        let sum = SecretShare::accumulate(x, 3, [&share]).unwrap();
+        assert!(sum == share);
+
+        // Multiple shares.
+        let other = SecretShare::new(x, q.clone());
+        let sum = SecretShare::accumulate(x, 3, [&share, &other]).unwrap();
+        assert!(sum.polynomial() == &(p + q));
+
+        // Different shareholder.
+        let other = SecretShare::new(PrimeField::from_u64(3), Polynomial::random(3, &mut rng));
+        let res = SecretShare::accumulate(x, 3, [&share, &other]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ShareholderIdentityMismatch.to_string()
+        );
+
+        // Different degree.
+        let res = SecretShare::accumulate(x, 2, [&share]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 2,
+                actual: 3
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_secret() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(2);
+        let p = Polynomial::random(3, &mut rng);
+        let s = *p.coefficient(0).unwrap();
+
+        let share = SecretShare::new(x, p.clone());
+        assert_eq!(share.secret(), Some(&s));
+        assert_eq!(share.take_secret(), Some(s));
+
+        // Extracting the secret scrubs all coefficients.
+        let mut share = SecretShare::new(x, p);
+        assert_eq!(share.extract_secret(), Some(s));
+        assert!(share.polynomial().is_zero());
+        assert_eq!(share.polynomial().size(), 4);
+
+        // Empty polynomial.
+        let share = SecretShare::new(x, Polynomial { a: Vec::new() });
+        assert_eq!(share.secret(), None);
+        assert_eq!(share.take_secret(), None);
+    }
+
+    #[test]
+    fn test_reconstruction_threshold() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(1);
+
+        for deg in [0, 1, 2, 5, 10] {
+            let share = SecretShare::new(x, Polynomial::random(deg, &mut rng));
+            let threshold = share.reconstruction_threshold();
+            assert_eq!(threshold, deg as usize);
+            assert_eq!(churp::required_points(threshold), deg as usize + 1);
+        }
+
+        // Proactivization preserves the threshold.
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let share = shareholder.verifiable_share().secret_share();
+            let expected = share.reconstruction_threshold();
+
+            let proactive = Dealer::new_proactive(threshold, &mut rng).unwrap();
+            let p = proactive.make_share(*share.coordinate_x(), kind);
+            let vm = proactive.verification_matrix();
+            let proactivized = shareholder.proactivize(p.polynomial(), vm).unwrap();
+            let share = proactivized.verifiable_share().secret_share();
+            let threshold = share.reconstruction_threshold();
+            assert_eq!(threshold, expected);
+            assert_eq!(churp::required_points(threshold), share.polynomial().size());
+        }
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let hash = Group::random(&mut rng);
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let shareholder = prepare_shareholder(&dealer, 1, kind);
+            let mut clone = shareholder.clone();
+            assert!(clone == shareholder);
+            assert_eq!(clone.switch_points(&xs), shareholder.switch_points(&xs));
+            assert_eq!(
+                clone.key_share(hash).unwrap(),
+                shareholder.key_share(hash).unwrap()
+            );
+
+            // Proactivizing the clone leaves the original unchanged.
+            let x = *shareholder.verifiable_share().secret_share().coordinate_x();
+            let proactive = Dealer::new_proactive(2, &mut rng).unwrap();
+            let p = proactive.make_share(x, kind);
+            let vm = proactive.verification_matrix();
+            clone.proactivize_in_place(p.polynomial(), vm).unwrap();
+            assert!(clone != shareholder);
+            assert!(shareholder == prepare_shareholder(&dealer, 1, kind));
+
+            // Dropping the clone doesn't zeroize the original.
+            drop(clone);
+            let share = shareholder.verifiable_share().secret_share();
+            assert!(*share == dealer.make_share(x, kind));
+        }
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let kind = HandoffKind::DealingPhase;
+        let x = PrimeField::from_u64(1);
+        let y = PrimeField::from_u64(2);
+
+        // Equal shares.
+        let share = dealer.make_share(x, kind);
+        assert!(bool::from(share.ct_eq(&dealer.make_share(x, kind))));
+
+        // Different identities or polynomials.
+        assert!(!bool::from(share.ct_eq(&dealer.make_share(y, kind))));
+        assert!(!bool::from(share.ct_eq(&other.make_share(x, kind))));
+        let reduced = dealer.make_share(x, HandoffKind::CommitteeChanged);
+        assert!(!bool::from(share.ct_eq(&reduced)));
+    }
+
+    #[test]
+    fn test_to_full_and_reduced() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+        let x = PrimeField::from_u64(1);
+        let reduced = dealer.make_share(x, HandoffKind::CommitteeChanged);
+        let full = dealer.make_share(x, HandoffKind::DealingPhase);
+
+        // Reduced to full and back.
+        let promoted = reduced.to_full(bp).unwrap();
+        assert!(promoted.polynomial() == full.polynomial());
+        let demoted = promoted.to_reduced(bp).unwrap();
+        assert!(demoted.polynomial() == reduced.polynomial());
+        assert_eq!(demoted.coordinate_x(), reduced.coordinate_x());
+        assert_eq!(demoted.coordinate_y(), reduced.coordinate_y());
+
+        // Incompatible dimensions.
+        let res = full.to_full(bp);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 2,
+                actual: 4
+            }
+            .to_string()
+        );
+        let res = reduced.to_reduced(bp);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::PolynomialDegreeMismatch {
+                expected: 4,
+                actual: 2
+            }
+            .to_string()
+        );
+
+        // Share of another bivariate polynomial.
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let res = reduced.to_full(other.bivariate_polynomial());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidPolynomial.to_string()
+        );
+        let res = full.to_reduced(other.bivariate_polynomial());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidPolynomial.to_string()
+        );
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let mut share = dealer.make_share(x, HandoffKind::DealingPhase);
+        assert!(share.p.a.iter().any(|ai| !bool::from(ai.is_zero())));
+
+        share.zeroize();
+        assert_eq!(share.x, x);
+        assert!(share.p.a.iter().all(|ai| bool::from(ai.is_zero())));
+    }
+
+    #[test]
+    fn test_recover_canonical() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+
+        // Switch points B(i,me) sent by the shareholders of the old committee.
+        let me = PrimeField::from_u64(10);
+        let mut points: Vec<_> = (1..=6)
+            .map(|i| prepare_shareholder(&dealer, i, HandoffKind::DealingPhase))
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&me)))
+            .collect();
+        let expected = bp.eval(&PrimeField::ZERO, &me);
+
+        // Not enough or duplicate points.
+        let n = threshold as usize + 1;
+        let res = SecretShare::recover_canonical(&points[..n - 1], threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+
+        let duplicates = vec![points[0], points[1], points[0]];
+        let res = SecretShare::recover_canonical(&duplicates, threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+
+        // Corrupt the points of the shareholders that come last
+        // in the canonical order, which are never selected.
+        points.sort_unstable_by(|a, b| churp::cmp_shareholders(&a.0, &b.0));
+        for point in &mut points[n..] {
+            point.1 += PrimeField::ONE;
+        }
+
+        // Shuffled and over-provided points.
+        for _ in 0..10 {
+            points.shuffle(&mut rng);
+            let y = SecretShare::recover_canonical(&points, threshold).unwrap();
+            assert_eq!(y, expected);
+        }
+
+        // Duplicate shareholders that are never selected don't matter.
+        let mut with_duplicates = points.clone();
+        with_duplicates.sort_unstable_by(|a, b| churp::cmp_shareholders(&a.0, &b.0));
+        let last = with_duplicates[with_duplicates.len() - 1];
+        with_duplicates.push((last.0, last.1 + PrimeField::ONE));
+        for _ in 0..10 {
+            with_duplicates.shuffle(&mut rng);
+            let y = SecretShare::recover_canonical(&with_duplicates, threshold).unwrap();
+            assert_eq!(y, expected);
+        }
+
+        // Order-dependent recovery may pick corrupted points.
+        let mut reversed = points.clone();
+        reversed.sort_unstable_by(|a, b| churp::cmp_shareholders(&b.0, &a.0));
+        let y = SecretShare::recover_from_points(&reversed, threshold).unwrap();
+        assert_ne!(y, expected);
+    }
+
+    #[test]
+    fn test_decomposition() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let verifiable_share = shareholder.verifiable_share().clone();
+
+        // Verifiable secret share.
+        let (share, vm): (SecretShare, VerificationMatrix) = verifiable_share.clone().into();
+        assert!(&share == verifiable_share.secret_share());
+        assert_eq!(&vm, verifiable_share.verification_matrix());
+        let restored = VerifiableSecretShare::from((share, vm));
+        assert!(restored == verifiable_share);
+
+        // Secret share.
+        let share = verifiable_share.secret_share().clone();
+        let (x, p): (PrimeField, Polynomial) = share.clone().into();
+        assert_eq!(&x, share.coordinate_x());
+        assert!(&p == share.polynomial());
+        let restored = SecretShare::from((x, p));
+        assert!(restored == share);
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let alice = Dealer::new(2, &mut rng).unwrap();
+        let bob = Dealer::new(2, &mut rng).unwrap();
+        let a = prepare_shareholder(&alice, 1, HandoffKind::DealingPhase);
+        let b = prepare_shareholder(&bob, 2, HandoffKind::DealingPhase);
+        let a = a.verifiable_share();
+        let b = b.verifiable_share();
+
+        let share = SecretShare::conditional_select(&a.share, &b.share, Choice::from(0));
+        assert!(share == a.share);
+        let share = SecretShare::conditional_select(&a.share, &b.share, Choice::from(1));
+        assert!(share == b.share);
+
+        let share = VerifiableSecretShare::conditional_select(a, b, Choice::from(0));
+        assert!(&share == a);
+        let share = VerifiableSecretShare::conditional_select(a, b, Choice::from(1));
+        assert!(&share == b);
+    }
+
+    #[test]
+    #[should_panic(expected = "polynomial sizes should match")]
+    fn test_conditional_select_size_mismatch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let full = dealer.make_share(PrimeField::from_u64(1), HandoffKind::DealingPhase);
+        let reduced = dealer.make_share(PrimeField::from_u64(1), HandoffKind::CommitteeChanged);
+
+        SecretShare::conditional_select(&full, &reduced, Choice::from(1));
+    }
+
+    #[test]
+    fn test_recover_from_points() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+
+        // Switch points B(i,me) sent by the shareholders of the old committee.
+        let me = PrimeField::from_u64(10);
+        let points: Vec<_> = (1..=6)
+            .map(|i| prepare_shareholder(&dealer, i, HandoffKind::DealingPhase))
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&me)))
+            .collect();
+        let expected = bp.eval(&PrimeField::ZERO, &me);
+
+        // Not enough points.
+        let n = threshold as usize + 1;
+        let res = SecretShare::recover_from_points(&points[..n - 1], threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+
+        // Duplicate points.
+        let duplicates = vec![points[0], points[1], points[0]];
+        let res = SecretShare::recover_from_points(&duplicates, threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+
+        // Exact number of points.
+        let y = SecretShare::recover_from_points(&points[..n], threshold).unwrap();
+        assert_eq!(y, expected);
+
+        // Too many points.
+        let y = SecretShare::recover_from_points(&points, threshold).unwrap();
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn test_recover_at() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+
+        // Switch points B(i,me) sent by the shareholders of the old committee.
+        let me = PrimeField::from_u64(10);
+        let points: Vec<_> = (1..=6)
+            .map(|i| prepare_shareholder(&dealer, i, HandoffKind::DealingPhase))
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&me)))
+            .collect();
+        let n = threshold as usize + 1;
+
+        // Abscissae of members, of a sender and zero.
+        for id in [11, 12, 1, 0] {
+            let member_id = PrimeField::from_u64(id);
+            let expected = bp.eval(&member_id, &me);
+
+            for points in [&points[..n], &points[..]] {
+                let y = SecretShare::recover_at(points, threshold, &member_id).unwrap();
+                assert_eq!(y, expected);
+            }
+        }
+
+        // The zero case coincides with the recovery of the point at zero.
+        let y = SecretShare::recover_at(&points, threshold, &PrimeField::ZERO).unwrap();
+        assert_eq!(
+            y,
+            SecretShare::recover_from_points(&points, threshold).unwrap()
+        );
+
+        // Not enough points.
+        let res = SecretShare::recover_at(&points[..n - 1], threshold, &me);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+
+        // Duplicate points.
+        let duplicates = vec![points[0], points[1], points[0]];
+        let res = SecretShare::recover_at(&duplicates, threshold, &me);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+    }
+
+    #[test]
+    fn test_recover_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+        // Switch points B(i,me) sent by the shareholders of the old committee.
+        let me = PrimeField::from_u64(10);
+        let points: Vec<_> = (1..=6)
+            .map(|i| prepare_shareholder(&dealer, i, HandoffKind::DealingPhase))
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&me)))
+            .collect();
+        let expected = dealer.make_share(me, HandoffKind::CommitteeChanged);
+
+        // Not enough points.
+        let n = threshold as usize + 1;
+        let res = SecretShare::recover_polynomial(&points[..n - 1], threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+
+        // Duplicate points.
+        let duplicates = vec![points[0], points[1], points[0]];
+        let res = SecretShare::recover_polynomial(&duplicates, threshold);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+
+        // Exact and too many points.
+        for points in [&points[..n], &points[..]] {
+            let p = SecretShare::recover_polynomial(points, threshold).unwrap();
+            assert!(p == *expected.polynomial());
+            assert_eq!(p.coefficient(0), Some(expected.coordinate_y()));
+            assert_eq!(
+                *p.coefficient(0).unwrap(),
+                SecretShare::recover_from_points(points, threshold).unwrap()
+            );
+        }
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut s, b| {
+            write!(s, "{b:02x}").unwrap();
+            s
+        })
+    }
+
+    fn deterministic_test_vectors() -> String {
+        let seeds = [[0u8; 32], [1u8; 32]];
+        let kinds = [
+            ("DealingPhase", HandoffKind::DealingPhase),
+            ("CommitteeChanged", HandoffKind::CommitteeChanged),
+        ];
+
+        let mut vectors = Vec::new();
+        for seed in seeds {
+            for threshold in [0, 2] {
+                let dealer = Dealer::new_deterministic(threshold, &seed).unwrap();
+
+                for (name, kind) in kinds {
+                    let x = PrimeField::from_u64(1);
+                    let shareholder =
+                        Shareholder::generate_deterministic(threshold, &seed, x, kind).unwrap();
+
+                    vectors.push(serde_json::json!({
+                        "seed": to_hex(&seed),
+                        "threshold": threshold,
+                        "x": to_hex(&scalar_to_bytes(&x)),
+                        "kind": name,
+                        "secret": to_hex(&scalar_to_bytes(dealer.secret())),
+                        "shareholder": to_hex(&shareholder.to_bytes().unwrap()),
+                    }));
+                }
+            }
+        }
+
+        serde_json::to_string_pretty(&vectors).unwrap() + "\n"
+    }
+
+    #[test]
+    fn test_generate_deterministic() {
+        // To regenerate the test vectors, run the test with
+        // the REGENERATE_TEST_VECTORS environment variable set.
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/testdata/churp_deterministic.json"
+        );
+        let vectors = deterministic_test_vectors();
+        if env::var_os("REGENERATE_TEST_VECTORS").is_some() {
+            fs::write(path, &vectors).unwrap();
+        }
+        let expected = fs::read_to_string(path).unwrap();
+        assert_eq!(vectors, expected);
+    }
+
+    fn prepare_bench_switch_points(threshold: u8, n: usize) -> (Shareholder, Vec<PrimeField>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let shareholder = prepare_shareholder(&dealer, 1, HandoffKind::DealingPhase);
+        let xs = random_scalars(n, &mut rng);
+
+        (shareholder, xs)
+    }
+
+    fn bench_switch_point(b: &mut Bencher, threshold: u8, n: usize) {
+        let (shareholder, xs) = prepare_bench_switch_points(threshold, n);
+
+        b.iter(|| {
+            let _: Vec<_> = xs.iter().map(|x| shareholder.switch_point(x)).collect();
+        });
+    }
+
+    fn bench_switch_points(b: &mut Bencher, threshold: u8, n: usize) {
+        let (shareholder, xs) = prepare_bench_switch_points(threshold, n);
+
+        b.iter(|| {
+            let _ = shareholder.switch_points(&xs);
+        });
+    }
+
+    #[bench]
+    fn bench_switch_point_10_064(b: &mut Bencher) {
+        bench_switch_point(b, 10, 64)
+    }
+
+    #[bench]
+    fn bench_switch_point_20_128(b: &mut Bencher) {
+        bench_switch_point(b, 20, 128)
+    }
+
+    #[bench]
+    fn bench_switch_points_10_064(b: &mut Bencher) {
+        bench_switch_points(b, 10, 64)
+    }
+
+    #[bench]
+    fn bench_switch_points_20_128(b: &mut Bencher) {
+        bench_switch_points(b, 20, 128)
+    }
+
+    fn prepare_bench_verify_switch_points(
+        threshold: u8,
+        n: u64,
+    ) -> (Shareholder, Vec<(PrimeField, PrimeField)>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let me = prepare_shareholder(&dealer, n + 1, HandoffKind::DealingPhase);
+        let x = me.verifiable_share().share.x;
+        let points = (1..=n)
+            .map(|i| prepare_shareholder(&dealer, i, HandoffKind::DealingPhase))
+            .map(|sh| (sh.verifiable_share().share.x, sh.switch_point(&x)))
+            .collect();
+
+        (me, points)
+    }
+
+    fn bench_identify_bad_points(b: &mut Bencher, threshold: u8, n: u64) {
+        let (me, points) = prepare_bench_verify_switch_points(threshold, n);
+
+        b.iter(|| {
+            let _ = me.identify_bad_points(&points);
+        });
+    }
+
+    fn bench_verify_switch_points_batch(b: &mut Bencher, threshold: u8, n: u64) {
+        let (me, points) = prepare_bench_verify_switch_points(threshold, n);
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        b.iter(|| {
+            let _ = me.verify_switch_points_batch(&points, &mut rng);
+        });
+    }
+
+    #[bench]
+    fn bench_identify_bad_points_10_128(b: &mut Bencher) {
+        bench_identify_bad_points(b, 10, 128)
+    }
+
+    #[bench]
+    fn bench_verify_switch_points_batch_10_128(b: &mut Bencher) {
+        bench_verify_switch_points_batch(b, 10, 128)
+    }
+
+    fn bench_proactivize(b: &mut Bencher, threshold: u8, in_place: bool) {
+        let (mut shareholder, _) = prepare_bench_handoff::<Suite>(threshold, 1, false);
+        let p = Polynomial::zero(2 * threshold);
+        let vm =
+            VerificationMatrix::zero_hole(shareholder.verifiable_share().vm.dimensions()).unwrap();
+
+        b.iter(|| match in_place {
+            true => shareholder.proactivize_in_place(&p, &vm).unwrap(),
+            false => shareholder = shareholder.proactivize(&p, &vm).unwrap(),
+        });
+    }
+
+    #[bench]
+    fn bench_proactivize_032(b: &mut Bencher) {
+        bench_proactivize(b, 32, false)
+    }
+
+    #[bench]
+    fn bench_proactivize_in_place_032(b: &mut Bencher) {
+        bench_proactivize(b, 32, true)
+    }
+
+    fn prepare_bench_handoff<S: suites::Suite>(
+        threshold: u8,
+        n: u64,
+        verifiable: bool,
+    ) -> (churp::Shareholder<S::Group>, Vec<S::PrimeField>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = S::PrimeField::from(n + 1);
+        let xs = (1..=n).map(S::PrimeField::from).collect();
+
+        // Verification matrices are expensive to compute for large
+        // thresholds, so they are only computed if they are needed.
+        let shareholder = match verifiable {
+            true => {
+                let dealer = churp::Dealer::new(threshold, &mut rng).unwrap();
+                let share = dealer.make_share(x, HandoffKind::DealingPhase);
+                let vm = dealer.verification_matrix().clone();
+                churp::Shareholder::new(share, vm)
+            }
+            false => {
+                let p = poly::Polynomial::random(2 * threshold, &mut rng);
+                let share = churp::SecretShare::new(x, p);
+                let t = threshold as usize;
+                let vm = vss::VerificationMatrix::zero_hole((t + 1, 2 * t + 1)).unwrap();
+                churp::Shareholder::new(share, vm)
+            }
+        };
+
+        (shareholder, xs)
+    }
+
+    fn bench_handoff_switch_point<S: suites::Suite>(b: &mut Bencher, threshold: u8) {
+        let (shareholder, xs) = prepare_bench_handoff::<S>(threshold, 1, false);
+
+        b.iter(|| shareholder.switch_point(&xs[0]));
+    }
+
+    fn bench_handoff_switch_points<S: suites::Suite>(b: &mut Bencher, threshold: u8) {
+        // Switch points for the whole committee of 2t + 1 members.
+        let n = 2 * threshold as u64 + 1;
+        let (shareholder, xs) = prepare_bench_handoff::<S>(threshold, n, false);
+
+        b.iter(|| shareholder.switch_points(&xs));
+    }
+
+    fn bench_handoff_verify_switch_point<S: suites::Suite>(b: &mut Bencher, threshold: u8) {
+        // Both shareholders get shares from the same dealer.
+        let (me, _) = prepare_bench_handoff::<S>(threshold, 1, true);
+        let (peer, _) = prepare_bench_handoff::<S>(threshold, 0, true);
+        let x = me.verifiable_share().share.x;
+        let y = peer.verifiable_share().share.x;
+        let bij = peer.switch_point(&x);
+
+        b.iter(|| me.verify_switch_point(&y, &bij));
+    }
+
+    fn bench_handoff_recover_from_points<S: suites::Suite>(b: &mut Bencher, threshold: u8) {
+        let n = threshold as u64 + 1;
+        let (shareholder, xs) = prepare_bench_handoff::<S>(threshold, n, false);
+        let points: Vec<_> = xs
+            .iter()
+            .map(|x| (*x, shareholder.switch_point(x)))
+            .collect();
+
+        b.iter(|| churp::SecretShare::recover_from_points(&points, threshold).unwrap());
+    }
+
+    macro_rules! handoff_benches {
+        ($($suite:ident: $ty:ty),*) => {$(
+            mod $suite {
+                use super::{
+                    bench_handoff_recover_from_points, bench_handoff_switch_point,
+                    bench_handoff_switch_points, bench_handoff_verify_switch_point, test::Bencher,
+                };
+
+                #[bench]
+                fn bench_handoff_switch_point_032(b: &mut Bencher) {
+                    bench_handoff_switch_point::<$ty>(b, 32)
+                }
+
+                #[bench]
+                fn bench_handoff_switch_point_064(b: &mut Bencher) {
+                    bench_handoff_switch_point::<$ty>(b, 64)
+                }
+
+                #[bench]
+                fn bench_handoff_switch_point_127(b: &mut Bencher) {
+                    bench_handoff_switch_point::<$ty>(b, 127)
+                }
+
+                #[bench]
+                fn bench_handoff_switch_points_032(b: &mut Bencher) {
+                    bench_handoff_switch_points::<$ty>(b, 32)
+                }
+
+                #[bench]
+                #[ignore = "slow, run with `cargo bench -- --ignored`"]
+                fn bench_handoff_switch_points_127(b: &mut Bencher) {
+                    bench_handoff_switch_points::<$ty>(b, 127)
+                }
+
+                #[bench]
+                fn bench_handoff_verify_switch_point_002(b: &mut Bencher) {
+                    bench_handoff_verify_switch_point::<$ty>(b, 2)
+                }
+
+                #[bench]
+                #[ignore = "slow, run with `cargo bench -- --ignored`"]
+                fn bench_handoff_verify_switch_point_032(b: &mut Bencher) {
+                    bench_handoff_verify_switch_point::<$ty>(b, 32)
+                }
+
+                #[bench]
+                fn bench_handoff_recover_from_points_032(b: &mut Bencher) {
+                    bench_handoff_recover_from_points::<$ty>(b, 32)
+                }
+
+                #[bench]
+                #[ignore = "slow, run with `cargo bench -- --ignored`"]
+                fn bench_handoff_recover_from_points_127(b: &mut Bencher) {
+                    bench_handoff_recover_from_points::<$ty>(b, 127)
+                }
+            }
+        )*};
+    }
+
+    // Benchmarks of the handoff critical path on a large and a small curve,
+    // named after the threshold. Switch points are evaluated on polynomials
+    // of degree 2t, i.e. up to 254 for the largest threshold.
+    handoff_benches!(
+        p384_sha3_384: crate::suites::p384::Sha3_384,
+        ristretto255_sha512: crate::suites::ristretto255::Ristretto255Sha512
+    );
 }
@@ -0,0 +1,185 @@
+//! Asynchronous sources of switch points.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::stream::{FuturesUnordered, StreamExt};
+use group::{ff::Field, Group, GroupEncoding};
+
+use crate::vss::VerificationVector;
+
+use super::{required_points, Error};
+
+/// Source of switch points, e.g. a client fetching them from remote
+/// shareholders over the network.
+///
+/// The trait doesn't depend on any async runtime, so implementations
+/// are free to use whichever runtime the application runs on.
+#[async_trait]
+pub trait SwitchPointSource<G>: Send + Sync
+where
+    G: Group,
+{
+    /// Fetches the switch point of the shareholder with the given encoded
+    /// identity.
+    async fn fetch(&self, id: &G::Scalar) -> Result<G::Scalar>;
+}
+
+/// Concurrently fetches switch points from the given shareholders until
+/// `threshold + 1` of them have been verified against the verification
+/// vector.
+///
+/// The vector is derived from the verification matrix of the source
+/// committee, i.e. [`crate::vss::VerificationMatrix::verification_vector_for_x`]
+/// at the own identity verifies points `B(id,me)`, and
+/// [`crate::vss::VerificationMatrix::verification_vector_for_y`] verifies
+/// points `B(me,id)`.
+///
+/// Points are verified in the order in which they arrive. Shareholders
+/// whose fetch fails or whose point is invalid are skipped, as are zero
+/// and repeated identities, and the fetches still pending once enough
+/// points have been verified are dropped. The returned points `(id, point)`
+/// are in the order of arrival.
+///
+/// Fails if the shareholders run out before enough points are verified.
+pub async fn collect_until_threshold<G, S>(
+    source: &S,
+    vv: &VerificationVector<G>,
+    ids: &[G::Scalar],
+    threshold: usize,
+) -> Result<Vec<(G::Scalar, G::Scalar)>>
+where
+    G: Group + GroupEncoding,
+    S: SwitchPointSource<G> + ?Sized,
+{
+    let n = required_points(threshold);
+    let mut fetches = FuturesUnordered::new();
+    for (i, id) in ids.iter().enumerate() {
+        if id.is_zero().into() || ids[..i].contains(id) {
+            continue;
+        }
+        fetches.push(async move { (*id, source.fetch(id).await) });
+    }
+
+    let mut points = Vec::with_capacity(n);
+    while let Some((id, res)) = fetches.next().await {
+        let point = match res {
+            Ok(point) => point,
+            Err(_) => continue,
+        };
+        if !vv.verify(&id, &point) {
+            continue;
+        }
+
+        points.push((id, point));
+        if points.len() >= n {
+            return Ok(points);
+        }
+    }
+
+    Err(Error::NotEnoughSwitchPoints.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::{anyhow, Result};
+    use async_trait::async_trait;
+    use futures::executor::block_on;
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, collect_until_threshold, Error, SwitchPointSource},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+
+    /// Source serving canned responses, failing for unknown shareholders.
+    struct MockSource {
+        points: HashMap<u64, Result<PrimeField>>,
+    }
+
+    #[async_trait]
+    impl SwitchPointSource<Group> for MockSource {
+        async fn fetch(&self, id: &PrimeField) -> Result<PrimeField> {
+            let id = (1..10)
+                .find(|x| PrimeField::from_u64(*x) == *id)
+                .ok_or_else(|| anyhow!("unknown shareholder"))?;
+            match self.points.get(&id) {
+                Some(Ok(point)) => Ok(*point),
+                _ => Err(anyhow!("fetch failed")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_collect_until_threshold() {
+        let threshold = 2;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let bp = dealer.bivariate_polynomial();
+        let me = PrimeField::from_u64(9);
+        let vv = dealer.verification_matrix().verification_vector_for_x(&me);
+
+        // Shareholders 1, 3 and 5 are honest, 2 and 4 send invalid points,
+        // and the fetch from 6 fails.
+        let mut points = HashMap::new();
+        for x in [1, 3, 5].iter() {
+            points.insert(*x, Ok(bp.eval(&PrimeField::from_u64(*x), &me)));
+        }
+        for x in [2, 4].iter() {
+            points.insert(*x, Ok(PrimeField::random(&mut rng)));
+        }
+        points.insert(6, Err(anyhow!("unreachable")));
+        let source = MockSource { points };
+
+        let ids: Vec<_> = (1..=6).map(PrimeField::from_u64).collect();
+        let collected = block_on(collect_until_threshold(
+            &source,
+            &vv,
+            &ids,
+            threshold as usize,
+        ))
+        .unwrap();
+        assert_eq!(collected.len(), threshold as usize + 1);
+        for (x, point) in &collected {
+            assert_eq!(point, &bp.eval(x, &me));
+        }
+
+        // More points than needed are never collected.
+        let collected = block_on(collect_until_threshold(&source, &vv, &ids, 1)).unwrap();
+        assert_eq!(collected.len(), 2);
+
+        // Repeated and zero identities don't count toward the threshold.
+        let ids: Vec<_> = [1, 1, 3, 3, 0, 2]
+            .iter()
+            .copied()
+            .map(PrimeField::from_u64)
+            .collect();
+        let res = block_on(collect_until_threshold(
+            &source,
+            &vv,
+            &ids,
+            threshold as usize,
+        ));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+
+        // Too few honest shareholders.
+        let ids: Vec<_> = (1..=6).map(PrimeField::from_u64).collect();
+        let res = block_on(collect_until_threshold(&source, &vv, &ids, 3));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NotEnoughSwitchPoints.to_string()
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! CHURP shareholders over suites selected at runtime.
+
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use curve25519_dalek::RistrettoPoint;
+use p384::ProjectivePoint;
+
+use crate::suites::SuiteId;
+
+use super::{Error, Shareholder};
+
+/// Shareholder over the group of one of the supported suites.
+///
+/// Shareholders are generic over the group, which must be known at compile
+/// time. This type dispatches on the suite identifier instead, so that
+/// shareholders produced under different suites can be decoded at runtime.
+pub enum SuiteShareholder {
+    /// Shareholder over the NIST P-384 group.
+    P384Sha3_384(Shareholder<ProjectivePoint>),
+    /// Shareholder over the Ristretto255 group.
+    Ristretto255Sha512(Shareholder<RistrettoPoint>),
+}
+
+impl SuiteShareholder {
+    /// Returns the identifier of the suite of the shareholder.
+    pub fn suite_id(&self) -> SuiteId {
+        match self {
+            Self::P384Sha3_384(_) => SuiteId::P384Sha3_384,
+            Self::Ristretto255Sha512(_) => SuiteId::Ristretto255Sha512,
+        }
+    }
+
+    /// Returns the byte representation of the shareholder.
+    ///
+    /// The representation consists of the numeric tag of the suite,
+    /// followed by the byte representation of the shareholder.
+    ///
+    /// Fails if the shareholder cannot be encoded, see [`Shareholder::to_bytes`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let bytes = match self {
+            Self::P384Sha3_384(shareholder) => shareholder.to_bytes()?,
+            Self::Ristretto255Sha512(shareholder) => shareholder.to_bytes()?,
+        };
+
+        let mut tagged = Vec::with_capacity(1 + bytes.len());
+        tagged.push(self.suite_id().tag());
+        tagged.extend_from_slice(&bytes);
+
+        Ok(tagged)
+    }
+
+    /// Attempts to create a shareholder over the group of the given suite
+    /// from its byte representation.
+    ///
+    /// Fails if the shareholder was encoded under a different suite.
+    pub fn from_bytes_with_suite(id: SuiteId, bytes: &[u8]) -> Result<Self> {
+        let (&tag, bytes) = bytes.split_first().ok_or(Error::InvalidEncoding)?;
+        if tag != id.tag() {
+            return Err(Error::SuiteMismatch.into());
+        }
+
+        let shareholder = match id {
+            SuiteId::P384Sha3_384 => Self::P384Sha3_384(Shareholder::from_bytes(bytes)?),
+            SuiteId::Ristretto255Sha512 => {
+                Self::Ristretto255Sha512(Shareholder::from_bytes(bytes)?)
+            }
+        };
+
+        Ok(shareholder)
+    }
+}
+
+impl From<Shareholder<ProjectivePoint>> for SuiteShareholder {
+    fn from(shareholder: Shareholder<ProjectivePoint>) -> Self {
+        Self::P384Sha3_384(shareholder)
+    }
+}
+
+impl From<Shareholder<RistrettoPoint>> for SuiteShareholder {
+    fn from(shareholder: Shareholder<RistrettoPoint>) -> Self {
+        Self::Ristretto255Sha512(shareholder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{Dealer, Error, HandoffKind, Shareholder},
+        suites::{p384, ristretto255, FieldDigest, SuiteId},
+    };
+
+    use super::SuiteShareholder;
+
+    fn prepare_shareholders() -> (SuiteShareholder, SuiteShareholder) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let kind = HandoffKind::DealingPhase;
+
+        let dealer = Dealer::<::p384::ProjectivePoint>::new(2, &mut rng).unwrap();
+        let x = p384::Sha3_384::hash_to_field(b"shareholder", b"dst").unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let a = Shareholder::new(dealer.make_share(x, kind), vm).into();
+
+        let dealer = Dealer::<curve25519_dalek::RistrettoPoint>::new(2, &mut rng).unwrap();
+        let x = ristretto255::Ristretto255Sha512::hash_to_field(b"shareholder", b"dst").unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let b = Shareholder::new(dealer.make_share(x, kind), vm).into();
+
+        (a, b)
+    }
+
+    #[test]
+    fn test_serialization() {
+        let (a, b) = prepare_shareholders();
+
+        for shareholder in [a, b] {
+            let id = shareholder.suite_id();
+            let bytes = shareholder.to_bytes().unwrap();
+            assert_eq!(bytes[0], id.tag());
+
+            let restored = SuiteShareholder::from_bytes_with_suite(id, &bytes).unwrap();
+            assert_eq!(restored.suite_id(), id);
+            assert_eq!(restored.to_bytes().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_suite_mismatch() {
+        let (a, b) = prepare_shareholders();
+
+        for (shareholder, other) in [(a, SuiteId::Ristretto255Sha512), (b, SuiteId::P384Sha3_384)] {
+            let bytes = shareholder.to_bytes().unwrap();
+            let res = SuiteShareholder::from_bytes_with_suite(other, &bytes);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::SuiteMismatch.to_string()
+            );
+        }
+
+        // Empty encoding.
+        let res = SuiteShareholder::from_bytes_with_suite(SuiteId::P384Sha3_384, &[]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    mem,
+    sync::{Arc, Mutex},
+};
 
 use anyhow::Result;
-use group::{Group, GroupEncoding};
+use group::{ff::Field, Group, GroupEncoding};
+use zeroize::Zeroizing;
 
 use crate::{
     poly::{lagrange::lagrange, Polynomial},
@@ -68,6 +72,10 @@ where
 
     /// Current state of the switch.
     state: Mutex<DimensionSwitchState<G>>,
+
+    /// The shareholders that sent invalid switch points, or conflicting
+    /// switch points for the same identity, in the order of detection.
+    blamed: Mutex<Vec<G::Scalar>>,
 }
 
 impl<G> DimensionSwitch<G>
@@ -112,6 +120,7 @@ where
         shareholders: Vec<G::Scalar>,
     ) -> Result<Self> {
         let state = Mutex::new(DimensionSwitchState::WaitingForVerificationMatrix);
+        let blamed = Mutex::new(Vec::new());
 
         Ok(Self {
             threshold,
@@ -120,6 +129,7 @@ where
             me,
             shareholders,
             state,
+            blamed,
         })
     }
 
@@ -180,7 +190,25 @@ where
             _ => return Err(Error::InvalidState.into()),
         };
 
-        let done = sp.add_point(x, bij)?;
+        let done = match sp.add_point(x, bij) {
+            Ok(done) => done,
+            Err(err) => {
+                // Blame shareholders that sent invalid points or equivocated,
+                // but not those that merely resent the same point.
+                let blame = match err.downcast_ref::<Error>() {
+                    Some(Error::InvalidSwitchPoint) => true,
+                    Some(Error::DuplicateShareholder) => sp.point(&x) != Some(&bij),
+                    _ => false,
+                };
+                if blame {
+                    let mut blamed = self.blamed.lock().unwrap();
+                    if !blamed.contains(&x) {
+                        blamed.push(x);
+                    }
+                }
+                return Err(err);
+            }
+        };
         if done {
             let shareholder = sp.reconstruct_shareholder()?;
             let shareholder = Arc::new(shareholder);
@@ -203,6 +231,12 @@ where
         Ok(done)
     }
 
+    /// Returns the shareholders that sent invalid or conflicting switch
+    /// points, in the order in which they were detected.
+    pub(crate) fn blamed_shareholders(&self) -> Vec<G::Scalar> {
+        self.blamed.lock().unwrap().clone()
+    }
+
     /// Checks if the switch is waiting for a shareholder.
     pub(crate) fn is_waiting_for_shareholder(&self) -> bool {
         let state = self.state.lock().unwrap();
@@ -333,7 +367,11 @@ where
         let cols = 2 * threshold + 1;
 
         if vm.dimensions() != (rows, cols) {
-            return Err(Error::VerificationMatrixDimensionMismatch.into());
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: (rows, cols),
+                actual: vm.dimensions(),
+            }
+            .into());
         }
 
         // Precomputing the verification vector speeds up switch point
@@ -370,6 +408,11 @@ where
         !self.xs.contains(x)
     }
 
+    /// Returns the switch point received from the given shareholder.
+    fn point(&self, x: &G::Scalar) -> Option<&G::Scalar> {
+        self.xs.iter().position(|xi| xi == x).map(|i| &self.bijs[i])
+    }
+
     /// Verifies and adds the given switch point.
     ///
     /// Returns true if enough points have been received; otherwise,
@@ -412,7 +455,11 @@ where
         let p = lagrange(xs, ys);
 
         if p.size() != self.n {
-            return Err(Error::PolynomialDegreeMismatch.into());
+            return Err(Error::PolynomialDegreeMismatch {
+                expected: self.n - 1,
+                actual: p.size() - 1,
+            }
+            .into());
         }
 
         let x = self.me.take().ok_or(Error::ShareholderIdentityRequired)?;
@@ -424,6 +471,112 @@ where
     }
 }
 
+/// An incremental accumulator for switch points.
+///
+/// Unlike interpolating the received points from scratch, the accumulator
+/// updates the denominators `d_i = \prod_{j != i} (x_i - x_j)` of the Lagrange
+/// basis polynomials as points arrive, so adding a point takes a linear
+/// number of field multiplications and recovery needs no further products
+/// over all pairs of points.
+///
+/// The accumulator doesn't verify switch points.
+pub struct SwitchPointAccumulator<G>
+where
+    G: Group,
+{
+    /// The encoded identities of the shareholders whose points have been
+    /// received.
+    xs: Vec<G::Scalar>,
+    /// The received switch points.
+    ys: Vec<G::Scalar>,
+    /// The denominators of the Lagrange basis polynomials.
+    ds: Vec<G::Scalar>,
+}
+
+impl<G> SwitchPointAccumulator<G>
+where
+    G: Group,
+{
+    /// Creates a new empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            xs: Vec::new(),
+            ys: Vec::new(),
+            ds: Vec::new(),
+        }
+    }
+
+    /// Returns the number of accumulated switch points.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Returns true iff no switch points have been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Adds the switch point of the given shareholder.
+    ///
+    /// Fails if the shareholder already provided a point or if its
+    /// identity is zero.
+    pub fn add(&mut self, x: G::Scalar, y: G::Scalar) -> Result<()> {
+        if x.is_zero().into() {
+            return Err(Error::ZeroValueShareholder.into());
+        }
+        if self.xs.contains(&x) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+
+        let mut d = G::Scalar::ONE;
+        for (xi, di) in self.xs.iter().zip(self.ds.iter_mut()) {
+            *di *= *xi - x;
+            d *= x - xi;
+        }
+
+        self.xs.push(x);
+        self.ys.push(y);
+        self.ds.push(d);
+
+        Ok(())
+    }
+
+    /// Recovers the value at zero of the polynomial of the given degree
+    /// on which the switch points lie, once at least `threshold + 1`
+    /// points have been accumulated.
+    ///
+    /// All accumulated points are interpolated, so they must all lie on
+    /// the same polynomial.
+    pub fn try_recover(&self, threshold: usize) -> Option<G::Scalar> {
+        if self.xs.len() <= threshold {
+            return None;
+        }
+
+        // Evaluate the Lagrange basis polynomials at zero:
+        // L_i(0) = \prod_{j != i} (0 - x_j) / (x_i - x_j)
+        //        = \prod_j (-x_j) / (-x_i * d_i).
+        let numerator: G::Scalar = self.xs.iter().map(|x| -*x).product();
+        let mut sum = G::Scalar::ZERO;
+        for ((xi, yi), di) in self.xs.iter().zip(&self.ys).zip(&self.ds) {
+            let inv = (-*xi * di)
+                .invert()
+                .expect("identities are distinct and non-zero");
+            sum += *yi * inv;
+        }
+
+        Some(numerator * sum)
+    }
+}
+
+impl<G> Default for SwitchPointAccumulator<G>
+where
+    G: Group,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// An accumulator for bivariate shares.
 struct BivariateShares<G>
 where
@@ -451,8 +604,8 @@ where
     /// The shareholder to be proactivized with bivariate shares.
     shareholder: Option<Arc<Shareholder<G>>>,
 
-    /// The sum of the received bivariate shares.
-    p: Option<Polynomial<G::Scalar>>,
+    /// The sum of the received bivariate shares, which is zeroized on drop.
+    p: Option<Zeroizing<Polynomial<G::Scalar>>>,
 
     /// The sum of the verification matrices of the received bivariate shares.
     vm: Option<VerificationMatrix<G>>,
@@ -521,11 +674,10 @@ where
         }
         verifiable_share.verify(self.threshold, self.zero_hole, self.full_share)?;
 
-        let p = match self.p.take() {
-            Some(p) => p + verifiable_share.share.p,
-            None => verifiable_share.share.p,
+        match &mut self.p {
+            Some(p) => **p += &verifiable_share.share.p,
+            None => self.p = Some(Zeroizing::new(verifiable_share.share.p.clone())),
         };
-        self.p = Some(p);
 
         let vm = match self.vm.take() {
             Some(vm) => vm + verifiable_share.vm,
@@ -552,7 +704,7 @@ where
             return Err(Error::NotEnoughBivariateShares.into());
         }
 
-        let p = self
+        let mut p = self
             .p
             .take()
             .ok_or(Error::ShareholderProactivizationCompleted)?;
@@ -562,9 +714,11 @@ where
             .ok_or(Error::ShareholderProactivizationCompleted)?;
 
         let shareholder = match &self.shareholder {
-            Some(shareholder) => shareholder.proactivize(&p, &vm)?,
+            // The combined polynomial is consistent with the combined
+            // verification matrix, as all bivariate shares were verified.
+            Some(shareholder) => shareholder.proactivize_unverified(&p, &vm)?,
             None => {
-                let share = SecretShare::new(self.me, p);
+                let share = SecretShare::new(self.me, mem::take(&mut *p));
                 Shareholder::new(share, vm)
             }
         };
@@ -582,22 +736,31 @@ where
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use rand::{rngs::StdRng, SeedableRng};
+    use group::ff::Field;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
 
     use crate::{
-        churp::{SecretShare, VerifiableSecretShare},
+        churp::{self, HandoffKind, Player, SecretShare, Shareholder, VerifiableSecretShare},
         poly,
         suites::{self, p384},
         vss,
     };
 
-    use super::{BivariateShares, Error, SwitchPoints};
+    use super::{BivariateShares, Error, SwitchPointAccumulator, SwitchPoints};
 
     type Suite = p384::Sha3_384;
     type Group = <Suite as suites::Suite>::Group;
     type PrimeField = <Suite as suites::Suite>::PrimeField;
     type BivariatePolynomial = poly::BivariatePolynomial<<Suite as suites::Suite>::PrimeField>;
     type VerificationMatrix = vss::VerificationMatrix<<Suite as suites::Suite>::Group>;
+    type Dealer = churp::Dealer<Group>;
+
+    /// The number of random committees and the maximum threshold used
+    /// in randomized reconstruction tests.
+    #[cfg(not(feature = "slow-tests"))]
+    const RANDOM_COMMITTEES: (usize, u8) = (8, 4);
+    #[cfg(feature = "slow-tests")]
+    const RANDOM_COMMITTEES: (usize, u8) = (128, 32);
 
     fn prepare_shareholder(id: u64) -> PrimeField {
         id.into()
@@ -779,7 +942,11 @@ mod tests {
                 assert!(res.is_err());
                 assert_eq!(
                     res.unwrap_err().to_string(),
-                    Error::VerificationMatrixDimensionMismatch.to_string()
+                    Error::VerificationMatrixDimensionMismatch {
+                        expected: (3, 5),
+                        actual: (4, 7)
+                    }
+                    .to_string()
                 );
 
                 // Add share.
@@ -839,4 +1006,88 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_switch_point_accumulator() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let threshold = 2;
+        let bp = BivariatePolynomial::random(threshold, 2 * threshold, &mut rng);
+        let me = prepare_shareholder(1);
+        let expected = bp.eval(&PrimeField::ZERO, &me);
+
+        // Switch points B(x,me) lie on a polynomial of degree threshold,
+        // so recovery succeeds once there are threshold + 1 points.
+        let mut acc = SwitchPointAccumulator::<Group>::new();
+        assert!(acc.is_empty());
+        for id in 1..=threshold as u64 + 1 {
+            assert_eq!(acc.try_recover(threshold as usize), None);
+
+            let x = prepare_shareholder(id);
+            acc.add(x, bp.eval(&x, &me)).unwrap();
+            assert_eq!(acc.len(), id as usize);
+        }
+        assert_eq!(acc.try_recover(threshold as usize), Some(expected));
+
+        // Additional points don't change the result.
+        let x = prepare_shareholder(7);
+        acc.add(x, bp.eval(&x, &me)).unwrap();
+        assert_eq!(acc.try_recover(threshold as usize), Some(expected));
+
+        // Duplicate and zero shareholders.
+        let res = acc.add(x, PrimeField::ONE);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::DuplicateShareholder.to_string()
+        );
+        let res = acc.add(PrimeField::ZERO, PrimeField::ONE);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::ZeroValueShareholder.to_string()
+        );
+    }
+
+    #[test]
+    fn test_random_reconstruction() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let (committees, max_threshold) = RANDOM_COMMITTEES;
+        let kind = HandoffKind::DealingPhase;
+
+        for _ in 0..committees {
+            let threshold = rng.gen_range(0..=max_threshold);
+            let t = threshold as usize;
+            let secret = PrimeField::random(&mut rng);
+            let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+            let bp = dealer.bivariate_polynomial();
+            let vm = dealer.verification_matrix();
+
+            // Random shareholders, distinct with overwhelming probability.
+            let xs: Vec<_> = (0..=t).map(|_| PrimeField::random(&mut rng)).collect();
+            let shares = dealer.make_shares(xs.clone(), kind);
+
+            // Secret.
+            let player = Player::new(threshold, kind);
+            let recovered = player.recover_secret(&shares).unwrap();
+            assert_eq!(recovered, secret);
+
+            // Switch points B(x,me) of a random shareholder.
+            let me = PrimeField::random(&mut rng);
+            let mut acc = SwitchPointAccumulator::<Group>::new();
+            for (x, share) in xs.iter().zip(shares) {
+                let shareholder = Shareholder::new(share, vm.clone());
+                let bij = shareholder.switch_point(&me);
+                assert!(acc.try_recover(t).is_none());
+                if acc.len() == t && t > 0 {
+                    // Too few points yield a different value.
+                    let value = acc.try_recover(t - 1).unwrap();
+                    assert_ne!(value, bp.eval(&PrimeField::ZERO, &me));
+                }
+                acc.add(*x, bij).unwrap();
+            }
+            let expected = bp.eval(&PrimeField::ZERO, &me);
+            assert_eq!(acc.try_recover(t), Some(expected));
+        }
+    }
 }
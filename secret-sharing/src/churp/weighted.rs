@@ -0,0 +1,190 @@
+//! CHURP weighted shareholder.
+
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use group::{ff::Field, Group, GroupEncoding};
+
+use crate::{poly::distinct_abscissae, vss::VerificationMatrix};
+
+use super::{Error, SecretShare, Shareholder};
+
+/// Weighted shareholder holds several shares of the same bivariate
+/// polynomial, one per abscissa, in weighted-threshold settings.
+///
+/// The weight of the shareholder is the number of its shares. Each share
+/// yields its own switch point and key share, so reconstruction counts
+/// the points of a weighted shareholder toward the threshold according
+/// to its weight, without any changes to the combining logic.
+pub struct WeightedShareholder<G: Group + GroupEncoding> {
+    /// The shareholders of the individual shares.
+    shareholders: Vec<Shareholder<G>>,
+}
+
+impl<G> WeightedShareholder<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Creates a new weighted shareholder from the given shares, which must
+    /// be committed to by the given verification matrix.
+    ///
+    /// Fails if no shares are given, or if the abscissae of the shares are
+    /// not distinct and non-zero.
+    pub fn new(shares: Vec<SecretShare<G::Scalar>>, vm: VerificationMatrix<G>) -> Result<Self> {
+        if shares.is_empty() {
+            return Err(Error::NotEnoughShareholders.into());
+        }
+        if shares.iter().any(|s| s.coordinate_x().is_zero().into()) {
+            return Err(Error::ZeroValueShareholder.into());
+        }
+        let points: Vec<_> = shares.iter().map(|s| (*s.coordinate_x(), ())).collect();
+        if !distinct_abscissae(&points) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+
+        let shareholders = shares
+            .into_iter()
+            .map(|share| Shareholder::new(share, vm.clone()))
+            .collect();
+
+        Ok(Self { shareholders })
+    }
+
+    /// Returns the weight of the shareholder, i.e. the number of its shares.
+    pub fn weight(&self) -> usize {
+        self.shareholders.len()
+    }
+
+    /// Returns the shareholders of the individual shares.
+    pub fn shareholders(&self) -> &[Shareholder<G>] {
+        &self.shareholders
+    }
+
+    /// Returns the abscissae of the shares.
+    pub fn abscissae(&self) -> Vec<G::Scalar> {
+        self.shareholders
+            .iter()
+            .map(|s| *s.verifiable_share().secret_share().coordinate_x())
+            .collect()
+    }
+
+    /// Computes the switch points of all shares for the given shareholder.
+    ///
+    /// The returned points are in the same order as the shares.
+    pub fn switch_points(&self, id: &G::Scalar) -> Vec<G::Scalar> {
+        self.shareholders
+            .iter()
+            .map(|s| s.switch_point(id))
+            .collect()
+    }
+
+    /// Computes the key shares `(x_i, B(x_i,0) * H)` of all shares
+    /// for the given key hash.
+    ///
+    /// The returned key shares are in the same order as the shares and can
+    /// be combined with those of other shareholders, see
+    /// [`super::combine_key_shares`].
+    pub fn key_shares(&self, hash: G) -> Result<Vec<(G::Scalar, G)>> {
+        self.shareholders
+            .iter()
+            .map(|s| {
+                let x = *s.verifiable_share().secret_share().coordinate_x();
+                Ok((x, s.key_share(hash)?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Group as _;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, combine_key_shares, Error, HandoffKind, Player},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type WeightedShareholder = churp::WeightedShareholder<Group>;
+
+    fn prepare_weighted_shareholder(dealer: &Dealer, xs: &[u64]) -> WeightedShareholder {
+        let xs = xs.iter().copied().map(PrimeField::from_u64).collect();
+        let shares = dealer.make_shares(xs, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+        WeightedShareholder::new(shares, vm).unwrap()
+    }
+
+    #[test]
+    fn test_weighted_reconstruction() {
+        let threshold = 2;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+
+        let alice = prepare_weighted_shareholder(&dealer, &[1, 2]);
+        let bob = prepare_weighted_shareholder(&dealer, &[3]);
+        assert_eq!(alice.weight(), 2);
+        assert_eq!(bob.weight(), 1);
+
+        // Secret.
+        let shares: Vec<_> = alice
+            .shareholders()
+            .iter()
+            .chain(bob.shareholders())
+            .map(|s| s.verifiable_share().secret_share().clone())
+            .collect();
+        let player = Player::new(threshold, HandoffKind::DealingPhase);
+        let secret = player.recover_secret(&shares).unwrap();
+        assert_eq!(&secret, dealer.secret());
+
+        // Key.
+        let hash = Group::random(&mut rng);
+        let mut key_shares = alice.key_shares(hash).unwrap();
+        key_shares.extend(bob.key_shares(hash).unwrap());
+        let key = combine_key_shares::<Group>(&key_shares, threshold as usize).unwrap();
+        assert_eq!(key, hash * dealer.secret());
+
+        // The weighted shareholder alone doesn't meet the threshold.
+        let res = combine_key_shares::<Group>(&alice.key_shares(hash).unwrap(), 2);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_switch_points() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let alice = prepare_weighted_shareholder(&dealer, &[1, 2]);
+        let id = PrimeField::from_u64(5);
+
+        let points = alice.switch_points(&id);
+        assert_eq!(points.len(), 2);
+        for (x, point) in alice.abscissae().iter().zip(&points) {
+            let expected = dealer.bivariate_polynomial().eval(x, &id);
+            assert_eq!(point, &expected);
+        }
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix().clone();
+
+        let test_cases = vec![
+            (vec![], Error::NotEnoughShareholders),
+            (vec![1, 1], Error::DuplicateShareholder),
+            (vec![0, 1], Error::ZeroValueShareholder),
+        ];
+
+        for (xs, err) in test_cases {
+            let xs = xs.into_iter().map(PrimeField::from_u64).collect();
+            let shares = dealer.make_shares(xs, HandoffKind::DealingPhase);
+            let res = WeightedShareholder::new(shares, vm.clone());
+            assert!(res.is_err());
+            assert_eq!(res.err().unwrap().to_string(), err.to_string());
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! C ABI for the core operations of CHURP shareholders.
+//!
+//! Shareholders of the NIST P-384 suite with the SHA3-384 hash function
+//! are passed across the boundary as opaque handles, which must be freed
+//! using [`churp_p384_shareholder_free`].
+//!
+//! All functions return [`FFI_OK`] on success and the code of the failure
+//! otherwise, see [`Error::code`]. Outputs are written into caller-provided
+//! buffers, which must be large enough to hold them.
+
+use core::{ptr, slice};
+
+use anyhow::Result;
+use group::GroupEncoding;
+use zeroize::Zeroize;
+
+use crate::{
+    churp::{encode_shareholder, Error, Shareholder},
+    poly::scalar_to_bytes,
+    suites::{p384, Suite},
+};
+
+/// The suite of the shareholders.
+type FfiSuite = p384::Sha3_384;
+
+/// The group of the shareholders.
+type FfiGroup = <FfiSuite as Suite>::Group;
+
+/// Opaque handle of a shareholder.
+pub type ShareholderHandle = Shareholder<FfiGroup>;
+
+/// The code returned on success.
+pub const FFI_OK: u32 = 0;
+
+/// The code returned for failures that don't originate from CHURP.
+pub const FFI_UNKNOWN_ERROR: u32 = u32::MAX;
+
+/// The size of a shareholder ID in bytes.
+pub const FFI_ID_SIZE: usize = 32;
+
+/// The size of a serialized switch point in bytes.
+pub const FFI_SWITCH_POINT_SIZE: usize = 48;
+
+/// The size of a serialized key share or key hash in bytes.
+pub const FFI_POINT_SIZE: usize = 49;
+
+/// Creates a shareholder from its byte representation and stores its handle
+/// in the given output.
+///
+/// # Safety
+///
+/// `bytes` must be valid for reads of `len` bytes, and `out` must be valid
+/// for writes of a handle pointer.
+#[no_mangle]
+pub unsafe extern "C" fn churp_p384_shareholder_from_bytes(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut ShareholderHandle,
+) -> u32 {
+    into_code((|| {
+        if bytes.is_null() || out.is_null() {
+            return Err(Error::NullPointer.into());
+        }
+
+        let bytes = slice::from_raw_parts(bytes, len);
+        let shareholder = Shareholder::from_bytes(bytes)?;
+        *out = Box::into_raw(Box::new(shareholder));
+
+        Ok(())
+    })())
+}
+
+/// Frees the given shareholder handle.
+///
+/// Null handles are ignored.
+///
+/// # Safety
+///
+/// `handle` must be null or a handle created by this module that hasn't
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn churp_p384_shareholder_free(handle: *mut ShareholderHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Computes the switch point for the shareholder with the given ID,
+/// encoded using the given domain separation tag, and writes it
+/// into the output buffer.
+///
+/// The output buffer must hold at least [`FFI_SWITCH_POINT_SIZE`] bytes.
+///
+/// # Safety
+///
+/// `handle` must be a valid shareholder handle, `id` must be valid for reads
+/// of [`FFI_ID_SIZE`] bytes, `dst` must be valid for reads of `dst_len` bytes,
+/// and `out` must be valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn churp_p384_switch_point(
+    handle: *const ShareholderHandle,
+    id: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> u32 {
+    into_code((|| {
+        if handle.is_null() || id.is_null() || dst.is_null() {
+            return Err(Error::NullPointer.into());
+        }
+
+        let shareholder = &*handle;
+        let id = slice::from_raw_parts(id, FFI_ID_SIZE);
+        let dst = slice::from_raw_parts(dst, dst_len);
+        let x = encode_shareholder::<FfiSuite>(id, dst)?;
+
+        let mut bytes = scalar_to_bytes(&shareholder.switch_point(&x));
+        let res = write_output(&bytes, out, out_len);
+        bytes.zeroize();
+
+        res
+    })())
+}
+
+/// Computes the key share for the given key hash and writes it
+/// into the output buffer.
+///
+/// The key hash must be a compressed point of [`FFI_POINT_SIZE`] bytes,
+/// and the output buffer must hold at least as many bytes.
+///
+/// # Safety
+///
+/// `handle` must be a valid shareholder handle, `hash` must be valid
+/// for reads of `hash_len` bytes, and `out` must be valid for writes
+/// of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn churp_p384_key_share(
+    handle: *const ShareholderHandle,
+    hash: *const u8,
+    hash_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> u32 {
+    into_code((|| {
+        if handle.is_null() || hash.is_null() {
+            return Err(Error::NullPointer.into());
+        }
+
+        let shareholder = &*handle;
+        let hash = slice::from_raw_parts(hash, hash_len);
+        let mut repr = <FfiGroup as GroupEncoding>::Repr::default();
+        let slice: &mut [u8] = repr.as_mut();
+        if slice.len() != hash.len() {
+            return Err(Error::InvalidEncoding.into());
+        }
+        slice.copy_from_slice(hash);
+        let hash =
+            Option::<FfiGroup>::from(FfiGroup::from_bytes(&repr)).ok_or(Error::InvalidEncoding)?;
+
+        let key_share = shareholder.key_share(hash)?;
+        write_output(key_share.to_bytes().as_ref(), out, out_len)
+    })())
+}
+
+/// Copies the given bytes into the output buffer of the given length.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `out_len` bytes.
+unsafe fn write_output(bytes: &[u8], out: *mut u8, out_len: usize) -> Result<()> {
+    if out.is_null() {
+        return Err(Error::NullPointer.into());
+    }
+    if out_len < bytes.len() {
+        return Err(Error::BufferTooSmall.into());
+    }
+
+    ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len());
+
+    Ok(())
+}
+
+/// Converts the result of an operation into the code returned across
+/// the boundary.
+fn into_code(res: Result<()>) -> u32 {
+    match res {
+        Ok(()) => FFI_OK,
+        Err(err) => err
+            .downcast_ref::<Error>()
+            .map(Error::code)
+            .unwrap_or(FFI_UNKNOWN_ERROR),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use group::{Group as _, GroupEncoding};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, encode_shareholder, Error, HandoffKind},
+        poly::scalar_to_bytes,
+    };
+
+    use super::{
+        churp_p384_key_share, churp_p384_shareholder_free, churp_p384_shareholder_from_bytes,
+        churp_p384_switch_point, FfiGroup, FfiSuite, ShareholderHandle, FFI_ID_SIZE, FFI_OK,
+        FFI_POINT_SIZE, FFI_SWITCH_POINT_SIZE,
+    };
+
+    type Dealer = churp::Dealer<FfiGroup>;
+    type Shareholder = churp::Shareholder<FfiGroup>;
+
+    const DST: &[u8] = b"shareholder";
+
+    fn prepare_shareholder(rng: &mut StdRng) -> Shareholder {
+        let dealer = Dealer::new(2, rng).unwrap();
+        let x = encode_shareholder::<FfiSuite>(&[1; FFI_ID_SIZE], DST).unwrap();
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+        Shareholder::new(share, vm)
+    }
+
+    #[test]
+    fn test_ffi() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let shareholder = prepare_shareholder(&mut rng);
+        let bytes = shareholder.to_bytes().unwrap();
+
+        // Creation.
+        let mut handle: *mut ShareholderHandle = ptr::null_mut();
+        let code =
+            unsafe { churp_p384_shareholder_from_bytes(bytes.as_ptr(), bytes.len(), &mut handle) };
+        assert_eq!(code, FFI_OK);
+        assert!(!handle.is_null());
+
+        // Switch point.
+        let id = [2u8; FFI_ID_SIZE];
+        let mut out = [0u8; FFI_SWITCH_POINT_SIZE];
+        let code = unsafe {
+            churp_p384_switch_point(
+                handle,
+                id.as_ptr(),
+                DST.as_ptr(),
+                DST.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        assert_eq!(code, FFI_OK);
+        let x = encode_shareholder::<FfiSuite>(&id, DST).unwrap();
+        assert_eq!(out.to_vec(), scalar_to_bytes(&shareholder.switch_point(&x)));
+
+        // Key share.
+        let hash = FfiGroup::random(&mut rng).to_bytes();
+        let mut out = [0u8; FFI_POINT_SIZE];
+        let code = unsafe {
+            churp_p384_key_share(
+                handle,
+                hash.as_ptr(),
+                hash.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        assert_eq!(code, FFI_OK);
+        let hash = FfiGroup::from_bytes(&hash).unwrap();
+        let key_share = shareholder.key_share(hash).unwrap();
+        assert_eq!(out.as_slice(), &key_share.to_bytes()[..]);
+
+        unsafe { churp_p384_shareholder_free(handle) };
+    }
+
+    #[test]
+    fn test_ffi_errors() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let shareholder = prepare_shareholder(&mut rng);
+        let bytes = shareholder.to_bytes().unwrap();
+        let id = [2u8; FFI_ID_SIZE];
+
+        // Invalid or missing representation.
+        let mut handle: *mut ShareholderHandle = ptr::null_mut();
+        let code = unsafe {
+            churp_p384_shareholder_from_bytes(bytes.as_ptr(), bytes.len() - 1, &mut handle)
+        };
+        assert_eq!(code, Error::InvalidEncoding.code());
+        assert!(handle.is_null());
+
+        let code = unsafe { churp_p384_shareholder_from_bytes(ptr::null(), 0, &mut handle) };
+        assert_eq!(code, Error::NullPointer.code());
+
+        let code =
+            unsafe { churp_p384_shareholder_from_bytes(bytes.as_ptr(), bytes.len(), &mut handle) };
+        assert_eq!(code, FFI_OK);
+
+        // Output buffer too small.
+        let mut out = [0u8; FFI_SWITCH_POINT_SIZE - 1];
+        let code = unsafe {
+            churp_p384_switch_point(
+                handle,
+                id.as_ptr(),
+                DST.as_ptr(),
+                DST.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        assert_eq!(code, Error::BufferTooSmall.code());
+        assert_eq!(out, [0u8; FFI_SWITCH_POINT_SIZE - 1]);
+
+        // Missing output buffer or handle.
+        let code = unsafe {
+            churp_p384_switch_point(
+                handle,
+                id.as_ptr(),
+                DST.as_ptr(),
+                DST.len(),
+                ptr::null_mut(),
+                0,
+            )
+        };
+        assert_eq!(code, Error::NullPointer.code());
+
+        let mut out = [0u8; FFI_POINT_SIZE];
+        let hash = FfiGroup::random(&mut rng).to_bytes();
+        let code = unsafe {
+            churp_p384_key_share(
+                ptr::null(),
+                hash.as_ptr(),
+                hash.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        assert_eq!(code, Error::NullPointer.code());
+
+        // Invalid key hash.
+        for invalid in [&hash[..hash.len() - 1], &[0xff; FFI_POINT_SIZE]] {
+            let code = unsafe {
+                churp_p384_key_share(
+                    handle,
+                    invalid.as_ptr(),
+                    invalid.len(),
+                    out.as_mut_ptr(),
+                    out.len(),
+                )
+            };
+            assert_eq!(code, Error::InvalidEncoding.code());
+        }
+
+        unsafe {
+            churp_p384_shareholder_free(handle);
+            churp_p384_shareholder_free(ptr::null_mut());
+        }
+    }
+}
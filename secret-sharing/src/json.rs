@@ -0,0 +1,331 @@
+//! Canonical JSON encoding of secret shares and verification matrices.
+//!
+//! The encoding is meant for interoperability with implementations
+//! in other languages. Scalars and group elements are encoded as padded
+//! standard base64 of their canonical byte representations, and every
+//! object declares its size explicitly:
+//!
+//! ```text
+//! SecretShare:        {"coefficients": [<scalar>, ...], "degree": <u64>, "x": <scalar>}
+//! VerificationMatrix: {"dimensions": [<rows>, <cols>], "elements": [[<point>, ...], ...]}
+//! ```
+//!
+//! Keys are sorted and no whitespace is emitted, so encodings are unique.
+//! Decoding fails if any scalar, group element or base64 string is not
+//! canonically encoded, or if the declared sizes don't match the content.
+
+use alloc::{string::String, vec::Vec};
+
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use group::{ff::PrimeField, Group, GroupEncoding};
+use serde_json::{json, Map, Value};
+use zeroize::Zeroize;
+
+use crate::{
+    churp::{self, SecretShare},
+    poly::{scalar_from_bytes, scalar_to_bytes, Polynomial},
+    vss::{self, VerificationMatrix},
+};
+
+impl<F> SecretShare<F>
+where
+    F: PrimeField,
+{
+    /// Returns the canonical JSON encoding of the share.
+    pub fn to_json(&self) -> String {
+        let coefficients: Vec<_> = self.p.a.iter().map(encode_scalar).collect();
+        let value = json!({
+            "coefficients": coefficients,
+            "degree": self.p.size() - 1,
+            "x": encode_scalar(&self.x),
+        });
+
+        value.to_string()
+    }
+
+    /// Attempts to create a share from its canonical JSON encoding.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json).map_err(|_| churp::Error::InvalidEncoding)?;
+        Self::from_json_value(&value).ok_or_else(|| churp::Error::InvalidEncoding.into())
+    }
+
+    fn from_json_value(value: &Value) -> Option<Self> {
+        let object = exact_object(value, &["coefficients", "degree", "x"])?;
+        let degree = object["degree"].as_u64()?;
+        let coefficients = object["coefficients"].as_array()?;
+        if coefficients.is_empty() || coefficients.len() as u64 - 1 != degree {
+            return None;
+        }
+
+        let x = decode_scalar(&object["x"])?;
+        let mut a = Vec::with_capacity(coefficients.len());
+        for ai in coefficients {
+            match decode_scalar(ai) {
+                Some(ai) => a.push(ai),
+                None => {
+                    Polynomial::with_coefficients(a).zeroize();
+                    return None;
+                }
+            }
+        }
+
+        Some(SecretShare::new(x, Polynomial::with_coefficients(a)))
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the canonical JSON encoding of the verification matrix.
+    pub fn to_json(&self) -> String {
+        let elements: Vec<Vec<_>> = self
+            .m
+            .iter()
+            .map(|mi| {
+                mi.iter()
+                    .map(|mij| STANDARD.encode(mij.to_bytes()))
+                    .collect()
+            })
+            .collect();
+        let value = json!({
+            "dimensions": [self.rows, self.cols],
+            "elements": elements,
+        });
+
+        value.to_string()
+    }
+
+    /// Attempts to create a verification matrix from its canonical JSON
+    /// encoding.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json).map_err(|_| vss::Error::InvalidEncoding)?;
+        let (rows, cols, bytes) =
+            Self::element_bytes_from_json(&value).ok_or(vss::Error::InvalidEncoding)?;
+
+        Self::from_element_bytes(rows, cols, &bytes)
+    }
+
+    /// Returns the declared dimensions and the concatenated byte
+    /// representations of the elements in row-major order.
+    fn element_bytes_from_json(value: &Value) -> Option<(usize, usize, Vec<u8>)> {
+        let object = exact_object(value, &["dimensions", "elements"])?;
+        let (rows, cols) = match object["dimensions"].as_array()?.as_slice() {
+            [rows, cols] => (rows.as_u64()? as usize, cols.as_u64()? as usize),
+            _ => return None,
+        };
+        let elements = object["elements"].as_array()?;
+        if rows == 0 || cols == 0 || elements.len() != rows {
+            return None;
+        }
+
+        let element_size = Self::element_byte_size();
+        let mut bytes = Vec::with_capacity(rows.checked_mul(cols)?.checked_mul(element_size)?);
+        for mi in elements {
+            let mi = mi.as_array()?;
+            if mi.len() != cols {
+                return None;
+            }
+            for mij in mi {
+                let mij = decode_base64(mij)?;
+                if mij.len() != element_size {
+                    return None;
+                }
+                bytes.extend_from_slice(&mij);
+            }
+        }
+
+        Some((rows, cols, bytes))
+    }
+}
+
+/// Returns the base64 encoding of the canonical byte representation
+/// of the given scalar.
+fn encode_scalar<F: PrimeField>(s: &F) -> String {
+    let mut bytes = scalar_to_bytes(s);
+    let encoded = STANDARD.encode(&bytes);
+    bytes.zeroize();
+
+    encoded
+}
+
+/// Decodes a scalar from the base64 encoding of its canonical byte
+/// representation.
+fn decode_scalar<F: PrimeField>(value: &Value) -> Option<F> {
+    let mut bytes = decode_base64(value)?;
+    let s = scalar_from_bytes(&bytes);
+    bytes.zeroize();
+
+    s
+}
+
+/// Decodes a canonical padded standard base64 string.
+fn decode_base64(value: &Value) -> Option<Vec<u8>> {
+    STANDARD.decode(value.as_str()?).ok()
+}
+
+/// Returns the given value as an object, if it has exactly the given keys.
+fn exact_object<'a>(value: &'a Value, keys: &[&str]) -> Option<&'a Map<String, Value>> {
+    let object = value.as_object()?;
+    if object.len() != keys.len() || !keys.iter().all(|key| object.contains_key(*key)) {
+        return None;
+    }
+
+    Some(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde_json::{json, Value};
+
+    use crate::{
+        churp::{self, HandoffKind},
+        suites::{self, p384},
+        vss,
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Dealer = churp::Dealer<Group>;
+    type SecretShare = churp::SecretShare<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    fn prepare_share_and_matrix() -> (SecretShare, VerificationMatrix) {
+        let dealer = Dealer::new_deterministic(1, &[1u8; 32]).unwrap();
+        let share = dealer.make_share(PrimeField::from_u64(1), HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+
+        (share, vm)
+    }
+
+    /// Replaces the value under the given key of the given JSON object.
+    fn replace(json: &str, key: &str, value: Value) -> String {
+        let mut object: Value = serde_json::from_str(json).unwrap();
+        object[key] = value;
+        object.to_string()
+    }
+
+    #[test]
+    fn test_json() {
+        let (share, vm) = prepare_share_and_matrix();
+
+        let json = share.to_json();
+        let restored = SecretShare::from_json(&json).unwrap();
+        assert!(restored == share);
+        assert_eq!(restored.to_json(), json);
+
+        let json = vm.to_json();
+        let restored = VerificationMatrix::from_json(&json).unwrap();
+        assert_eq!(restored, vm);
+        assert_eq!(restored.to_json(), json);
+
+        // The golden file pins the exact encodings. To regenerate it, run
+        // the test with the REGENERATE_TEST_VECTORS environment variable set.
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/json_encoding.jsonl");
+        let golden = format!("{}\n{}\n", share.to_json(), vm.to_json());
+        if env::var_os("REGENERATE_TEST_VECTORS").is_some() {
+            fs::write(path, &golden).unwrap();
+        }
+        let expected = fs::read_to_string(path).unwrap();
+        assert_eq!(golden, expected);
+    }
+
+    #[test]
+    fn test_json_invalid_share() {
+        let (share, _) = prepare_share_and_matrix();
+        let json = share.to_json();
+        let invalid_scalar = STANDARD.encode([0xff; 48]);
+        let x = STANDARD.encode([0; 48]);
+
+        let test_cases = vec![
+            // Malformed JSON.
+            json[1..].to_string(),
+            // Mismatched degree.
+            replace(&json, "degree", json!(1)),
+            replace(&json, "degree", json!(3)),
+            replace(&json, "degree", json!(-1)),
+            // Scalar larger than the modulus.
+            replace(&json, "x", json!(invalid_scalar)),
+            replace(&json, "coefficients", json!([x, x, invalid_scalar])),
+            // Scalar of the wrong size.
+            replace(&json, "x", json!(STANDARD.encode([0; 47]))),
+            // Base64 without padding.
+            replace(&json, "x", json!("AA")),
+            // Unknown key.
+            replace(&json, "y", json!(0)),
+        ];
+
+        for json in test_cases {
+            let res = SecretShare::from_json(&json);
+            assert!(res.is_err(), "{}", json);
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                churp::Error::InvalidEncoding.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_invalid_matrix() {
+        let (_, vm) = prepare_share_and_matrix();
+        let json = vm.to_json();
+        let object: Value = serde_json::from_str(&json).unwrap();
+        let elements = object["elements"].as_array().unwrap();
+
+        // Base64 strings with non-zero trailing bits.
+        let point = elements[0][1].as_str().unwrap();
+        assert!(point.ends_with("=="));
+        let mut chars: Vec<char> = point.chars().collect();
+        let i = chars.len() - 3;
+        chars[i] = match chars[i] {
+            'A' => 'B',
+            'Q' => 'R',
+            'g' => 'h',
+            'w' => 'x',
+            _ => unreachable!("trailing bits should be zero"),
+        };
+        let non_canonical: String = chars.into_iter().collect();
+        let mut tampered = object.clone();
+        tampered["elements"][0][1] = json!(non_canonical);
+
+        let mut short_row = object.clone();
+        short_row["elements"][1].as_array_mut().unwrap().pop();
+
+        let test_cases = vec![
+            // Malformed JSON.
+            json[1..].to_string(),
+            // Mismatched dimensions.
+            replace(&json, "dimensions", json!([3, 3])),
+            replace(&json, "dimensions", json!([2, 2])),
+            replace(&json, "dimensions", json!([0, 0])),
+            replace(&json, "dimensions", json!([2])),
+            short_row.to_string(),
+            // Non-canonical base64.
+            tampered.to_string(),
+        ];
+
+        for json in test_cases {
+            let res = VerificationMatrix::from_json(&json);
+            assert!(res.is_err(), "{}", json);
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                vss::Error::InvalidEncoding.to_string()
+            );
+        }
+
+        // Invalid point.
+        let mut invalid = object;
+        invalid["elements"][0][0] = json!(STANDARD.encode([0xff; 49]));
+        let res = VerificationMatrix::from_json(&invalid.to_string());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            vss::Error::InvalidPointEncoding.to_string()
+        );
+    }
+}
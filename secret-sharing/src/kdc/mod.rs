@@ -1,6 +1,7 @@
 //! Key derivation center.
 
-use std::iter::zip;
+use alloc::vec::Vec;
+use core::iter::zip;
 
 use anyhow::{bail, Result};
 use group::{ff::PrimeField, Group};
@@ -8,12 +8,33 @@
 //!
 //! - CHURP (CHUrn-Robust Proactive secret sharing)
 //! - Shamir (Shamir secret sharing)
+//!
+//! ## Constant Time
+//!
+//! Polynomial evaluation (`Polynomial::eval`), switch point computation
+//! (`Shareholder::switch_point`) and share verification are constant time
+//! for a given polynomial size, provided that the underlying prime field
+//! and group arithmetic is constant time. Other operations on secret data,
+//! including comparisons of polynomials using `PartialEq`, are not
+//! guaranteed to be constant time.
 
 #![feature(test)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("the `alloc` feature is required");
+
+extern crate alloc;
 
 pub mod churp;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "json")]
+mod json;
 pub mod kdc;
 pub mod poly;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod shamir;
 pub mod suites;
 pub mod vss;
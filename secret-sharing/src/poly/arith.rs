@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use group::ff::PrimeField;
 
 /// Returns a vector containing powers of x: x^0, x^1, ..., x^k.
@@ -12,9 +14,23 @@ pub fn powers<F: PrimeField>(x: &F, k: usize) -> Vec<F> {
     pows
 }
 
+/// Returns true iff the given points have distinct x-coordinates.
+pub(crate) fn distinct_abscissae<F: PrimeField, T>(points: &[(F, T)]) -> bool {
+    // For a small number of points, a brute-force approach should
+    // suffice, and it doesn't require the prime field to be hashable.
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            if points[i].0 == points[j].0 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
-    use super::powers;
+    use super::{distinct_abscissae, powers};
 
     type PrimeField = p384::Scalar;
 
@@ -31,4 +47,15 @@ mod tests {
         let xpows = powers(&x2, 4);
         assert_eq!(xpows, vec![PrimeField::ONE, x2, x4, x8, x16]);
     }
+
+    #[test]
+    fn test_distinct_abscissae() {
+        let x1 = PrimeField::from_u64(1);
+        let x2 = PrimeField::from_u64(2);
+
+        assert!(distinct_abscissae::<PrimeField, PrimeField>(&[]));
+        assert!(distinct_abscissae(&[(x1, x1)]));
+        assert!(distinct_abscissae(&[(x1, x1), (x2, x1)]));
+        assert!(!distinct_abscissae(&[(x1, x1), (x2, x1), (x1, x2)]));
+    }
 }
@@ -1,3 +1,5 @@
+use alloc::{vec, vec::Vec};
+
 use group::ff::PrimeField;
 use rand_core::RngCore;
 use subtle::{Choice, CtOption};
@@ -104,6 +106,12 @@ where
         self.b[0][0].is_zero().into()
     }
 
+    /// Returns the degrees of the bivariate polynomial in the x and y
+    /// variables.
+    pub fn degree(&self) -> (usize, usize) {
+        (self.deg_x, self.deg_y)
+    }
+
     /// Returns the coefficient `b_{i,j}` of the bivariate polynomial.
     pub fn coefficient(&self, i: usize, j: usize) -> Option<&F> {
         self.b.get(i).and_then(|bi| bi.get(j))
@@ -248,6 +256,7 @@ where
 mod tests {
     use std::panic;
 
+    use group::ff::Field;
     use rand::{rngs::StdRng, SeedableRng};
 
     type PrimeField = p384::Scalar;
@@ -369,6 +378,12 @@ mod tests {
         assert!(bp.is_zero_hole());
     }
 
+    #[test]
+    fn test_degree() {
+        let bp = BivariatePolynomial::zero(2, 3);
+        assert_eq!(bp.degree(), (2, 3));
+    }
+
     #[test]
     fn test_coefficient() {
         let b = vec![scalars(&[1, 2, 3]), scalars(&[2, 3, 1])];
@@ -448,4 +463,16 @@ mod tests {
         let expected = Polynomial::with_coefficients(scalars(&[586, 242, 298]));
         assert!(result == expected);
     }
+
+    #[test]
+    fn test_partial_eval() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(3, 5, &mut rng);
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+        let expected = bp.eval(&x, &y);
+
+        assert_eq!(bp.eval_x(&x).eval(&y), expected);
+        assert_eq!(bp.eval_y(&y).eval(&x), expected);
+    }
 }
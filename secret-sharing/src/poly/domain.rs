@@ -0,0 +1,269 @@
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use group::ff::PrimeField;
+
+use super::{Error, Polynomial};
+
+/// Multiplicative subgroup of a prime field of power-of-two order,
+/// generated by a primitive root of unity.
+///
+/// The domain `{1, w, w^2, ..., w^{n-1}}` supports the evaluation
+/// and interpolation of polynomials of degree less than `n` using the
+/// number-theoretic transform in `O(n log n)` field operations.
+///
+/// The size of the domain is bounded by the two-adicity `S` of the field,
+/// i.e. the largest `S` such that `2^S` divides `p - 1`, which is small
+/// for the fields of most elliptic curves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Radix2Domain<F> {
+    /// The number of elements in the domain.
+    size: usize,
+    /// The primitive `size`-th root of unity generating the domain.
+    omega: F,
+    /// The inverse of the generator.
+    omega_inv: F,
+    /// The inverse of the size of the domain.
+    size_inv: F,
+}
+
+impl<F> Radix2Domain<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new domain of the given size.
+    ///
+    /// Fails if the size is not a power of two or exceeds `2^S`.
+    pub fn new(size: usize) -> Result<Self> {
+        if !size.is_power_of_two() || size.trailing_zeros() > F::S {
+            return Err(Error::UnsupportedDomainSize.into());
+        }
+
+        // Square the primitive 2^S-th root of unity down to a primitive
+        // root of unity of the requested order.
+        let mut omega = F::ROOT_OF_UNITY;
+        let mut omega_inv = F::ROOT_OF_UNITY_INV;
+        for _ in size.trailing_zeros()..F::S {
+            omega = omega.square();
+            omega_inv = omega_inv.square();
+        }
+
+        let size_inv = F::from_u128(size as u128)
+            .invert()
+            .expect("size should be non-zero");
+
+        Ok(Self {
+            size,
+            omega,
+            omega_inv,
+            size_inv,
+        })
+    }
+
+    /// Returns the number of elements in the domain.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the generator of the domain.
+    pub fn generator(&self) -> &F {
+        &self.omega
+    }
+
+    /// Returns the elements of the domain in order, i.e. `w^i`
+    /// for `i = 0, ..., n-1`.
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut wi = F::ONE;
+        for _ in 0..self.size {
+            elements.push(wi);
+            wi *= self.omega;
+        }
+        elements
+    }
+
+    /// Computes the number-theoretic transform of the given values in place,
+    /// i.e. evaluates the polynomial with the given coefficients at all
+    /// elements of the domain.
+    fn ntt(&self, values: &mut [F]) {
+        transform(values, &self.omega);
+    }
+
+    /// Computes the inverse number-theoretic transform of the given values
+    /// in place, i.e. interpolates the polynomial from its evaluations
+    /// at all elements of the domain.
+    fn intt(&self, values: &mut [F]) {
+        transform(values, &self.omega_inv);
+        for v in values.iter_mut() {
+            *v *= self.size_inv;
+        }
+    }
+}
+
+impl<F> Polynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a polynomial from its evaluations at the elements
+    /// of the given domain, using the inverse number-theoretic transform.
+    ///
+    /// The resulting polynomial has as many coefficients as the domain
+    /// has elements. Fails if the number of evaluations doesn't match
+    /// the size of the domain.
+    pub fn from_evaluations(evals: &[F], domain: &Radix2Domain<F>) -> Result<Self> {
+        if evals.len() != domain.size() {
+            return Err(Error::DomainSizeMismatch.into());
+        }
+
+        let mut a = evals.to_vec();
+        domain.intt(&mut a);
+
+        Ok(Polynomial::with_coefficients(a))
+    }
+
+    /// Evaluates the polynomial at the elements of the given domain,
+    /// using the number-theoretic transform.
+    ///
+    /// Fails if the polynomial has more coefficients than the domain
+    /// has elements.
+    pub fn to_evaluations(&self, domain: &Radix2Domain<F>) -> Result<Vec<F>> {
+        if self.size() > domain.size() {
+            return Err(Error::DomainSizeMismatch.into());
+        }
+
+        let mut evals = self.coefficients().to_vec();
+        evals.resize(domain.size(), F::ZERO);
+        domain.ntt(&mut evals);
+
+        Ok(evals)
+    }
+}
+
+/// Computes the discrete Fourier transform of the given values in place,
+/// with respect to the given primitive root of unity of order equal
+/// to the number of values, using the iterative Cooley-Tukey algorithm.
+fn transform<F: PrimeField>(values: &mut [F], omega: &F) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+
+    // Reorder values by bit-reversed indices.
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // Combine butterflies of doubling sizes.
+    let mut m = 1;
+    while m < n {
+        // Primitive (2m)-th root of unity.
+        let wm = omega.pow_vartime([(n / (2 * m)) as u64]);
+
+        for k in (0..n).step_by(2 * m) {
+            let mut w = F::ONE;
+            for j in 0..m {
+                let t = w * values[k + j + m];
+                let u = values[k + j];
+                values[k + j] = u + t;
+                values[k + j + m] = u - t;
+                w *= wm;
+            }
+        }
+
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::PrimeField;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Error, Polynomial, Radix2Domain};
+
+    fn test_round_trip<F: PrimeField>() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for log_size in 0..=F::S {
+            let size = 1 << log_size;
+            let domain = Radix2Domain::<F>::new(size).unwrap();
+            let elements = domain.elements();
+            assert_eq!(domain.size(), size);
+            assert_eq!(elements.len(), size);
+            assert_eq!(domain.generator().pow_vartime([size as u64]), F::ONE);
+
+            // Coefficients to evaluations and back.
+            let p = Polynomial::<F>::random((size - 1) as u8, &mut rng);
+            let evals = p.to_evaluations(&domain).unwrap();
+            for (x, y) in elements.iter().zip(&evals) {
+                assert_eq!(p.eval(x), *y);
+            }
+            let q = Polynomial::from_evaluations(&evals, &domain).unwrap();
+            assert!(p == q);
+
+            // Evaluations to coefficients, matching Lagrange interpolation.
+            let evals: Vec<_> = (0..size).map(|_| F::random(&mut rng)).collect();
+            let p = Polynomial::from_evaluations(&evals, &domain).unwrap();
+            let points: Vec<_> = elements.iter().cloned().zip(evals.clone()).collect();
+            let q = Polynomial::interpolate(&points).unwrap();
+            assert_eq!(p.coefficients(), q.coefficients());
+            assert_eq!(p.to_evaluations(&domain).unwrap(), evals);
+
+            // Size mismatch.
+            let res = Polynomial::from_evaluations(&evals[1..], &domain);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DomainSizeMismatch.to_string()
+            );
+            let p = Polynomial::<F>::random(size as u8, &mut rng);
+            let res = p.to_evaluations(&domain);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DomainSizeMismatch.to_string()
+            );
+        }
+
+        // Unsupported sizes.
+        for size in [0, 3, 6, 1 << (F::S + 1)] {
+            let res = Radix2Domain::<F>::new(size);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::UnsupportedDomainSize.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_p384() {
+        test_round_trip::<p384::Scalar>();
+    }
+
+    #[test]
+    fn test_round_trip_ristretto255() {
+        test_round_trip::<curve25519_dalek::Scalar>();
+    }
+
+    #[test]
+    fn test_lower_degree_polynomial() {
+        type PrimeField = curve25519_dalek::Scalar;
+
+        // Polynomials with fewer coefficients are padded with zeros.
+        let domain = Radix2Domain::<PrimeField>::new(4).unwrap();
+        let p = Polynomial::with_coefficients(vec![PrimeField::from(3u64), PrimeField::ONE]);
+        let evals = p.to_evaluations(&domain).unwrap();
+        for (x, y) in domain.elements().iter().zip(&evals) {
+            assert_eq!(p.eval(x), *y);
+        }
+
+        let q = Polynomial::from_evaluations(&evals, &domain).unwrap();
+        assert_eq!(&q.coefficients()[..2], p.coefficients());
+        assert!(q.coefficients()[2..].iter().all(|a| *a == PrimeField::ZERO));
+    }
+}
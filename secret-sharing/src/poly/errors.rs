@@ -0,0 +1,17 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("division by zero polynomial")]
+    DivisionByZeroPolynomial,
+    #[error("domain size mismatch")]
+    DomainSizeMismatch,
+    #[error("duplicate abscissa")]
+    DuplicateAbscissa,
+    #[error("inversion of zero")]
+    InversionOfZero,
+    #[error("random number generator failure")]
+    RngFailure,
+    #[error("scalar sampling failed")]
+    ScalarSamplingFailed,
+    #[error("unsupported domain size")]
+    UnsupportedDomainSize,
+}
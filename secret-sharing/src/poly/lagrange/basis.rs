@@ -0,0 +1,277 @@
+use alloc::{vec, vec::Vec};
+use core::iter::zip;
+
+use anyhow::Result;
+use group::ff::PrimeField;
+
+use crate::poly::{batch_invert, Error, Polynomial};
+
+use super::multiplier::Multiplier;
+
+/// Lagrange basis for a fixed set of x values.
+///
+/// The basis stores the barycentric weights
+/// ```text
+///     w_i = 1 / \prod_{j=0,j≠i}^n (x_i - x_j)
+/// ```
+/// together with the Lagrange coefficients `L_i(0)`, so that values
+/// interpolated at the same x values, e.g. switch points received from
+/// a fixed committee, can be reconstructed repeatedly at the cost of one
+/// inner product.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LagrangeBasis<F> {
+    /// The x values.
+    xs: Vec<F>,
+    /// The barycentric weights `w_i`.
+    weights: Vec<F>,
+    /// The Lagrange coefficients `L_i(0)`.
+    coefficients: Vec<F>,
+}
+
+impl<F> LagrangeBasis<F>
+where
+    F: PrimeField,
+{
+    /// Creates the Lagrange basis for the given x values.
+    ///
+    /// Fails if the x values are not distinct.
+    pub fn new(xs: &[F]) -> Result<Self> {
+        let mut weights = Vec::with_capacity(xs.len());
+        for (i, xi) in xs.iter().enumerate() {
+            let mut denom = F::ONE;
+            for (j, xj) in xs.iter().enumerate() {
+                if j != i {
+                    denom *= *xi - xj; // (x_i - x_j)
+                }
+            }
+            weights.push(denom);
+        }
+        // The denominator is zero iff some x values coincide.
+        batch_invert(&mut weights).map_err(|_| Error::DuplicateAbscissa)?;
+
+        let mut basis = Self {
+            xs: xs.to_vec(),
+            weights,
+            coefficients: Vec::new(),
+        };
+        basis.coefficients = basis.coefficients_at(&F::ZERO);
+
+        Ok(basis)
+    }
+
+    /// Returns the x values.
+    pub fn xs(&self) -> &[F] {
+        &self.xs
+    }
+
+    /// Returns the barycentric weights `w_i`.
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Returns the Lagrange coefficients `L_i(0)`.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Returns the number of x values.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Returns true if the basis has no x values.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Returns the values `L_i(x)` of the Lagrange basis polynomials
+    /// at the given point.
+    ///
+    /// The i-th value is computed as:
+    /// ```text
+    ///     L_i(x) = w_i * \prod_{j=0,j≠i}^n (x - x_j)
+    /// ```
+    pub fn coefficients_at(&self, x: &F) -> Vec<F> {
+        let diffs: Vec<_> = self.xs.iter().map(|xj| *x - xj).collect(); // (x - x_j)
+        let m = Multiplier::new(&diffs);
+
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(i, wi)| m.get_product(i).unwrap_or(F::ONE) * wi)
+            .collect()
+    }
+
+    /// Evaluates the polynomial interpolating the points `(x_i, y_i)`
+    /// at the given point.
+    ///
+    /// Panics if the number of y values differs from the number of x values.
+    pub fn eval(&self, ys: &[F], x: &F) -> F {
+        assert_eq!(ys.len(), self.xs.len(), "y values should match x values");
+
+        zip(self.coefficients_at(x), ys).map(|(c, y)| c * y).sum()
+    }
+
+    /// Returns the polynomial interpolating the points `(x_i, y_i)`.
+    ///
+    /// The polynomial is computed as:
+    /// ```text
+    ///     L(x) = \sum_{i=0}^n y_i * w_i * \prod_{j=0,j≠i}^n (x - x_j)
+    /// ```
+    ///
+    /// Panics if the number of y values differs from the number of x values.
+    pub fn interpolate(&self, ys: &[F]) -> Polynomial<F> {
+        assert_eq!(ys.len(), self.xs.len(), "y values should match x values");
+
+        let diffs: Vec<_> = self
+            .xs
+            .iter()
+            .map(|xj| Polynomial::with_coefficients(vec![xj.neg(), F::ONE])) // (x - x_j)
+            .collect();
+        let m = Multiplier::new(&diffs);
+
+        let mut l = Polynomial::zero(0);
+        for (i, (wi, yi)) in zip(&self.weights, ys).enumerate() {
+            let li = m
+                .get_product(i)
+                .unwrap_or(Polynomial::with_coefficients(vec![F::ONE]));
+            l.add_scaled(&li, &(*wi * yi));
+        }
+
+        l
+    }
+}
+
+/// Reconstructs the value at zero of the polynomial interpolating
+/// the points `(x_i, y_i)`, where `x_i` are the x values of the given
+/// basis.
+///
+/// Panics if the number of y values differs from the number of x values.
+pub fn recover_with_basis<F: PrimeField>(basis: &LagrangeBasis<F>, ys: &[F]) -> F {
+    assert_eq!(ys.len(), basis.len(), "y values should match x values");
+
+    zip(basis.coefficients(), ys).map(|(c, y)| *c * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use std::iter::zip;
+
+    use group::ff::Field;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::poly::{lagrange, Error, Polynomial};
+
+    use super::{recover_with_basis, LagrangeBasis};
+
+    type PrimeField = p384::Scalar;
+
+    fn scalars(values: &[u64]) -> Vec<PrimeField> {
+        values.iter().map(|&w| PrimeField::from_u64(w)).collect()
+    }
+
+    fn random_scalars(n: usize, rng: &mut impl RngCore) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut *rng)).collect()
+    }
+
+    #[test]
+    fn test_basis() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [1, 2, 5, 20] {
+            let xs = random_scalars(n, &mut rng);
+            let basis = LagrangeBasis::new(&xs).unwrap();
+            assert_eq!(basis.xs(), &xs);
+            assert_eq!(basis.len(), n);
+
+            // Equivalence with the direct method.
+            assert_eq!(basis.coefficients(), lagrange::coefficients(&xs));
+            assert_eq!(basis.coefficients(), lagrange::coefficients_naive(&xs));
+
+            let p = Polynomial::random((n - 1) as u8, &mut rng);
+            let ys = p.eval_many(&xs);
+            assert_eq!(recover_with_basis(&basis, &ys), p.eval(&PrimeField::ZERO));
+
+            // Evaluation at arbitrary points.
+            for x in random_scalars(3, &mut rng).iter().chain(&xs) {
+                assert_eq!(basis.eval(&ys, x), p.eval(x));
+            }
+
+            // Interpolation.
+            assert!(basis.interpolate(&ys) == p);
+            assert!(basis.interpolate(&ys) == lagrange::lagrange(&xs, &ys));
+        }
+
+        // Zero among the x values.
+        let xs = scalars(&[0, 1, 2]);
+        let basis = LagrangeBasis::new(&xs).unwrap();
+        assert_eq!(basis.coefficients(), scalars(&[1, 0, 0]));
+    }
+
+    #[test]
+    fn test_weights() {
+        let xs = scalars(&[1, 2, 3]);
+        let basis = LagrangeBasis::new(&xs).unwrap();
+
+        // w_i = 1 / \prod_{j≠i} (x_i - x_j)
+        let expected: Vec<_> = [2, -1, 2]
+            .iter()
+            .map(|&d: &i64| {
+                let d = match d.is_negative() {
+                    false => PrimeField::from_u64(d as u64),
+                    true => -PrimeField::from_u64(-d as u64),
+                };
+                d.invert().unwrap()
+            })
+            .collect();
+        assert_eq!(basis.weights(), expected);
+    }
+
+    #[test]
+    fn test_duplicate_abscissae() {
+        for xs in [scalars(&[1, 1]), scalars(&[1, 2, 3, 2])] {
+            let res = LagrangeBasis::new(&xs);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DuplicateAbscissa.to_string()
+            );
+        }
+    }
+
+    fn prepare_bench_recover(n: usize, m: usize) -> (Vec<PrimeField>, Vec<Vec<PrimeField>>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs = random_scalars(n, &mut rng);
+        let yss = (0..m).map(|_| random_scalars(n, &mut rng)).collect();
+
+        (xs, yss)
+    }
+
+    #[bench]
+    fn bench_recover_with_basis_64_100(b: &mut Bencher) {
+        let (xs, yss) = prepare_bench_recover(64, 100);
+
+        b.iter(|| {
+            let basis = LagrangeBasis::new(&xs).unwrap();
+            for ys in &yss {
+                let _ = recover_with_basis(&basis, ys);
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_recover_with_coefficients_64_100(b: &mut Bencher) {
+        let (xs, yss) = prepare_bench_recover(64, 100);
+
+        b.iter(|| {
+            for ys in &yss {
+                let cs = lagrange::coefficients(&xs);
+                let _: PrimeField = zip(cs, ys).map(|(c, y)| c * y).sum();
+            }
+        });
+    }
+}
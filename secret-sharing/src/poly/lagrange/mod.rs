@@ -1,8 +1,9 @@
 //! Lagrange interpolation.
 
+mod basis;
 mod multiplier;
 mod naive;
 mod optimized;
 
 // Re-exports.
-pub use self::{naive::*, optimized::*};
+pub use self::{basis::*, naive::*, optimized::*};
@@ -1,4 +1,5 @@
-use std::ops::Mul;
+use alloc::boxed::Box;
+use core::ops::Mul;
 
 /// Multiplier efficiently computes the product of all values except one.
 ///
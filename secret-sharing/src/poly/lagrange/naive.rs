@@ -1,5 +1,6 @@
 // Lagrange Polynomials interpolation / reconstruction
-use std::iter::zip;
+use alloc::{vec, vec::Vec};
+use core::iter::zip;
 
 use group::ff::PrimeField;
 
@@ -1,8 +1,9 @@
-use std::iter::zip;
+use alloc::{vec, vec::Vec};
+use core::iter::zip;
 
 use group::ff::PrimeField;
 
-use crate::poly::Polynomial;
+use crate::poly::{batch_invert, Polynomial};
 
 use super::multiplier::Multiplier;
 
@@ -15,7 +16,12 @@ use super::multiplier::Multiplier;
 /// where `L_i(x)` represents the i-th Lagrange basis polynomial.
 pub fn lagrange<F: PrimeField>(xs: &[F], ys: &[F]) -> Polynomial<F> {
     let ls = basis_polynomials(xs);
-    zip(ls, ys).map(|(li, &yi)| li * yi).sum()
+    let mut l = Polynomial::zero(0);
+    for (li, yi) in zip(&ls, ys) {
+        l.add_scaled(li, yi);
+    }
+
+    l
 }
 
 /// Returns Lagrange basis polynomials for the given set of x values.
@@ -27,10 +33,14 @@ pub fn lagrange<F: PrimeField>(xs: &[F], ys: &[F]) -> Polynomial<F> {
 /// i.e. it holds `L_i(x_i)` = 1 and `L_i(x_j) = 0` for all `j ≠ i`.
 fn basis_polynomials<F: PrimeField>(xs: &[F]) -> Vec<Polynomial<F>> {
     let m = multiplier_for_basis_polynomials(xs);
-    (0..xs.len()).map(|i| basis_polynomial(xs, i, &m)).collect()
+    let denoms_inv = inverted_denominators(xs);
+    (0..xs.len())
+        .map(|i| basis_polynomial(i, &m, &denoms_inv[i]))
+        .collect()
 }
 
-/// Returns i-th Lagrange basis polynomial for the given set of x values.
+/// Returns i-th Lagrange basis polynomial for the given set of x values,
+/// given the inverse of its denominator.
 ///
 /// The i-th Lagrange basis polynomial is defined as:
 /// ```text
@@ -38,22 +48,14 @@ fn basis_polynomials<F: PrimeField>(xs: &[F]) -> Vec<Polynomial<F>> {
 /// ```
 /// i.e. it holds `L_i(x_i)` = 1 and `L_i(x_j) = 0` for all `j ≠ i`.
 fn basis_polynomial<F: PrimeField>(
-    xs: &[F],
     i: usize,
     multiplier: &Multiplier<Polynomial<F>>,
+    denom_inv: &F,
 ) -> Polynomial<F> {
     let mut nom = multiplier
         .get_product(i)
         .unwrap_or(Polynomial::with_coefficients(vec![F::ONE]));
-    let mut denom = F::ONE;
-    for j in 0..xs.len() {
-        if j == i {
-            continue;
-        }
-        denom *= xs[j] - xs[i]; // (x_j - x_i)
-    }
-    let denom_inv = denom.invert().expect("values should be unique");
-    nom *= denom_inv; // L_i(x) = nom / denom
+    nom *= *denom_inv; // L_i(x) = nom / denom
 
     nom
 }
@@ -66,17 +68,42 @@ fn basis_polynomial<F: PrimeField>(
 /// ```
 pub fn coefficients<F: PrimeField>(xs: &[F]) -> Vec<F> {
     let m = multiplier_for_coefficients(xs);
-    (0..xs.len()).map(|i| coefficient(xs, i, &m)).collect()
+    let denoms_inv = inverted_denominators(xs);
+    (0..xs.len())
+        .map(|i| coefficient(i, &m, &denoms_inv[i]))
+        .collect()
 }
 
-/// Returns i-th Lagrange coefficient for the given set of x values.
+/// Returns i-th Lagrange coefficient for the given set of x values,
+/// given the inverse of its denominator.
 ///
 /// The i-th Lagrange coefficient is defined as:
 /// ```text
 ///     L_i(0) = \prod_{j=0,j≠i}^n x_j / (x_j - x_i)
 /// ```
-fn coefficient<F: PrimeField>(xs: &[F], i: usize, multiplier: &Multiplier<F>) -> F {
-    let mut nom = multiplier.get_product(i).unwrap_or(F::ONE);
+fn coefficient<F: PrimeField>(i: usize, multiplier: &Multiplier<F>, denom_inv: &F) -> F {
+    let nom = multiplier.get_product(i).unwrap_or(F::ONE);
+    nom * denom_inv // L_i(0) = nom / denom
+}
+
+/// Returns the inverses of the denominators of all Lagrange coefficients
+/// and basis polynomials for the given set of x values, using a single
+/// field inversion.
+fn inverted_denominators<F: PrimeField>(xs: &[F]) -> Vec<F> {
+    let mut denoms: Vec<_> = (0..xs.len()).map(|i| denominator(xs, i)).collect();
+    batch_invert(&mut denoms).expect("values should be unique");
+
+    denoms
+}
+
+/// Returns the denominator of the i-th Lagrange coefficient and basis
+/// polynomial for the given set of x values.
+///
+/// The i-th denominator is defined as:
+/// ```text
+///     \prod_{j=0,j≠i}^n (x_j - x_i)
+/// ```
+fn denominator<F: PrimeField>(xs: &[F], i: usize) -> F {
     let mut denom = F::ONE;
     for j in 0..xs.len() {
         if j == i {
@@ -84,10 +111,8 @@ fn coefficient<F: PrimeField>(xs: &[F], i: usize, multiplier: &Multiplier<F>) ->
         }
         denom *= xs[j] - xs[i]; // (x_j - x_i)
     }
-    let denom_inv = denom.invert().expect("values should be unique");
-    nom *= denom_inv; // L_i(0) = nom / denom
 
-    nom
+    denom
 }
 
 /// Creates a multiplier for the nominators in the Lagrange coefficients.
@@ -116,7 +141,7 @@ mod tests {
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     use super::{
-        basis_polynomial, basis_polynomials, coefficient, coefficients, lagrange,
+        basis_polynomial, basis_polynomials, coefficient, coefficients, denominator, lagrange,
         multiplier_for_basis_polynomials, multiplier_for_coefficients,
     };
 
@@ -173,9 +198,12 @@ mod tests {
 
         for xs in vec {
             let m = multiplier_for_basis_polynomials(&xs);
+            let ps = basis_polynomials(&xs);
 
-            for i in 0..xs.len() {
-                let p = basis_polynomial(&xs, i, &m);
+            for (i, pi) in ps.iter().enumerate() {
+                let denom_inv = denominator(&xs, i).invert().unwrap();
+                let p = basis_polynomial(i, &m, &denom_inv);
+                assert!(p == *pi);
 
                 // Verify points.
                 for (j, x) in xs.iter().enumerate() {
@@ -203,11 +231,15 @@ mod tests {
         for xs in vec {
             let sm = multiplier_for_coefficients(&xs);
             let pm = multiplier_for_basis_polynomials(&xs);
-            for i in 0..xs.len() {
-                let c = coefficient(&xs, i, &sm);
-                let p = basis_polynomial(&xs, i, &pm);
+            let cs = coefficients(&xs);
+            for (i, ci) in cs.iter().enumerate() {
+                // Individual inverses match batched inverses.
+                let denom_inv = denominator(&xs, i).invert().unwrap();
+                let c = coefficient(i, &sm, &denom_inv);
+                let p = basis_polynomial(i, &pm, &denom_inv);
 
                 assert_eq!(c, p.eval(&scalar(0)));
+                assert_eq!(c, *ci);
             }
         }
     }
@@ -241,6 +273,26 @@ mod tests {
         });
     }
 
+    fn bench_reconstruction(b: &mut Bencher, threshold: usize, batched: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs = random_scalars(threshold + 1, &mut rng);
+        let ys = random_scalars(threshold + 1, &mut rng);
+
+        b.iter(|| {
+            let cs: Vec<_> = match batched {
+                true => coefficients(&xs),
+                false => {
+                    // One field inversion per coefficient.
+                    let m = multiplier_for_coefficients(&xs);
+                    (0..xs.len())
+                        .map(|i| coefficient(i, &m, &denominator(&xs, i).invert().unwrap()))
+                        .collect()
+                }
+            };
+            let _s: PrimeField = zip(cs, &ys).map(|(c, y)| c * y).sum();
+        });
+    }
+
     #[bench]
     fn bench_lagrange_01(b: &mut Bencher) {
         bench_lagrange(b, 1)
@@ -315,4 +367,14 @@ mod tests {
     fn bench_coefficients_20(b: &mut Bencher) {
         bench_coefficients(b, 20)
     }
+
+    #[bench]
+    fn bench_reconstruction_128_batch_inversion(b: &mut Bencher) {
+        bench_reconstruction(b, 128, true)
+    }
+
+    #[bench]
+    fn bench_reconstruction_128_individual_inversion(b: &mut Bencher) {
+        bench_reconstruction(b, 128, false)
+    }
 }
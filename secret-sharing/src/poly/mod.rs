@@ -3,15 +3,27 @@
 //! Features include:
 //!
 //! - Univariate and bivariate polynomials
+//! - Sparse univariate polynomials
 //! - Evaluation of points on polynomials
 //! - Lagrange interpolation methods
+//! - Number-theoretic transforms over power-of-two domains
 
 mod arith;
 mod bivariate;
+mod domain;
+mod errors;
 pub mod lagrange;
 mod point;
+mod rng;
 mod scalar;
+mod sparse;
 mod univariate;
 
 // Re-exports.
-pub use self::{arith::*, bivariate::*, point::*, scalar::*, univariate::*};
+pub use self::{
+    arith::*, bivariate::*, domain::*, errors::*, point::*, scalar::*, sparse::*, univariate::*,
+};
+pub(crate) use self::{
+    rng::{sample_nonzero_scalar, CheckedRng},
+    scalar::batch_invert,
+};
@@ -0,0 +1,282 @@
+use anyhow::Result;
+use group::ff::PrimeField;
+use rand_core::{Error as RngError, RngCore};
+
+use super::Error;
+
+/// The maximum number of attempts made by [`sample_nonzero_scalar`].
+///
+/// Each attempt succeeds with probability at least one half, so a working
+/// generator exhausts the attempts with probability at most `2^-128`.
+const MAX_SAMPLING_ATTEMPTS: usize = 128;
+
+/// Random number generator wrapper that records failures of the wrapped
+/// generator.
+///
+/// Field elements are sampled through the infallible [`RngCore`] methods,
+/// so the wrapper draws all randomness using [`RngCore::try_fill_bytes`]
+/// and remembers whether any call failed. Failed requests are filled with
+/// zeros, so sampling always terminates, and callers must check
+/// [`Self::failed`] before using the sampled values.
+pub(crate) struct CheckedRng<'a, R: RngCore> {
+    rng: &'a mut R,
+    failed: bool,
+}
+
+impl<'a, R> CheckedRng<'a, R>
+where
+    R: RngCore,
+{
+    /// Wraps the given generator.
+    pub(crate) fn new(rng: &'a mut R) -> Self {
+        Self { rng, failed: false }
+    }
+
+    /// Returns true if any request to the wrapped generator failed.
+    pub(crate) fn failed(&self) -> bool {
+        self.failed
+    }
+}
+
+impl<'a, R> RngCore for CheckedRng<'a, R>
+where
+    R: RngCore,
+{
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.try_fill_bytes(dest).is_err() {
+            dest.fill(0);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        let res = self.rng.try_fill_bytes(dest);
+        self.failed |= res.is_err();
+        res
+    }
+}
+
+/// Samples a uniformly random non-zero element of the prime field.
+///
+/// Each attempt fills the canonical representation with random bytes,
+/// clears the bits above the bit length of the modulus, and accepts
+/// the value if it is a valid, non-zero field element. Attempts run
+/// in constant time, and since rejected draws are discarded, the number
+/// of attempts reveals nothing about the returned element.
+///
+/// Fails if the random number generator fails, or if no element was
+/// accepted within the maximum number of attempts.
+pub(crate) fn sample_nonzero_scalar<F, R>(rng: &mut R) -> Result<F>
+where
+    F: PrimeField,
+    R: RngCore,
+{
+    // The canonical representation is either little- or big-endian,
+    // which can be told apart from the representation of one.
+    let little_endian = F::ONE.to_repr().as_ref()[0] == 1;
+    let num_bits = F::NUM_BITS as usize;
+
+    let mut repr = F::Repr::default();
+    for _ in 0..MAX_SAMPLING_ATTEMPTS {
+        let bytes = repr.as_mut();
+        let len = bytes.len();
+        if rng.try_fill_bytes(bytes).is_err() {
+            bytes.fill(0);
+            return Err(Error::RngFailure.into());
+        }
+
+        // Clear the bits above the bit length of the modulus, so that each
+        // attempt succeeds with probability at least one half.
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let k = if little_endian { i } else { len - 1 - i };
+            let excess = (8 * (k + 1)).saturating_sub(num_bits).min(8);
+            *byte &= (0xffu16 >> excess) as u8;
+        }
+
+        let s = F::from_repr(repr);
+        let accepted = s.is_some() & !s.unwrap_or(F::ZERO).is_zero();
+        if accepted.into() {
+            repr.as_mut().fill(0);
+            return Ok(s.unwrap());
+        }
+    }
+
+    Err(Error::ScalarSamplingFailed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use group::ff::PrimeField;
+    use rand::{rngs::StdRng, Error, RngCore, SeedableRng};
+
+    use crate::poly;
+
+    use super::{sample_nonzero_scalar, CheckedRng, MAX_SAMPLING_ATTEMPTS};
+
+    /// A generator that fails after the given number of requests.
+    struct FailingRng {
+        remaining: usize,
+    }
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            panic!("not implemented")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            panic!("not implemented")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            panic!("not implemented")
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            if self.remaining == 0 {
+                return Err(NonZeroU32::new(Error::CUSTOM_START).unwrap().into());
+            }
+            self.remaining -= 1;
+            dest.fill(7);
+            Ok(())
+        }
+    }
+
+    /// A generator that fills the first few requests with the given byte
+    /// and delegates the subsequent ones.
+    struct FixedRng {
+        byte: u8,
+        remaining: usize,
+        rng: StdRng,
+    }
+
+    impl FixedRng {
+        fn new(byte: u8, remaining: usize) -> Self {
+            let rng = SeedableRng::from_seed([1u8; 32]);
+            Self {
+                byte,
+                remaining,
+                rng,
+            }
+        }
+    }
+
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            panic!("not implemented")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            panic!("not implemented")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            panic!("not implemented")
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            if self.remaining == 0 {
+                return self.rng.try_fill_bytes(dest);
+            }
+            self.remaining -= 1;
+            dest.fill(self.byte);
+            Ok(())
+        }
+    }
+
+    fn test_sample_nonzero_scalar<F: PrimeField>() {
+        // Rejected draws: zero and, after clearing the excess bits,
+        // values larger than the modulus.
+        for byte in [0x00, 0xff] {
+            let mut rng = FixedRng::new(byte, 10);
+            let s: F = sample_nonzero_scalar(&mut rng).unwrap();
+            assert!(!bool::from(s.is_zero()));
+            assert_eq!(rng.remaining, 0);
+
+            let mut rng = FixedRng::new(byte, MAX_SAMPLING_ATTEMPTS);
+            let res = sample_nonzero_scalar::<F, _>(&mut rng);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                poly::Error::ScalarSamplingFailed.to_string()
+            );
+        }
+
+        // Failing generator.
+        let mut rng = FailingRng { remaining: 0 };
+        let res = sample_nonzero_scalar::<F, _>(&mut rng);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            poly::Error::RngFailure.to_string()
+        );
+
+        // Rough uniformity of the least significant bits.
+        let n = 4096;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let little_endian = F::ONE.to_repr().as_ref()[0] == 1;
+        let mut buckets = [0usize; 16];
+        for _ in 0..n {
+            let s: F = sample_nonzero_scalar(&mut rng).unwrap();
+            assert!(!bool::from(s.is_zero()));
+
+            let repr = s.to_repr();
+            let bytes = repr.as_ref();
+            let lsb = match little_endian {
+                true => bytes[0],
+                false => bytes[bytes.len() - 1],
+            };
+            buckets[(lsb & 0x0f) as usize] += 1;
+        }
+
+        // Each bucket expects 256 samples with a standard deviation of 15.5.
+        for count in buckets {
+            assert!((192..=320).contains(&count), "unexpected count {}", count);
+        }
+    }
+
+    #[test]
+    fn test_sample_nonzero_scalar_p384() {
+        test_sample_nonzero_scalar::<p384::Scalar>();
+    }
+
+    #[test]
+    fn test_sample_nonzero_scalar_ristretto255() {
+        test_sample_nonzero_scalar::<curve25519_dalek::Scalar>();
+    }
+
+    #[test]
+    fn test_checked_rng() {
+        // Working generator.
+        let mut inner: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut rng = CheckedRng::new(&mut inner);
+        let _ = rng.next_u32();
+        let _ = rng.next_u64();
+        assert!(!rng.failed());
+
+        // Failing generator.
+        let mut inner = FailingRng { remaining: 1 };
+        let mut rng = CheckedRng::new(&mut inner);
+        let mut bytes = [1u8; 4];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes, [7; 4]);
+        assert!(!rng.failed());
+
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes, [0; 4]);
+        assert!(rng.failed());
+        assert!(rng.try_fill_bytes(&mut bytes).is_err());
+    }
+}
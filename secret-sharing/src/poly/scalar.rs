@@ -1,5 +1,11 @@
+use alloc::vec::Vec;
+use core::iter::zip;
+
+use anyhow::Result;
 use group::ff::PrimeField;
 
+use super::Error;
+
 /// Converts an element of a non-binary prime field to bytes.
 pub fn scalar_to_bytes<F: PrimeField>(element: &F) -> Vec<u8> {
     element.to_repr().as_ref().to_vec()
@@ -22,12 +28,46 @@ pub fn scalar_from_bytes<F: PrimeField>(bytes: &[u8]) -> Option<F> {
     F::from_repr(repr).into()
 }
 
+/// Inverts the given elements in place using Montgomery's trick, which
+/// takes a single field inversion and three multiplications per element.
+///
+/// Fails, leaving the elements unchanged, if any element is zero.
+///
+/// This method is not constant time if any element is zero, so it should
+/// only be used on public values, such as the x values of Lagrange
+/// coefficients.
+pub(crate) fn batch_invert<F: PrimeField>(elems: &mut [F]) -> Result<()> {
+    if elems.iter().any(|e| bool::from(e.is_zero())) {
+        return Err(Error::InversionOfZero.into());
+    }
+
+    // Prefix products e_0 * ... * e_{i-1}.
+    let mut prefixes = Vec::with_capacity(elems.len());
+    let mut acc = F::ONE;
+    for e in elems.iter() {
+        prefixes.push(acc);
+        acc *= e;
+    }
+
+    // The product of non-zero elements is invertible.
+    let mut inv = acc.invert().expect("product should be non-zero");
+    for (e, prefix) in zip(elems.iter_mut(), prefixes).rev() {
+        let next = inv * *e; // 1 / (e_0 * ... * e_{i-1})
+        *e = inv * prefix; // 1 / e_i
+        inv = next;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use group::ff::Field;
     use rand::{rngs::StdRng, SeedableRng};
 
-    use super::{scalar_from_bytes, scalar_to_bytes};
+    use crate::poly::Error;
+
+    use super::{batch_invert, scalar_from_bytes, scalar_to_bytes};
 
     type PrimeField = p384::Scalar;
 
@@ -52,4 +92,33 @@ mod tests {
         let res = scalar_from_bytes::<PrimeField>(&bytes);
         assert!(res.is_none());
     }
+
+    #[test]
+    fn test_batch_invert() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [0, 1, 2, 5, 129] {
+            let elems: Vec<_> = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+            let mut inverses = elems.clone();
+            batch_invert(&mut inverses).unwrap();
+
+            // Batched inverses match individual inverses.
+            for (e, inv) in elems.iter().zip(&inverses) {
+                assert_eq!(*inv, e.invert().unwrap());
+            }
+        }
+
+        // Zero elements are rejected, leaving the elements unchanged.
+        let mut elems = vec![PrimeField::ONE, PrimeField::ZERO, PrimeField::from_u64(2)];
+        let res = batch_invert(&mut elems);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InversionOfZero.to_string()
+        );
+        assert_eq!(
+            elems,
+            vec![PrimeField::ONE, PrimeField::ZERO, PrimeField::from_u64(2)]
+        );
+    }
 }
@@ -0,0 +1,426 @@
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::max,
+    ops::{Add, Mul},
+    ptr,
+    sync::atomic,
+};
+
+use group::ff::PrimeField;
+use zeroize::Zeroize;
+
+use super::Polynomial;
+
+/// Sparse univariate polynomial over a non-binary prime field.
+///
+/// ```text
+/// A(x) = \sum_{k} a_k x^{e_k}
+/// ```
+///
+/// Only the non-zero coefficients are stored, together with their exponents,
+/// which suits polynomials of high degree with few terms, such as some
+/// masking polynomials. The terms are kept sorted by increasing exponent.
+///
+/// The constant zero polynomial is represented by an empty vector of terms.
+///
+/// Unlike [`Polynomial`], the representation reveals which coefficients
+/// are zero, so sparse polynomials are not suited for secret polynomials
+/// whose shape must stay hidden.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparsePolynomial<F> {
+    terms: Vec<(usize, F)>,
+}
+
+impl<F> SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a sparse polynomial from the given terms, i.e. pairs
+    /// of exponents and coefficients.
+    ///
+    /// Coefficients of equal exponents are summed and zero coefficients
+    /// are dropped.
+    ///
+    /// This method is not constant time.
+    pub fn new(mut terms: Vec<(usize, F)>) -> Self {
+        terms.sort_by_key(|(e, _)| *e);
+
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+        for (e, a) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == e => *sum += a,
+                _ => merged.push((e, a)),
+            }
+        }
+        merged.retain(|(_, a)| !bool::from(a.is_zero()));
+
+        Self { terms: merged }
+    }
+
+    /// Creates a sparse polynomial from the non-zero coefficients
+    /// of the given dense polynomial.
+    ///
+    /// This is equivalent to converting the polynomial using [`From`].
+    ///
+    /// This method is not constant time.
+    pub fn from_dense(p: &Polynomial<F>) -> Self {
+        Self::from(p)
+    }
+
+    /// Converts the sparse polynomial to a dense polynomial
+    /// with `degree + 1` coefficients.
+    pub fn to_dense(&self) -> Polynomial<F> {
+        let mut a = vec![F::ZERO; self.degree() + 1];
+        for (e, ae) in self.terms.iter() {
+            a[*e] = *ae;
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+
+    /// Returns the terms of the polynomial, i.e. pairs of exponents
+    /// and non-zero coefficients sorted by increasing exponent.
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.terms
+    }
+
+    /// Returns the number of non-zero terms of the polynomial.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns true if the polynomial has no non-zero terms, i.e. if it
+    /// is the zero polynomial.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the degree of the polynomial, which is zero for the zero
+    /// polynomial.
+    pub fn degree(&self) -> usize {
+        self.terms.last().map(|(e, _)| *e).unwrap_or(0)
+    }
+
+    /// Returns the coefficient of the term with the given exponent.
+    pub fn coefficient(&self, e: usize) -> F {
+        self.terms
+            .binary_search_by_key(&e, |(ei, _)| *ei)
+            .map(|i| self.terms[i].1)
+            .unwrap_or(F::ZERO)
+    }
+
+    /// Evaluates the polynomial at the given point.
+    ///
+    /// Only the non-zero terms are processed, and the powers of the point
+    /// are computed by exponentiation by the gaps between consecutive
+    /// exponents, so the running time depends on the number of terms
+    /// and on the exponents, but not on the coefficients.
+    pub fn eval(&self, x: &F) -> F {
+        let mut r = F::ZERO;
+        let mut xpow = F::ONE;
+        let mut prev = 0;
+        for (e, ae) in self.terms.iter() {
+            xpow *= x.pow_vartime([(e - prev) as u64]);
+            prev = *e;
+            r += *ae * xpow;
+        }
+
+        r
+    }
+}
+
+impl<F> From<&Polynomial<F>> for SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a sparse polynomial from the non-zero coefficients
+    /// of the given dense polynomial.
+    fn from(p: &Polynomial<F>) -> Self {
+        let terms = p
+            .coefficients()
+            .iter()
+            .enumerate()
+            .filter(|(_, ai)| !bool::from(ai.is_zero()))
+            .map(|(i, ai)| (i, *ai))
+            .collect();
+
+        Self { terms }
+    }
+}
+
+impl<F> From<Polynomial<F>> for SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a sparse polynomial from the non-zero coefficients
+    /// of the given dense polynomial.
+    fn from(p: Polynomial<F>) -> Self {
+        (&p).into()
+    }
+}
+
+impl<F> From<&SparsePolynomial<F>> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    /// Converts the sparse polynomial to a dense polynomial.
+    fn from(p: &SparsePolynomial<F>) -> Self {
+        p.to_dense()
+    }
+}
+
+impl<F> From<SparsePolynomial<F>> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    /// Converts the sparse polynomial to a dense polynomial.
+    fn from(p: SparsePolynomial<F>) -> Self {
+        p.to_dense()
+    }
+}
+
+//
+// Implementations of the `core::ops` traits.
+//
+
+impl<F> Add<&SparsePolynomial<F>> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = Polynomial<F>;
+
+    #[inline]
+    fn add(self, rhs: &SparsePolynomial<F>) -> Polynomial<F> {
+        &self + rhs
+    }
+}
+
+impl<F> Add<&SparsePolynomial<F>> for &Polynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = Polynomial<F>;
+
+    /// Adds the sparse polynomial to the dense polynomial, padding
+    /// the result with zeros to the larger of the two sizes.
+    fn add(self, rhs: &SparsePolynomial<F>) -> Polynomial<F> {
+        let mut a = self.a.clone();
+        a.resize(max(a.len(), rhs.degree() + 1), F::ZERO);
+        for (e, ae) in rhs.terms.iter() {
+            a[*e] += ae;
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+}
+
+impl<F> Mul<&SparsePolynomial<F>> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = Polynomial<F>;
+
+    #[inline]
+    fn mul(self, rhs: &SparsePolynomial<F>) -> Polynomial<F> {
+        &self * rhs
+    }
+}
+
+impl<F> Mul<&SparsePolynomial<F>> for &Polynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = Polynomial<F>;
+
+    /// Multiplies the dense polynomial by the sparse polynomial, skipping
+    /// zero terms of the latter. The result has the same size as the product
+    /// of the dense polynomial and the dense form of the sparse polynomial.
+    fn mul(self, rhs: &SparsePolynomial<F>) -> Polynomial<F> {
+        let mut a = vec![F::ZERO; self.a.len() + rhs.degree()];
+        for (e, ae) in rhs.terms.iter() {
+            for (i, ai) in self.a.iter().enumerate() {
+                a[i + e] += *ai * ae;
+            }
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+}
+
+impl<F> Zeroize for SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Overwrites all coefficients with zeros.
+    ///
+    /// The exponents of the terms are preserved.
+    fn zeroize(&mut self) {
+        for (_, ae) in self.terms.iter_mut() {
+            // SAFETY: The pointer is valid, properly aligned and points to
+            // an initialized coefficient. Volatile writes prevent the compiler
+            // from optimizing the zeroing away.
+            unsafe { ptr::write_volatile(ae, F::ZERO) };
+        }
+        atomic::compiler_fence(atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::SparsePolynomial;
+
+    type PrimeField = p384::Scalar;
+    type Polynomial = super::Polynomial<PrimeField>;
+
+    fn scalars(values: &[u64]) -> Vec<PrimeField> {
+        values.iter().map(|&w| PrimeField::from_u64(w)).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let terms = vec![(5, 1), (0, 2), (5, 3), (2, 0)];
+        let terms = terms
+            .into_iter()
+            .map(|(e, w)| (e, PrimeField::from_u64(w)))
+            .collect();
+        let p = SparsePolynomial::new(terms);
+        assert_eq!(p.terms(), &[(0, scalars(&[2])[0]), (5, scalars(&[4])[0])]);
+        assert_eq!(p.len(), 2);
+        assert_eq!(p.degree(), 5);
+        assert_eq!(p.coefficient(5), PrimeField::from_u64(4));
+        assert_eq!(p.coefficient(2), PrimeField::ZERO);
+
+        // Terms that cancel out are dropped.
+        let p = SparsePolynomial::new(vec![(3, PrimeField::ONE), (3, -PrimeField::ONE)]);
+        assert!(p.is_empty());
+        assert_eq!(p.degree(), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let test_cases = [
+            vec![0],
+            vec![1],
+            vec![0, 0, 3],
+            vec![1, 0, 0, 0, 2, 0],
+            vec![0, 1, 0, 1, 0, 1],
+        ];
+
+        for coefficients in test_cases {
+            let p = Polynomial::with_coefficients(scalars(&coefficients));
+            let sp = SparsePolynomial::from(&p);
+            assert_eq!(SparsePolynomial::from_dense(&p), sp);
+            for (e, ae) in sp.terms() {
+                assert_eq!(p.coefficient(*e), Some(ae));
+                assert_ne!(*ae, PrimeField::ZERO);
+            }
+
+            // Trailing zeros are trimmed by the conversion.
+            let mut q = p.clone();
+            q.trim();
+            assert!(sp.to_dense() == q);
+            assert!(Polynomial::from(&sp) == q);
+            assert_eq!(SparsePolynomial::from(sp.to_dense()), sp);
+        }
+    }
+
+    #[test]
+    fn test_eval() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let terms = (0..8)
+            .map(|k| (k * 37 + 5, PrimeField::random(&mut rng)))
+            .collect();
+        let sp = SparsePolynomial::new(terms);
+        let p = sp.to_dense();
+
+        for x in scalars(&[0, 1, 2, 12345]) {
+            assert_eq!(sp.eval(&x), p.eval(&x));
+        }
+        assert_eq!(
+            SparsePolynomial::<PrimeField>::new(vec![]).eval(&PrimeField::ONE),
+            PrimeField::ZERO
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        let test_cases = [
+            (vec![1, 2], vec![(0, 3)], vec![4, 2]),
+            (vec![1, 2], vec![(4, 3)], vec![1, 2, 0, 0, 3]),
+            (vec![1, 2, 3, 4], vec![(1, 1), (2, 1)], vec![1, 3, 4, 4]),
+            (vec![1, 2], vec![], vec![1, 2]),
+        ];
+
+        for (dense, sparse, expected) in test_cases {
+            let p = Polynomial::with_coefficients(scalars(&dense));
+            let sp = SparsePolynomial::new(
+                sparse
+                    .into_iter()
+                    .map(|(e, w)| (e, PrimeField::from_u64(w)))
+                    .collect(),
+            );
+            let expected = Polynomial::with_coefficients(scalars(&expected));
+
+            assert!(&p + &sp == expected);
+            assert!(p.clone() + &sp == expected);
+            assert!(&p + &sp.to_dense() == expected);
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = Polynomial::random(5, &mut rng);
+        let terms = vec![
+            (0, PrimeField::random(&mut rng)),
+            (7, PrimeField::random(&mut rng)),
+            (20, PrimeField::random(&mut rng)),
+        ];
+        let sp = SparsePolynomial::new(terms);
+
+        let expected = &p * &sp.to_dense();
+        assert!(&p * &sp == expected);
+        assert!(p.clone() * &sp == expected);
+
+        // Multiplication by zero.
+        let zero = SparsePolynomial::new(vec![]);
+        assert!(&p * &zero == Polynomial::zero(5));
+    }
+
+    fn prepare_bench_eval(deg: usize, n: usize) -> (SparsePolynomial<PrimeField>, PrimeField) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut terms: Vec<_> = (0..n - 1)
+            .map(|k| (k * deg / n, PrimeField::random(&mut rng)))
+            .collect();
+        terms.push((deg, PrimeField::random(&mut rng)));
+        let x = PrimeField::random(&mut rng);
+
+        (SparsePolynomial::new(terms), x)
+    }
+
+    #[bench]
+    fn bench_eval_sparse_4096_8(b: &mut Bencher) {
+        let (sp, x) = prepare_bench_eval(4096, 8);
+
+        b.iter(|| {
+            let _ = sp.eval(&x);
+        });
+    }
+
+    #[bench]
+    fn bench_eval_dense_4096_8(b: &mut Bencher) {
+        let (sp, x) = prepare_bench_eval(4096, 8);
+        let p = sp.to_dense();
+
+        b.iter(|| {
+            let _ = p.eval(&x);
+        });
+    }
+}
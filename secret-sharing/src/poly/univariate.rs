@@ -1,14 +1,31 @@
-use std::{
+use alloc::{vec, vec::Vec};
+use core::{
     cmp::{max, min},
-    iter::Sum,
+    iter::{zip, Sum},
+    mem,
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+    ptr,
+    sync::atomic,
 };
 
-use group::ff::PrimeField;
+use anyhow::Result;
+use group::{
+    ff::{Field, PrimeField},
+    Group,
+};
 use rand_core::RngCore;
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
+
+use crate::{
+    poly::{distinct_abscissae, lagrange, powers},
+    suites::FieldDigest,
+};
+
+use super::Error;
 
-use crate::poly::powers;
+/// The domain separation tag used to derive polynomials from transcripts.
+const TRANSCRIPT_POLYNOMIAL_DST: &[u8] = b"oasis-core/secret-sharing: transcript polynomial";
 
 /// Univariate polynomial over a non-binary prime field.
 ///
@@ -55,6 +72,54 @@ where
         Self::with_coefficients(a)
     }
 
+    /// Creates a polynomial with the given constant term and random
+    /// coefficients for all other terms.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_with_constant(c0: F, deg: u8, rng: &mut impl RngCore) -> Self {
+        let mut p = Self::random(deg, rng);
+        p.a[0] = c0;
+        p
+    }
+
+    /// Derives a polynomial of the given degree deterministically
+    /// from the given transcript seed and index.
+    ///
+    /// The i-th coefficient is the hash of the seed, prefixed with its
+    /// length, followed by the index and `i`, encoded as big-endian 64-bit
+    /// integers. Members of a committee who share the seed therefore derive
+    /// the same polynomial for each index, e.g. to agree on masking
+    /// polynomials without a leader, while distinct indices yield
+    /// independent polynomials.
+    pub fn derive_from_transcript<H>(seed: &[u8], index: usize, degree: usize) -> Result<Self>
+    where
+        H: FieldDigest<Output = F>,
+    {
+        let prefix = 8 + seed.len() + 8;
+        let mut msg = Vec::with_capacity(prefix + 8);
+        msg.extend_from_slice(&(seed.len() as u64).to_be_bytes());
+        msg.extend_from_slice(seed);
+        msg.extend_from_slice(&(index as u64).to_be_bytes());
+
+        let mut a = Vec::with_capacity(degree + 1);
+        for i in 0..=degree {
+            msg.truncate(prefix);
+            msg.extend_from_slice(&(i as u64).to_be_bytes());
+            match H::hash_to_field(&msg, TRANSCRIPT_POLYNOMIAL_DST) {
+                Ok(ai) => a.push(ai),
+                Err(err) => {
+                    msg.zeroize();
+                    zeroize_coefficients(&mut a);
+                    return Err(err);
+                }
+            }
+        }
+        msg.zeroize();
+
+        Ok(Self::with_coefficients(a))
+    }
+
     /// Creates a polynomial with the given coefficients.
     pub fn with_coefficients(a: Vec<F>) -> Self {
         if a.is_empty() {
@@ -64,6 +129,35 @@ where
         Self { a }
     }
 
+    /// Returns `a` if `choice` is zero and `b` if `choice` is one,
+    /// selecting each coefficient in constant time.
+    ///
+    /// Panics if the polynomials are of different sizes.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        assert_eq!(a.a.len(), b.a.len(), "polynomial sizes should match");
+
+        let a = zip(&a.a, &b.a)
+            .map(|(ai, bi)| F::conditional_select(ai, bi, choice))
+            .collect();
+
+        Self { a }
+    }
+
+    /// Creates a polynomial of the minimal degree passing through
+    /// the given points using Lagrange interpolation.
+    ///
+    /// If no points are given, the zero polynomial is returned.
+    pub fn interpolate(points: &[(F, F)]) -> Result<Self> {
+        if !distinct_abscissae(points) {
+            return Err(Error::DuplicateAbscissa.into());
+        }
+
+        let (xs, ys): (Vec<F>, Vec<F>) = points.iter().cloned().unzip();
+        let p = lagrange::lagrange(&xs, &ys);
+
+        Ok(p)
+    }
+
     /// Sets the coefficient `a_i` that belongs to the term `x^i`.
     ///
     /// If the coefficient does not exist, this is a no-op.
@@ -87,20 +181,88 @@ where
         self.a[0].is_zero().into()
     }
 
+    /// Returns true iff all coefficients of the polynomial are zero.
+    pub fn is_zero(&self) -> bool {
+        self.a
+            .iter()
+            .fold(Choice::from(1), |zero, ai| zero & ai.is_zero())
+            .into()
+    }
+
     /// Returns the number of coefficients in the polynomial.
+    ///
+    /// The size includes trailing zero coefficients, so it is preserved
+    /// by arithmetic operations even if the leading coefficients cancel.
     pub fn size(&self) -> usize {
         self.a.len()
     }
 
+    /// Returns the number of coefficients in the polynomial.
+    ///
+    /// This is an alias for [`Self::size`]. A polynomial always has
+    /// at least one coefficient, so it is never empty.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Returns the degree of the polynomial, ignoring trailing zero
+    /// coefficients.
+    ///
+    /// The degree of the zero polynomial is zero, and so is the degree
+    /// of a polynomial without coefficients.
+    ///
+    /// This method is not constant time.
+    pub fn degree(&self) -> usize {
+        self.a
+            .iter()
+            .rposition(|ai| !bool::from(ai.is_zero()))
+            .unwrap_or(0)
+    }
+
+    /// Removes trailing zero coefficients, keeping at least the constant
+    /// term, so that the size of the polynomial matches its degree.
+    ///
+    /// This method is not constant time.
+    pub fn trim(&mut self) {
+        self.a.truncate(self.degree() + 1);
+    }
+
+    /// Returns true if and only if the polynomials are equal up to
+    /// trailing zero coefficients, i.e. if they represent the same
+    /// function, so that `[1, 2, 0]` equals `[1, 2]`.
+    ///
+    /// Unlike [`PartialEq`], which compares the exact representations,
+    /// the comparison is constant time in the coefficients, and
+    /// short-circuits on the sizes only.
+    pub fn eq_normalized(&self, other: &Self) -> bool {
+        let (short, long) = match self.a.len() <= other.a.len() {
+            true => (&self.a, &other.a),
+            false => (&other.a, &self.a),
+        };
+
+        let eq = zip(short, long).fold(Choice::from(1), |eq, (a, b)| eq & a.ct_eq(b));
+        let eq = long[short.len()..]
+            .iter()
+            .fold(eq, |eq, ai| eq & ai.is_zero());
+
+        eq.into()
+    }
+
     /// Returns the i-th coefficient of the polynomial.
     pub fn coefficient(&self, i: usize) -> Option<&F> {
         self.a.get(i)
     }
 
+    /// Returns all coefficients of the polynomial, starting with
+    /// the coefficient of the constant term.
+    pub fn coefficients(&self) -> &[F] {
+        &self.a
+    }
+
     /// Returns the byte representation of the polynomial.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let cap = Self::byte_size(self.a.len());
-        let mut bytes = Vec::with_capacity(cap);
+        let mut bytes = Vec::with_capacity(self.encoded_len());
         for ai in &self.a {
             bytes.extend_from_slice(ai.to_repr().as_ref());
         }
@@ -112,12 +274,28 @@ where
     ///
     /// This method is not constant time if the length of the slice is invalid.
     pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes_with_limit(bytes, usize::MAX)
+    }
+
+    /// Attempts to create a polynomial from its byte representation,
+    /// rejecting representations of polynomials whose degree, including
+    /// trailing zero coefficients, exceeds the given maximum degree.
+    ///
+    /// Evaluating a polynomial takes time linear in its size, so callers
+    /// decoding untrusted polynomials should bound the degree, e.g. by
+    /// twice the largest threshold they support.
+    ///
+    /// This method is not constant time if the length of the slice is invalid.
+    pub fn from_bytes_with_limit(bytes: &[u8], max_degree: usize) -> Option<Self> {
         // Short-circuit on the length of the slice, not its contents.
         let coefficient_size = Self::coefficient_byte_size();
 
         if bytes.is_empty() || bytes.len() % coefficient_size != 0 {
             return None;
         }
+        if bytes.len() / coefficient_size - 1 > max_degree {
+            return None;
+        }
 
         // Don't short-circuit this loop to avoid revealing which coefficient
         // failed to decode.
@@ -152,16 +330,169 @@ where
         Self::coefficient_byte_size() * deg
     }
 
+    /// Returns the exact size of the byte representation returned by
+    /// [`Self::to_bytes`], so that callers can preallocate buffers.
+    pub fn encoded_len(&self) -> usize {
+        Self::byte_size(self.a.len())
+    }
+
     /// Evaluates the polynomial.
+    ///
+    /// Every coefficient, including trailing zeros, is processed with one
+    /// field multiplication and one field addition, so the running time
+    /// is linear in and depends only on the size of the polynomial, provided
+    /// that the field arithmetic is constant time. A polynomial without
+    /// coefficients evaluates to zero.
     pub fn eval(&self, x: &F) -> F {
-        let xpows = powers(x, self.a.len() - 1);
+        let xpows = powers(x, self.a.len().saturating_sub(1));
         let mut r = F::ZERO;
-        for (i, xpow) in xpows.iter().enumerate() {
-            r += self.a[i] * xpow
+        for (ai, xpow) in zip(&self.a, &xpows) {
+            r += *ai * xpow
         }
 
         r
     }
+
+    /// Evaluates the polynomial at the given points.
+    ///
+    /// The polynomial is evaluated at all points in a single pass over
+    /// its coefficients using Horner's method. This requires the same number
+    /// of field operations as evaluating the polynomial at each point
+    /// separately, as subproduct tree methods are only faster for degrees
+    /// much larger than those used in practice.
+    pub fn eval_many(&self, xs: &[F]) -> Vec<F> {
+        let mut rs = vec![F::ZERO; xs.len()];
+        for ai in self.a.iter().rev() {
+            for (r, x) in rs.iter_mut().zip(xs) {
+                *r *= x;
+                *r += ai;
+            }
+        }
+
+        rs
+    }
+
+    /// Evaluates the polynomial at the given point of an extension
+    /// of the prime field using Horner's method.
+    ///
+    /// The coefficients are lifted into the extension field before they are
+    /// combined, so for the prime field itself the result is the same
+    /// as the result of [`Self::eval`].
+    pub fn eval_ext<E>(&self, x: &E) -> E
+    where
+        E: Field + From<F>,
+    {
+        let mut r = E::ZERO;
+        for ai in self.a.iter().rev() {
+            r *= x;
+            r += E::from(*ai);
+        }
+
+        r
+    }
+
+    /// Evaluates the polynomial at the given point and commits to the value,
+    /// i.e. computes `A(x) * G` for the given generator `G`.
+    ///
+    /// The value is evaluated in the field and multiplied with the generator
+    /// once, rather than combining a commitment for every coefficient, so the
+    /// result can be compared directly with the evaluation of a verification
+    /// vector, such as one derived from [`VerificationMatrix::eval_at`].
+    ///
+    /// [`VerificationMatrix::eval_at`]: crate::vss::VerificationMatrix::eval_at
+    pub fn eval_on_group<G>(&self, x: &F, generator: &G) -> G
+    where
+        G: Group<Scalar = F>,
+    {
+        *generator * self.eval(x)
+    }
+
+    /// Divides the polynomial by the given divisor using long division
+    /// and returns the quotient and the remainder.
+    ///
+    /// The quotient `q` and the remainder `r` satisfy `self = q * divisor + r`,
+    /// where the degree of the remainder is smaller than the degree
+    /// of the divisor. The quotient is of the minimal size, while
+    /// the remainder has as many coefficients as the degree of the divisor.
+    ///
+    /// This method is not constant time.
+    pub fn div_rem(&self, divisor: &Polynomial<F>) -> Result<(Polynomial<F>, Polynomial<F>)> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZeroPolynomial.into());
+        }
+
+        let n = self.degree();
+        let m = divisor.degree();
+        let lead_inv = divisor.a[m]
+            .invert()
+            .expect("leading coefficient should be nonzero");
+
+        let mut r = self.a[..=n].to_vec();
+        let mut q = vec![F::ZERO; n.saturating_sub(m) + 1];
+        if n >= m {
+            for i in (0..=n - m).rev() {
+                let c = r[i + m] * lead_inv;
+                for (rj, dj) in r[i..=i + m].iter_mut().zip(&divisor.a) {
+                    *rj -= c * dj;
+                }
+                q[i] = c;
+            }
+        }
+        r.resize(m.max(1), F::ZERO);
+
+        Ok((Self::with_coefficients(q), Self::with_coefficients(r)))
+    }
+
+    /// Returns the formal derivative of the polynomial.
+    ///
+    /// The derivative has one coefficient less than the polynomial,
+    /// so the derivative of a constant polynomial is the zero polynomial
+    /// of degree zero.
+    pub fn derivative(&self) -> Polynomial<F> {
+        let a = self
+            .a
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, ai)| *ai * F::from_u128(i as u128))
+            .collect();
+
+        Self::with_coefficients(a)
+    }
+
+    /// Adds the given polynomial multiplied by the given scalar to this
+    /// polynomial, i.e. computes `self += other * scalar` in a single pass.
+    ///
+    /// Unlike the separate multiplication and addition, this doesn't
+    /// allocate a temporary polynomial. The polynomial grows in place
+    /// if the other polynomial is larger.
+    pub fn add_scaled(&mut self, other: &Polynomial<F>, scalar: &F) {
+        let min_len = min(self.a.len(), other.a.len());
+
+        for i in 0..min_len {
+            self.a[i] += other.a[i] * scalar;
+        }
+
+        self.a
+            .extend(other.a[min_len..].iter().map(|ai| *ai * scalar));
+    }
+}
+
+impl<F> ConstantTimeEq for Polynomial<F>
+where
+    F: PrimeField,
+{
+    /// Compares the coefficients of the polynomials in constant time.
+    ///
+    /// Polynomials of different sizes are never equal, and this case
+    /// short-circuits on the sizes only.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.a.len() != other.a.len() {
+            return Choice::from(0);
+        }
+
+        zip(&self.a, &other.a).fold(Choice::from(1), |eq, (a, b)| eq & a.ct_eq(b))
+    }
 }
 
 impl<F> Default for Polynomial<F>
@@ -385,19 +716,18 @@ where
 {
     type Output = Polynomial<F>;
 
+    /// Multiplies the polynomials.
+    ///
+    /// The size of the product is the sum of the sizes of the operands
+    /// minus one, so the degree of the product of non-zero polynomials
+    /// is the sum of their degrees.
+    ///
+    /// Short operands are multiplied using schoolbook multiplication,
+    /// and long ones using Karatsuba multiplication. The choice depends
+    /// only on the sizes of the operands, so the multiplication runs
+    /// in constant time.
     fn mul(self, rhs: &Polynomial<F>) -> Polynomial<F> {
-        let mut a = Vec::with_capacity(self.a.len() + rhs.a.len() - 1);
-        for i in 0..self.a.len() {
-            for j in 0..rhs.a.len() {
-                let aij = self.a[i] * rhs.a[j];
-                if i + j < a.len() {
-                    a[i + j] += aij;
-                } else {
-                    a.push(aij);
-                }
-            }
-        }
-        Polynomial::with_coefficients(a)
+        Polynomial::with_coefficients(mul_coefficients(&self.a, &rhs.a))
     }
 }
 
@@ -416,19 +746,107 @@ where
     F: PrimeField,
 {
     fn mul_assign(&mut self, rhs: &Polynomial<F>) {
-        let mut a = Vec::with_capacity(self.a.len() + rhs.a.len() - 2);
-        for i in 0..self.a.len() {
-            for j in 0..rhs.a.len() {
-                let aij = self.a[i] * rhs.a[j];
-                if i + j < a.len() {
-                    a[i + j] += aij;
-                } else {
-                    a.push(aij);
-                }
-            }
+        let mut a = mul_coefficients(&self.a, &rhs.a);
+        mem::swap(&mut self.a, &mut a);
+        zeroize_coefficients(&mut a);
+    }
+}
+
+/// The size of the shorter operand from which polynomials are multiplied
+/// using Karatsuba multiplication instead of schoolbook multiplication.
+///
+/// Benchmarked on P-384 scalars, where field multiplications dominate
+/// and a single level of Karatsuba multiplication breaks even with
+/// schoolbook multiplication for operands of about 8 coefficients
+/// and is consistently faster from 16 coefficients.
+const KARATSUBA_THRESHOLD: usize = 16;
+
+/// Multiplies the polynomials with the given coefficients.
+fn mul_coefficients<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+    mul_karatsuba(a, b)
+}
+
+/// Multiplies the non-empty polynomials with the given coefficients
+/// using schoolbook multiplication.
+fn mul_schoolbook<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut c = vec![F::ZERO; a.len() + b.len() - 1];
+    for (i, ai) in a.iter().enumerate() {
+        for (j, bj) in b.iter().enumerate() {
+            c[i + j] += *ai * bj;
         }
-        self.a = a;
     }
+    c
+}
+
+/// Multiplies the polynomials with the given coefficients using Karatsuba
+/// multiplication, where both polynomials have at least two coefficients.
+///
+/// The operands are split at half the size of the shorter one,
+/// i.e. `a = a0 + x^m a1` and `b = b0 + x^m b1`, and the product
+/// `a0 b0 + x^m ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) + x^2m a1 b1`
+/// is computed using three recursive multiplications.
+fn mul_karatsuba<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let m = a.len().min(b.len()) / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let mut z0 = mul_coefficients(a0, b0);
+    let mut z2 = mul_coefficients(a1, b1);
+    let mut sa = add_coefficients(a0, a1);
+    let mut sb = add_coefficients(b0, b1);
+    let mut z1 = mul_coefficients(&sa, &sb);
+    for (z1i, z0i) in z1.iter_mut().zip(&z0) {
+        *z1i -= z0i;
+    }
+    for (z1i, z2i) in z1.iter_mut().zip(&z2) {
+        *z1i -= z2i;
+    }
+
+    let mut c = vec![F::ZERO; a.len() + b.len() - 1];
+    for (ci, z0i) in c.iter_mut().zip(&z0) {
+        *ci += z0i;
+    }
+    for (ci, z1i) in c[m..].iter_mut().zip(&z1) {
+        *ci += z1i;
+    }
+    for (ci, z2i) in c[2 * m..].iter_mut().zip(&z2) {
+        *ci += z2i;
+    }
+
+    zeroize_coefficients(&mut z0);
+    zeroize_coefficients(&mut z1);
+    zeroize_coefficients(&mut z2);
+    zeroize_coefficients(&mut sa);
+    zeroize_coefficients(&mut sb);
+
+    c
+}
+
+/// Adds the polynomials with the given coefficients, where the first one
+/// is not longer than the second one.
+fn add_coefficients<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut c = b.to_vec();
+    for (ci, ai) in c.iter_mut().zip(a) {
+        *ci += ai;
+    }
+    c
+}
+
+/// Overwrites the given coefficients with zeros.
+fn zeroize_coefficients<F: PrimeField>(a: &mut [F]) {
+    for ai in a.iter_mut() {
+        // SAFETY: The pointer is valid, properly aligned and points to
+        // an initialized coefficient. Volatile writes prevent the compiler
+        // from optimizing the zeroing away.
+        unsafe { ptr::write_volatile(ai, F::ZERO) };
+    }
+    atomic::compiler_fence(atomic::Ordering::SeqCst);
 }
 
 impl<F> Mul<F> for Polynomial<F>
@@ -529,9 +947,37 @@ where
     }
 }
 
+impl<F> Zeroize for Polynomial<F>
+where
+    F: PrimeField,
+{
+    /// Overwrites all coefficients with zeros.
+    ///
+    /// The degree of the polynomial is preserved.
+    fn zeroize(&mut self) {
+        zeroize_coefficients(&mut self.a);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use rand::{rngs::StdRng, SeedableRng};
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use std::{
+        iter::{zip, Product, Sum},
+        ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    };
+
+    use group::{ff::Field, Group};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+    use zeroize::Zeroize;
+
+    use crate::{poly, suites, vss};
+
+    use super::{mul_karatsuba, mul_schoolbook, Error, KARATSUBA_THRESHOLD};
 
     type PrimeField = p384::Scalar;
     type Polynomial = super::Polynomial<PrimeField>;
@@ -559,6 +1005,50 @@ mod tests {
         assert_eq!(p.a, scalars(&[0, 0, 0]));
     }
 
+    #[test]
+    fn test_random_with_constant() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let c0 = scalar(7);
+
+        let p = Polynomial::random_with_constant(c0, 0, &mut rng);
+        assert_eq!(p.a, vec![c0]);
+
+        let p = Polynomial::random_with_constant(c0, 3, &mut rng);
+        let q = Polynomial::random_with_constant(c0, 3, &mut rng);
+        assert_eq!(p.size(), 4);
+        assert_eq!(p.coefficient(0), Some(&c0));
+        assert_eq!(q.coefficient(0), Some(&c0));
+        assert_ne!(p.a[1..], q.a[1..]);
+    }
+
+    #[test]
+    fn test_derive_from_transcript() {
+        type Suite = suites::p384::Sha3_384;
+
+        let p = Polynomial::derive_from_transcript::<Suite>(b"seed", 0, 3).unwrap();
+        let q = Polynomial::derive_from_transcript::<Suite>(b"seed", 0, 3).unwrap();
+        assert_eq!(p.size(), 4);
+        assert_eq!(p.a, q.a);
+
+        // Lower degrees yield prefixes.
+        let q = Polynomial::derive_from_transcript::<Suite>(b"seed", 0, 1).unwrap();
+        assert_eq!(p.a[..2], q.a[..]);
+
+        // Distinct indices or seeds diverge.
+        let q = Polynomial::derive_from_transcript::<Suite>(b"seed", 1, 3).unwrap();
+        assert!(zip(&p.a, &q.a).all(|(pi, qi)| pi != qi));
+
+        let q = Polynomial::derive_from_transcript::<Suite>(b"seed!", 0, 3).unwrap();
+        assert!(zip(&p.a, &q.a).all(|(pi, qi)| pi != qi));
+
+        // Seeds are length-prefixed, so they can't shift into the index.
+        let mut seed = b"seed".to_vec();
+        seed.extend_from_slice(&0u64.to_be_bytes());
+        let q = Polynomial::derive_from_transcript::<Suite>(&seed[..4], 0, 0).unwrap();
+        let r = Polynomial::derive_from_transcript::<Suite>(&seed, 0, 0).unwrap();
+        assert_ne!(q.a, r.a);
+    }
+
     #[test]
     fn test_with_coefficients() {
         let p = Polynomial::with_coefficients(vec![]);
@@ -616,6 +1106,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_degree_and_trim() {
+        let test_cases = [
+            (0, vec![]),
+            (0, vec![0]),
+            (0, vec![1]),
+            (0, vec![0, 0]),
+            (2, vec![1, 2, 3]),
+            (2, vec![1, 2, 3, 0, 0]),
+            (4, vec![0, 1, 2, 0, 3]),
+        ];
+
+        for (degree, coefficients) in test_cases {
+            let mut p = Polynomial::with_coefficients(scalars(&coefficients));
+            assert_eq!(p.degree(), degree);
+            p.trim();
+            assert_eq!(p.degree(), degree);
+            assert_eq!(p.size(), degree + 1);
+        }
+    }
+
+    #[test]
+    fn test_eq_normalized() {
+        let p = Polynomial::with_coefficients(scalars(&[1, 2]));
+        let padded = Polynomial::with_coefficients(scalars(&[1, 2, 0, 0]));
+        assert!(p.eq_normalized(&padded));
+        assert!(padded.eq_normalized(&p));
+        assert!(p.eq_normalized(&p));
+
+        // The exact representations still differ.
+        assert!(p != padded);
+        assert_eq!(padded.size(), 4);
+
+        let q = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        assert!(!p.eq_normalized(&q));
+        assert!(!q.eq_normalized(&p));
+        let q = Polynomial::with_coefficients(scalars(&[1, 3, 0]));
+        assert!(!p.eq_normalized(&q));
+
+        // Zero polynomials of any size are equal.
+        assert!(Polynomial::zero(0).eq_normalized(&Polynomial::zero(5)));
+
+        // Arithmetic may leave trailing zeros.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let a = Polynomial::random(3, &mut rng);
+        let mut b = Polynomial::random(3, &mut rng);
+        b.a[3] = a.a[3];
+        let diff = a.clone() - b.clone();
+        assert_eq!(diff.size(), 4);
+        let mut trimmed = diff.clone();
+        trimmed.trim();
+        assert_eq!(trimmed.size(), 3);
+        assert!(diff.eq_normalized(&trimmed));
+    }
+
+    #[test]
+    fn test_empty_polynomial() {
+        // Polynomials without coefficients can't be created using public
+        // constructors, but must not cause panics or wrap-arounds.
+        let mut p = Polynomial { a: Vec::new() };
+        assert_eq!(p.degree(), 0);
+        assert_eq!(p.size(), 0);
+        assert_eq!(p.eval(&scalar(5)), PrimeField::ZERO);
+        p.trim();
+        assert_eq!(p.size(), 0);
+
+        // Single coefficient.
+        let p = Polynomial::with_coefficients(scalars(&[7]));
+        assert_eq!(p.degree(), 0);
+        assert_eq!(p.eval(&scalar(5)), scalar(7));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert!(Polynomial::zero(0).is_zero());
+        assert!(Polynomial::zero(3).is_zero());
+        assert!(!Polynomial::with_coefficients(scalars(&[0, 0, 1])).is_zero());
+
+        // Cancellation keeps the size, but yields the zero polynomial.
+        let p = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        let q = Polynomial::with_coefficients(scalars(&[-1, -2, -3]));
+        let mut r = &p + &q;
+        assert!(r.is_zero());
+        assert_eq!(r.degree(), 0);
+        assert_eq!(r.size(), 3);
+        r.trim();
+        assert_eq!(r.size(), 1);
+
+        // Cancellation of the leading coefficients lowers the degree.
+        let q = Polynomial::with_coefficients(scalars(&[0, 0, -3]));
+        let r = &p + &q;
+        assert!(!r.is_zero());
+        assert_eq!(r.degree(), 1);
+    }
+
     #[test]
     fn test_coefficient() {
         let a = scalars(&[1, 2, 3]);
@@ -630,6 +1215,20 @@ mod tests {
         assert_eq!(p.coefficient(3), None);
     }
 
+    #[test]
+    fn test_coefficients() {
+        let mut p = Polynomial::with_coefficients(scalars(&[1, 2, 3, 0, 0]));
+        assert_eq!(p.coefficients(), scalars(&[1, 2, 3, 0, 0]).as_slice());
+        assert_eq!(p.len(), 5);
+
+        p.trim();
+        assert_eq!(p.coefficients().len(), p.degree() + 1);
+        assert_eq!(p.len(), p.size());
+        for (i, ai) in p.coefficients().iter().enumerate() {
+            assert_eq!(p.coefficient(i), Some(ai));
+        }
+    }
+
     #[test]
     fn test_serialization() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -644,6 +1243,41 @@ mod tests {
         assert!(bp == restored);
     }
 
+    #[test]
+    fn test_encoded_len() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let p = Polynomial { a: Vec::new() };
+        assert_eq!(p.encoded_len(), 0);
+        assert_eq!(p.to_bytes().len(), p.encoded_len());
+
+        for deg in [0, 1, 3, 10] {
+            let p = Polynomial::random(deg, &mut rng);
+            assert_eq!(p.encoded_len(), (deg as usize + 1) * 48);
+            assert_eq!(p.to_bytes().len(), p.encoded_len());
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_with_limit() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // The limit applies to the size, including trailing zeros.
+        let p = Polynomial::with_coefficients(scalars(&[1, 2, 3, 0]));
+        let bytes = p.to_bytes();
+        let restored = Polynomial::from_bytes_with_limit(&bytes, 3).unwrap();
+        assert!(p == restored);
+        assert!(Polynomial::from_bytes_with_limit(&bytes, 2).is_none());
+
+        let p = Polynomial::random(0, &mut rng);
+        let bytes = p.to_bytes();
+        assert!(Polynomial::from_bytes_with_limit(&bytes, 0).is_some());
+
+        // Invalid lengths are rejected regardless of the limit.
+        assert!(Polynomial::from_bytes_with_limit(&[], 3).is_none());
+        assert!(Polynomial::from_bytes_with_limit(&bytes[1..], 3).is_none());
+    }
+
     #[test]
     pub fn test_eval() {
         let f = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
@@ -656,6 +1290,278 @@ mod tests {
 
         let r = f.eval(&scalar(2));
         assert_eq!(r, scalar(1 + 2 * 2 + 3 * 2 * 2));
+
+        // Trailing zeros.
+        let f = Polynomial::with_coefficients(scalars(&[1, 2, 0, 0]));
+        let r = f.eval(&scalar(5));
+        assert_eq!(r, scalar(1 + 2 * 5));
+    }
+
+    #[test]
+    fn test_eval_on_group() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let generator = p384::ProjectivePoint::GENERATOR;
+
+        for deg in [0, 1, 5] {
+            let p = Polynomial::random(deg, &mut rng);
+            for _ in 0..3 {
+                let x = PrimeField::random(&mut rng);
+                let h = p384::ProjectivePoint::random(&mut rng);
+                assert_eq!(p.eval_on_group(&x, &generator), generator * p.eval(&x));
+                assert_eq!(p.eval_on_group(&x, &h), h * p.eval(&x));
+            }
+        }
+
+        // Comparison with the evaluation of the verification matrix.
+        let bp = poly::BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = vss::VerificationMatrix::<p384::ProjectivePoint>::from(&bp);
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+        let vv = vss::VerificationVector::new(vm.eval_at(&x));
+        assert_eq!(bp.eval_x(&x).eval_on_group(&y, &generator), vv.eval(&y));
+    }
+
+    #[test]
+    pub fn test_interpolate() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // No points.
+        let p = Polynomial::interpolate(&[]).unwrap();
+        assert!(p == Polynomial::zero(0));
+
+        // Random points.
+        for n in [1, 2, 5, 10] {
+            let points: Vec<_> = (0..n)
+                .map(|_| (PrimeField::random(&mut rng), PrimeField::random(&mut rng)))
+                .collect();
+            let p = Polynomial::interpolate(&points).unwrap();
+            assert!(p.size() <= n);
+            for (x, y) in points {
+                assert_eq!(p.eval(&x), y);
+            }
+        }
+
+        // Points on a polynomial.
+        let f = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        let points: Vec<_> = scalars(&[1, 2, 3])
+            .into_iter()
+            .map(|x| (x, f.eval(&x)))
+            .collect();
+        let p = Polynomial::interpolate(&points).unwrap();
+        assert!(p == f);
+
+        // Duplicate abscissae.
+        let points = vec![(scalar(1), scalar(2)), (scalar(1), scalar(3))];
+        let res = Polynomial::interpolate(&points);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DuplicateAbscissa.to_string()
+        );
+    }
+
+    /// The quadratic extension `F[i] / (i^2 - 2)` of the prime field,
+    /// as 2 is a quadratic non-residue modulo the order of P-384.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct QuadraticExtension(PrimeField, PrimeField);
+
+    impl QuadraticExtension {
+        const NON_RESIDUE: u64 = 2;
+
+        fn norm(&self) -> PrimeField {
+            self.0.square() - self.1.square() * PrimeField::from_u64(Self::NON_RESIDUE)
+        }
+    }
+
+    impl From<PrimeField> for QuadraticExtension {
+        fn from(c0: PrimeField) -> Self {
+            Self(c0, PrimeField::ZERO)
+        }
+    }
+
+    impl ConditionallySelectable for QuadraticExtension {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            Self(
+                PrimeField::conditional_select(&a.0, &b.0, choice),
+                PrimeField::conditional_select(&a.1, &b.1, choice),
+            )
+        }
+    }
+
+    impl ConstantTimeEq for QuadraticExtension {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1)
+        }
+    }
+
+    impl Neg for QuadraticExtension {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(-self.0, -self.1)
+        }
+    }
+
+    impl Add<&QuadraticExtension> for QuadraticExtension {
+        type Output = Self;
+
+        fn add(self, rhs: &Self) -> Self {
+            Self(self.0 + rhs.0, self.1 + rhs.1)
+        }
+    }
+
+    impl Sub<&QuadraticExtension> for QuadraticExtension {
+        type Output = Self;
+
+        fn sub(self, rhs: &Self) -> Self {
+            Self(self.0 - rhs.0, self.1 - rhs.1)
+        }
+    }
+
+    impl Mul<&QuadraticExtension> for QuadraticExtension {
+        type Output = Self;
+
+        fn mul(self, rhs: &Self) -> Self {
+            let nr = PrimeField::from_u64(Self::NON_RESIDUE);
+            Self(
+                self.0 * rhs.0 + self.1 * rhs.1 * nr,
+                self.0 * rhs.1 + self.1 * rhs.0,
+            )
+        }
+    }
+
+    macro_rules! impl_ops {
+        ($($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident);*) => {$(
+            impl $op for QuadraticExtension {
+                type Output = Self;
+
+                fn $fn(self, rhs: Self) -> Self {
+                    self.$fn(&rhs)
+                }
+            }
+
+            impl $op_assign for QuadraticExtension {
+                fn $fn_assign(&mut self, rhs: Self) {
+                    *self = self.$fn(&rhs);
+                }
+            }
+
+            impl $op_assign<&QuadraticExtension> for QuadraticExtension {
+                fn $fn_assign(&mut self, rhs: &Self) {
+                    *self = self.$fn(rhs);
+                }
+            }
+        )*};
+    }
+
+    impl_ops!(
+        Add, add, AddAssign, add_assign;
+        Sub, sub, SubAssign, sub_assign;
+        Mul, mul, MulAssign, mul_assign
+    );
+
+    impl Sum for QuadraticExtension {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Self::ZERO, |acc, x| acc + x)
+        }
+    }
+
+    impl<'a> Sum<&'a QuadraticExtension> for QuadraticExtension {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Self::ZERO, |acc, x| acc + x)
+        }
+    }
+
+    impl Product for QuadraticExtension {
+        fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Self::ONE, |acc, x| acc * x)
+        }
+    }
+
+    impl<'a> Product<&'a QuadraticExtension> for QuadraticExtension {
+        fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Self::ONE, |acc, x| acc * x)
+        }
+    }
+
+    impl Field for QuadraticExtension {
+        const ZERO: Self = Self(PrimeField::ZERO, PrimeField::ZERO);
+        const ONE: Self = Self(PrimeField::ONE, PrimeField::ZERO);
+
+        fn random(mut rng: impl RngCore) -> Self {
+            Self(PrimeField::random(&mut rng), PrimeField::random(&mut rng))
+        }
+
+        fn square(&self) -> Self {
+            *self * self
+        }
+
+        fn double(&self) -> Self {
+            *self + self
+        }
+
+        fn invert(&self) -> CtOption<Self> {
+            self.norm().invert().map(|n| Self(self.0 * n, -self.1 * n))
+        }
+
+        fn sqrt_ratio(_num: &Self, _div: &Self) -> (Choice, Self) {
+            unimplemented!("square roots are not needed in tests")
+        }
+    }
+
+    #[test]
+    pub fn test_eval_ext() {
+        let f = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+
+        // Point 2 + i, where i^2 = 2, and (2 + i)^2 = 6 + 4i.
+        let x = QuadraticExtension(scalar(2), scalar(1));
+        let r = f.eval_ext(&x);
+        assert_eq!(
+            r,
+            QuadraticExtension(scalar(1 + 2 * 2 + 3 * 6), scalar(2 + 3 * 4))
+        );
+
+        // Embedded prime field points.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let f = Polynomial::random(5, &mut rng);
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            assert_eq!(f.eval_ext(&x), f.eval(&x));
+            let r = f.eval_ext(&QuadraticExtension::from(x));
+            assert_eq!(r, QuadraticExtension::from(f.eval(&x)));
+        }
+
+        // Inverses in the extension field.
+        let x = QuadraticExtension::random(&mut rng);
+        assert_eq!(x * x.invert().unwrap(), QuadraticExtension::ONE);
+    }
+
+    #[test]
+    pub fn test_eval_many() {
+        let f = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        let xs = scalars(&[0, 1, 2, 5]);
+        let rs = scalars(&[1, 1 + 2 + 3, 1 + 2 * 2 + 3 * 2 * 2, 1 + 2 * 5 + 3 * 5 * 5]);
+        assert_eq!(f.eval_many(&xs), rs);
+
+        // No points.
+        assert!(f.eval_many(&[]).is_empty());
+    }
+
+    #[test]
+    pub fn test_ct_eq() {
+        let p = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        let q = Polynomial::with_coefficients(scalars(&[1, 2, 4]));
+        let r = Polynomial::with_coefficients(scalars(&[1, 2, 3, 0]));
+        assert!(bool::from(p.ct_eq(&p.clone())));
+        assert!(!bool::from(p.ct_eq(&q)));
+        assert!(!bool::from(p.ct_eq(&r)));
+    }
+
+    #[test]
+    pub fn test_zeroize() {
+        let mut p = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        p.zeroize();
+        assert_eq!(p.a, scalars(&[0, 0, 0]));
     }
 
     #[test]
@@ -711,6 +1617,8 @@ mod tests {
             (vec![1, 2, 3], vec![2], vec![-1, 2, 3]),
             // Zero coefficients.
             (vec![1, 2, 3, 4], vec![1, -2, 3, 4], vec![0, 4, 0, 0]),
+            // Same polynomial.
+            (vec![1, 2, 3], vec![1, 2, 3], vec![0, 0, 0]),
         ];
 
         for (coefficients_f, coefficients_g, coefficients_h) in test_cases {
@@ -780,11 +1688,151 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_mul_random() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let degs = [0, 1, 4, 15, 16, 17, 33, 63, 100];
+
+        for deg_f in degs {
+            for deg_g in degs {
+                let f = Polynomial::random(deg_f, &mut rng);
+                let g = Polynomial::random(deg_g, &mut rng);
+                let h = Polynomial::random(5, &mut rng);
+
+                // Karatsuba and schoolbook multiplication agree.
+                let fg = &f * &g;
+                assert_eq!(fg.a, mul_schoolbook(&f.a, &g.a));
+                assert_eq!(fg.size(), f.size() + g.size() - 1);
+                assert_eq!(fg.degree(), f.degree() + g.degree());
+
+                // Commutativity and associativity.
+                assert!(fg == &g * &f);
+                assert!(&fg * &h == &f * &(&g * &h));
+            }
+        }
+
+        // Karatsuba multiplication of long operands with short halves.
+        let f = Polynomial::random(200, &mut rng);
+        let g = Polynomial::random(KARATSUBA_THRESHOLD as u8, &mut rng);
+        assert_eq!(mul_karatsuba(&f.a, &g.a), mul_schoolbook(&f.a, &g.a));
+    }
+
+    #[test]
+    pub fn test_div_rem() {
+        let test_cases = vec![
+            // Exact division.
+            (vec![2, 8, 14, 12], vec![2, 4], vec![1, 2, 3], vec![0]),
+            // Non-zero remainder.
+            (vec![1, 2, 3], vec![1, 1], vec![-1, 3], vec![2]),
+            (vec![5, 0, 1, 1], vec![1, 0, 1], vec![1, 1], vec![4, -1]),
+            // Dividend of a smaller degree.
+            (vec![1, 2], vec![0, 0, 1], vec![0], vec![1, 2]),
+            // Constant divisor.
+            (vec![2, 4, 6], vec![2], vec![1, 2, 3], vec![0]),
+            // Trailing zeros.
+            (vec![1, 2, 3, 0], vec![1, 1, 0], vec![-1, 3], vec![2]),
+        ];
+
+        for (coefficients_f, coefficients_g, coefficients_q, coefficients_r) in test_cases {
+            let mut f = Polynomial::with_coefficients(scalars(&coefficients_f));
+            let g = Polynomial::with_coefficients(scalars(&coefficients_g));
+
+            let (q, r) = f.div_rem(&g).unwrap();
+            assert_eq!(q.a, scalars(&coefficients_q));
+            assert_eq!(r.a, scalars(&coefficients_r));
+            assert!(r.degree() < g.degree() || r.is_zero());
+
+            // Test q * g + r = f.
+            let mut h = q * &g + &r;
+            h.trim();
+            f.trim();
+            assert_eq!(h.a, f.a);
+        }
+
+        // Division by a monic linear factor (x - a).
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let f = Polynomial::random(5, &mut rng);
+        let a = PrimeField::random(&mut rng);
+        let g = Polynomial::with_coefficients(vec![a.neg(), PrimeField::ONE]);
+        let (q, r) = f.div_rem(&g).unwrap();
+        assert_eq!(q.size(), 5);
+        assert_eq!(r.a, vec![f.eval(&a)]);
+
+        // Division by zero.
+        let g = Polynomial::zero(2);
+        let res = f.div_rem(&g);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DivisionByZeroPolynomial.to_string()
+        );
+    }
+
+    #[test]
+    fn test_add_scaled() {
+        let test_cases = vec![
+            (vec![1, 2, 3], vec![4, 5, 6], 2, vec![9, 12, 15]),
+            (vec![1], vec![4, 5, 6], 3, vec![13, 15, 18]),
+            (vec![1, 2, 3], vec![4], -1, vec![-3, 2, 3]),
+            (vec![1, 2], vec![4, 5], 0, vec![1, 2]),
+        ];
+
+        for (coefficients_p, coefficients_q, s, coefficients_r) in test_cases {
+            let mut p = Polynomial::with_coefficients(scalars(&coefficients_p));
+            let q = Polynomial::with_coefficients(scalars(&coefficients_q));
+            p.add_scaled(&q, &scalar(s));
+            assert_eq!(p.a, scalars(&coefficients_r));
+        }
+
+        // Equivalence with separate multiplication and addition.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut p = Polynomial::zero(0);
+        let mut q = Polynomial::zero(0);
+        for deg in 0..10 {
+            let r = Polynomial::random(deg, &mut rng);
+            let s = PrimeField::random(&mut rng);
+            p.add_scaled(&r, &s);
+            q += &r * s;
+        }
+        assert_eq!(p.a, q.a);
+    }
+
+    #[test]
+    pub fn test_derivative() {
+        let test_cases = vec![
+            // Constant.
+            (vec![0], vec![0]),
+            (vec![7], vec![0]),
+            // Non-constant.
+            (vec![1, 2], vec![2]),
+            (vec![5, -3, 4, 2], vec![-3, 8, 6]),
+            // Trailing zeros.
+            (vec![1, 2, 0], vec![2, 0]),
+        ];
+
+        for (coefficients_f, coefficients_g) in test_cases {
+            let f = Polynomial::with_coefficients(scalars(&coefficients_f));
+            let g = f.derivative();
+            assert_eq!(g.a, scalars(&coefficients_g));
+        }
+
+        // Non-degenerate polynomials.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        for deg in 1..5 {
+            let mut f = Polynomial::random(deg, &mut rng);
+            f.set_coefficient(deg as usize, PrimeField::ONE);
+            let g = f.derivative();
+            assert_eq!(g.degree(), f.degree() - 1);
+        }
+    }
+
     #[test]
     pub fn test_scalar_mul() {
         let test_cases = vec![
             // Non-zero.
             (vec![1, 2, 3], 2, vec![2, 4, 6]),
+            // One.
+            (vec![1, 2, 3], 1, vec![1, 2, 3]),
             // Zero.
             (vec![1, 2, 3], 0, vec![0, 0, 0]),
         ];
@@ -843,4 +1891,125 @@ mod tests {
             assert_eq!(v.a, h.a);
         }
     }
+
+    fn prepare_bench_eval(deg: u8, n: usize) -> (Polynomial, Vec<PrimeField>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = Polynomial::random(deg, &mut rng);
+        let xs = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+
+        (p, xs)
+    }
+
+    fn bench_eval(b: &mut Bencher, deg: u8, n: usize) {
+        let (p, xs) = prepare_bench_eval(deg, n);
+
+        b.iter(|| {
+            let _: Vec<_> = xs.iter().map(|x| p.eval(x)).collect();
+        });
+    }
+
+    fn bench_eval_many(b: &mut Bencher, deg: u8, n: usize) {
+        let (p, xs) = prepare_bench_eval(deg, n);
+
+        b.iter(|| {
+            let _ = p.eval_many(&xs);
+        });
+    }
+
+    fn prepare_bench_reconstruction(deg: u8, n: usize) -> (Vec<Polynomial>, Vec<PrimeField>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let ps = (0..n).map(|_| Polynomial::random(deg, &mut rng)).collect();
+        let cs = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+
+        (ps, cs)
+    }
+
+    #[bench]
+    fn bench_reconstruction_add_scaled_255_256(b: &mut Bencher) {
+        let (ps, cs) = prepare_bench_reconstruction(255, 256);
+
+        b.iter(|| {
+            let mut acc = Polynomial::zero(255);
+            for (p, c) in ps.iter().zip(&cs) {
+                acc.add_scaled(p, c);
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_reconstruction_mul_add_255_256(b: &mut Bencher) {
+        let (ps, cs) = prepare_bench_reconstruction(255, 256);
+
+        b.iter(|| {
+            let mut acc = Polynomial::zero(255);
+            for (p, c) in ps.iter().zip(&cs) {
+                acc += &(p * c);
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_eval_128_256(b: &mut Bencher) {
+        bench_eval(b, 128, 256)
+    }
+
+    #[bench]
+    fn bench_eval_many_128_256(b: &mut Bencher) {
+        bench_eval_many(b, 128, 256)
+    }
+
+    fn prepare_bench_mul(size: usize) -> (Polynomial, Polynomial) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let deg = (size - 1) as u8;
+        let f = Polynomial::random(deg, &mut rng);
+        let g = Polynomial::random(deg, &mut rng);
+
+        (f, g)
+    }
+
+    fn bench_mul_schoolbook(b: &mut Bencher, size: usize) {
+        let (f, g) = prepare_bench_mul(size);
+
+        b.iter(|| {
+            let _ = mul_schoolbook(&f.a, &g.a);
+        });
+    }
+
+    fn bench_mul_karatsuba(b: &mut Bencher, size: usize) {
+        let (f, g) = prepare_bench_mul(size);
+
+        b.iter(|| {
+            let _ = mul_karatsuba(&f.a, &g.a);
+        });
+    }
+
+    #[bench]
+    fn bench_mul_schoolbook_8(b: &mut Bencher) {
+        bench_mul_schoolbook(b, 8)
+    }
+
+    #[bench]
+    fn bench_mul_karatsuba_8(b: &mut Bencher) {
+        bench_mul_karatsuba(b, 8)
+    }
+
+    #[bench]
+    fn bench_mul_schoolbook_16(b: &mut Bencher) {
+        bench_mul_schoolbook(b, 16)
+    }
+
+    #[bench]
+    fn bench_mul_karatsuba_16(b: &mut Bencher) {
+        bench_mul_karatsuba(b, 16)
+    }
+
+    #[bench]
+    fn bench_mul_schoolbook_32(b: &mut Bencher) {
+        bench_mul_schoolbook(b, 32)
+    }
+
+    #[bench]
+    fn bench_mul_karatsuba_32(b: &mut Bencher) {
+        bench_mul_karatsuba(b, 32)
+    }
 }
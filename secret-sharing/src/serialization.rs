@@ -0,0 +1,289 @@
+//! Serde support for secret shares and verification matrices.
+//!
+//! All types are serialized using their canonical byte representations,
+//! so deserialization fails if any of the scalars or group elements
+//! is not canonically encoded.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use group::{ff::PrimeField, Group, GroupEncoding};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use zeroize::Zeroize;
+
+use crate::{
+    churp::{SecretShare, VerifiableSecretShare},
+    poly::{scalar_from_bytes, scalar_to_bytes, Polynomial},
+    vss::VerificationMatrix,
+};
+
+/// The maximum number of bytes preallocated when deserializing a sequence.
+const MAX_PREALLOCATED_BYTES: usize = 4096;
+
+/// Byte string which is zeroized on drop, as it may hold secret data.
+struct Bytes(Vec<u8>);
+
+impl Drop for Bytes {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Bytes(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Don't trust the size hint, as it may come from malformed input.
+        let cap = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED_BYTES);
+        let mut bytes = Bytes(Vec::with_capacity(cap));
+        while let Some(b) = seq.next_element()? {
+            bytes.0.push(b);
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl<F> Serialize for Polynomial<F>
+where
+    F: PrimeField,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bytes(self.to_bytes()).serialize(serializer)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Bytes::deserialize(deserializer)?;
+        Polynomial::from_bytes(&bytes.0).ok_or_else(|| de::Error::custom("invalid polynomial"))
+    }
+}
+
+impl<G> Serialize for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bytes(self.to_bytes()).serialize(serializer)
+    }
+}
+
+impl<'de, G> Deserialize<'de> for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Bytes::deserialize(deserializer)?;
+        VerificationMatrix::from_bytes(&bytes.0)
+            .map_err(|_| de::Error::custom("invalid verification matrix"))
+    }
+}
+
+impl<F> Serialize for SecretShare<F>
+where
+    F: PrimeField,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (Bytes(scalar_to_bytes(&self.x)), &self.p).serialize(serializer)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for SecretShare<F>
+where
+    F: PrimeField,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, p) = <(Bytes, Polynomial<F>)>::deserialize(deserializer)?;
+        let x = scalar_from_bytes(&x.0).ok_or_else(|| de::Error::custom("invalid identity"))?;
+        Ok(SecretShare::new(x, p))
+    }
+}
+
+impl<G> Serialize for VerifiableSecretShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.share, &self.vm).serialize(serializer)
+    }
+}
+
+impl<'de, G> Deserialize<'de> for VerifiableSecretShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (share, vm) =
+            <(SecretShare<G::Scalar>, VerificationMatrix<G>)>::deserialize(deserializer)?;
+        Ok(VerifiableSecretShare::new(share, vm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use serde::{
+        de::{self, value::SeqDeserializer},
+        Deserialize,
+    };
+
+    use super::Bytes;
+    use crate::{
+        churp::{self, HandoffKind},
+        poly::{self, scalar_to_bytes},
+        suites::{self, p384},
+        vss,
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Polynomial = poly::Polynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+    type SecretShare = churp::SecretShare<PrimeField>;
+    type VerifiableSecretShare = churp::VerifiableSecretShare<Group>;
+    type Dealer = churp::Dealer<Group>;
+
+    fn prepare_verifiable_share() -> VerifiableSecretShare {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+
+        VerifiableSecretShare::new(share, vm)
+    }
+
+    #[test]
+    fn test_serialization() {
+        let verifiable_share = prepare_verifiable_share();
+        let share = verifiable_share.secret_share();
+        let vm = verifiable_share.verification_matrix();
+
+        // Polynomial.
+        let json = serde_json::to_string(share.polynomial()).unwrap();
+        let restored: Polynomial = serde_json::from_str(&json).unwrap();
+        assert!(restored == *share.polynomial());
+
+        // Verification matrix.
+        let json = serde_json::to_string(vm).unwrap();
+        let restored: VerificationMatrix = serde_json::from_str(&json).unwrap();
+        assert!(restored == *vm);
+
+        // Secret share.
+        let json = serde_json::to_string(share).unwrap();
+        let restored: SecretShare = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.coordinate_x(), share.coordinate_x());
+        assert!(restored.polynomial() == share.polynomial());
+
+        // Verifiable secret share.
+        let json = serde_json::to_string(&verifiable_share).unwrap();
+        let restored: VerifiableSecretShare = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.secret_share().coordinate_x(), share.coordinate_x());
+        assert!(restored.secret_share().polynomial() == share.polynomial());
+        assert!(restored.verification_matrix() == vm);
+
+        // Serialization is deterministic.
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn test_non_canonical_encoding() {
+        // Scalar larger than the modulus.
+        let invalid = vec![255u8; scalar_to_bytes(&PrimeField::ONE).len()];
+
+        // Polynomial.
+        let json = serde_json::to_string(&invalid).unwrap();
+        let res: Result<Polynomial, _> = serde_json::from_str(&json);
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("invalid polynomial"));
+
+        // Secret share with invalid identity.
+        let p = Polynomial::with_coefficients(vec![PrimeField::ONE]);
+        let json = serde_json::to_string(&(&invalid, &p)).unwrap();
+        let res: Result<SecretShare, _> = serde_json::from_str(&json);
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("invalid identity"));
+
+        // Secret share with invalid polynomial.
+        let x = scalar_to_bytes(&PrimeField::ONE);
+        let json = serde_json::to_string(&(&x, &invalid)).unwrap();
+        let res: Result<SecretShare, _> = serde_json::from_str(&json);
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("invalid polynomial"));
+
+        // Verification matrix with invalid dimensions.
+        let json = serde_json::to_string(&vec![0u8, 0u8]).unwrap();
+        let res: Result<VerificationMatrix, _> = serde_json::from_str(&json);
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("invalid verification matrix"));
+    }
+
+    /// Iterator over the given bytes which claims to be much longer.
+    struct LyingIter(std::vec::IntoIter<u8>);
+
+    impl Iterator for LyingIter {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::MAX, Some(usize::MAX))
+        }
+    }
+
+    #[test]
+    fn test_untrusted_size_hint() {
+        let iter = LyingIter(vec![1u8, 2, 3].into_iter());
+        let deserializer = SeqDeserializer::<_, de::value::Error>::new(iter);
+        let bytes = Bytes::deserialize(deserializer).unwrap();
+        assert_eq!(bytes.0, vec![1u8, 2, 3]);
+    }
+}
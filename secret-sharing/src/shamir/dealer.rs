@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
+
 use group::ff::PrimeField;
-use rand::RngCore;
+use rand_core::RngCore;
 
 use crate::poly::{Point, Polynomial};
 
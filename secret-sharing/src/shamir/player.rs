@@ -1,4 +1,5 @@
-use std::iter::zip;
+use alloc::vec::Vec;
+use core::iter::zip;
 
 use anyhow::{bail, Result};
 use group::ff::PrimeField;
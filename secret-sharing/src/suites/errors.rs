@@ -0,0 +1,5 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("non-canonical scalar")]
+    NonCanonicalScalar,
+}
@@ -1,8 +1,47 @@
 use anyhow::Result;
 
-use group::{ff::PrimeField, Group, GroupEncoding};
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
 
+mod errors;
 pub mod p384;
+pub mod ristretto255;
+#[cfg(any(test, feature = "test-suite"))]
+pub mod toy;
+
+// Re-exports.
+pub use self::errors::*;
+
+/// Identifier of a cipher suite.
+///
+/// The identifier is written into encodings that must be decoded under
+/// the same suite, so the numeric tags must never change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SuiteId {
+    /// The NIST P-384 suite, see [`p384::Sha3_384`].
+    P384Sha3_384 = 1,
+    /// The Ristretto255 suite, see [`ristretto255::Ristretto255Sha512`].
+    Ristretto255Sha512 = 2,
+}
+
+impl SuiteId {
+    /// Returns the numeric tag of the suite.
+    pub fn tag(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the suite with the given numeric tag, if any.
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(Self::P384Sha3_384),
+            2 => Some(Self::Ristretto255Sha512),
+            _ => None,
+        }
+    }
+}
 
 /// A trait for hashing arbitrary-length byte strings to elements of a prime field.
 pub trait FieldDigest {
@@ -34,13 +73,171 @@ pub trait Suite:
 
     /// The type representing an element of a cryptographic group.
     type Group: Group<Scalar = Self::PrimeField> + GroupEncoding;
+
+    /// The order of the group, encoded in big-endian byte order.
+    const ORDER_BYTES: &'static [u8];
+
+    /// Returns the canonical generator of the group.
+    ///
+    /// Commitments are computed and verified with respect to this generator,
+    /// which is the standard base point of the group returned by
+    /// [`Group::generator`], as all verification helpers are generic over
+    /// the group rather than the suite.
+    fn generator() -> Self::Group {
+        Self::Group::generator()
+    }
+
+    /// Attempts to create an element of the prime field from its canonical
+    /// big-endian encoding, which has as many bytes as the order
+    /// of the group.
+    ///
+    /// Fails if the length of the encoding is invalid or if the encoded
+    /// integer is not smaller than the order of the group.
+    ///
+    /// This method is not constant time if the length of the slice is invalid.
+    fn scalar_from_canonical_bytes(bytes: &[u8]) -> Result<Self::PrimeField> {
+        let mut repr = <Self::PrimeField as PrimeField>::Repr::default();
+        let slice = repr.as_mut();
+
+        // Short-circuit on the length of the slice, not its contents.
+        if slice.len() != bytes.len() || slice.len() != Self::ORDER_BYTES.len() {
+            return Err(Error::NonCanonicalScalar.into());
+        }
+
+        slice.copy_from_slice(bytes);
+
+        // The endianness of the representation is implementation-specific,
+        // so it is determined from the representation of one.
+        if Self::PrimeField::ONE.to_repr().as_ref()[0] == 1 {
+            slice.reverse();
+        }
+
+        Option::from(Self::PrimeField::from_repr(repr)).ok_or(Error::NonCanonicalScalar.into())
+    }
 }
 
-impl<S> Suite for S
-where
-    S: FieldDigest + GroupDigest,
-    <S as GroupDigest>::Output: Group<Scalar = <S as FieldDigest>::Output> + GroupEncoding,
-{
-    type PrimeField = <S as FieldDigest>::Output;
-    type Group = <S as GroupDigest>::Output;
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use group::ff::{Field, PrimeField};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly::BivariatePolynomial, vss::VerificationMatrix};
+
+    use super::{p384, ristretto255, toy, Error, Suite, SuiteId};
+
+    fn test_scalar_from_canonical_bytes<S: Suite>() {
+        // The order is consistent with the modulus of the prime field.
+        let modulus = S::ORDER_BYTES.iter().fold(String::new(), |mut s, b| {
+            write!(s, "{:02x}", b).unwrap();
+            s
+        });
+        assert_eq!(S::PrimeField::MODULUS.trim_start_matches("0x"), modulus);
+
+        // The order itself is rejected.
+        let res = S::scalar_from_canonical_bytes(S::ORDER_BYTES);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NonCanonicalScalar.to_string()
+        );
+
+        // The order decremented by one is accepted.
+        let mut bytes = S::ORDER_BYTES.to_vec();
+        *bytes.last_mut().unwrap() -= 1;
+        let s = S::scalar_from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(s, -S::PrimeField::ONE);
+
+        // Small values and invalid lengths.
+        let mut bytes = vec![0; S::ORDER_BYTES.len()];
+        *bytes.last_mut().unwrap() = 7;
+        let s = S::scalar_from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(s, S::PrimeField::from(7));
+        assert!(S::scalar_from_canonical_bytes(&bytes[1..]).is_err());
+        assert!(S::scalar_from_canonical_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+    }
+
+    #[test]
+    fn test_scalar_from_canonical_bytes_p384() {
+        test_scalar_from_canonical_bytes::<p384::Sha3_384>();
+    }
+
+    #[test]
+    fn test_scalar_from_canonical_bytes_ristretto255() {
+        test_scalar_from_canonical_bytes::<ristretto255::Ristretto255Sha512>();
+    }
+
+    #[test]
+    fn test_scalar_from_canonical_bytes_toy() {
+        test_scalar_from_canonical_bytes::<toy::Toy64>();
+    }
+
+    fn test_generator<S: Suite>(base_point: S::Group) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // The generator is the standard base point.
+        assert_eq!(S::generator(), base_point);
+
+        // Commitments computed with the generator verify.
+        let bp = BivariatePolynomial::<S::PrimeField>::random(2, 3, &mut rng);
+        let m: Vec<Vec<_>> = (0..=2)
+            .map(|i| {
+                (0..=3)
+                    .map(|j| S::generator() * bp.coefficient(i, j).unwrap())
+                    .collect()
+            })
+            .collect();
+        let vm = VerificationMatrix::<S::Group> {
+            rows: 3,
+            cols: 4,
+            m,
+        };
+        assert!(vm.verify_commitment(&bp));
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+
+        // Commitments computed with another generator don't.
+        let other = S::generator() * S::PrimeField::from(2);
+        let m: Vec<Vec<_>> = (0..=2)
+            .map(|i| {
+                (0..=3)
+                    .map(|j| other * bp.coefficient(i, j).unwrap())
+                    .collect()
+            })
+            .collect();
+        let vm = VerificationMatrix::<S::Group> {
+            rows: 3,
+            cols: 4,
+            m,
+        };
+        assert!(!vm.verify_commitment(&bp));
+    }
+
+    #[test]
+    fn test_generator_p384() {
+        test_generator::<p384::Sha3_384>(::p384::ProjectivePoint::GENERATOR);
+    }
+
+    #[test]
+    fn test_generator_ristretto255() {
+        test_generator::<ristretto255::Ristretto255Sha512>(
+            curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT,
+        );
+    }
+
+    #[test]
+    fn test_generator_toy() {
+        test_generator::<toy::Toy64>(toy::Point::new(toy::Scalar::from(3)));
+    }
+
+    #[test]
+    fn test_suite_id_tag() {
+        for id in [SuiteId::P384Sha3_384, SuiteId::Ristretto255Sha512] {
+            assert_eq!(SuiteId::from_tag(id.tag()), Some(id));
+        }
+        assert_eq!(SuiteId::P384Sha3_384.tag(), 1);
+        assert_eq!(SuiteId::Ristretto255Sha512.tag(), 2);
+        assert_eq!(SuiteId::from_tag(0), None);
+        assert_eq!(SuiteId::from_tag(3), None);
+    }
 }
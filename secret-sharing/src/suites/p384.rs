@@ -1,35 +1,84 @@
+use core::marker::PhantomData;
+
 use anyhow::Result;
 
 use p384::{
     elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest as _},
     NistP384, ProjectivePoint, Scalar,
 };
+use sha2::digest::{
+    core_api::BlockSizeUser,
+    typenum::{IsLess, IsLessOrEqual, U256},
+    FixedOutput, HashMarker,
+};
 
-use super::{FieldDigest, GroupDigest};
+use super::{FieldDigest, GroupDigest, Suite};
 
-/// The NIST P-384 elliptic curve group with the SHA3-384 hash function used
+/// The NIST P-384 elliptic curve group with the hash function `H` used
 /// to encode arbitrary-length byte strings to elements of the underlying prime
 /// field or elliptic curve points.
-pub struct Sha3_384;
+///
+/// Byte strings are expanded using `expand_message_xmd` with `H` (RFC 9380),
+/// so any hash function supported by the expander can be used. To retain
+/// the security level of the curve, the output of `H` should be at least
+/// 384 bits long. Different hash functions yield different, unrelated
+/// encodings, so all parties must agree on the same suite.
+pub struct P384<H>(PhantomData<fn() -> H>);
+
+/// The NIST P-384 suite with the SHA3-384 hash function.
+pub type Sha3_384 = P384<sha3::Sha3_384>;
+
+/// The NIST P-384 suite with the SHA-384 hash function, as used
+/// by the `P384_XMD:SHA-384_SSWU_RO_` suite from RFC 9380.
+pub type Sha384 = P384<sha2::Sha384>;
+
+/// The NIST P-384 suite with the SHA-512 hash function.
+pub type Sha512 = P384<sha2::Sha512>;
+
+impl<H> Suite for P384<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type PrimeField = Scalar;
+    type Group = ProjectivePoint;
 
-impl GroupDigest for Sha3_384 {
+    const ORDER_BYTES: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc7, 0x63, 0x4d, 0x81, 0xf4, 0x37,
+        0x2d, 0xdf, 0x58, 0x1a, 0x0d, 0xb2, 0x48, 0xb0, 0xa7, 0x7a, 0xec, 0xec, 0x19, 0x6a, 0xcc,
+        0xc5, 0x29, 0x73,
+    ];
+}
+
+impl<H> GroupDigest for P384<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
     type Output = ProjectivePoint;
 
     fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
         let msgs = [msg];
         let dsts = [dst];
-        let p = NistP384::hash_from_bytes::<ExpandMsgXmd<sha3::Sha3_384>>(&msgs, &dsts)?;
+        let p = NistP384::hash_from_bytes::<ExpandMsgXmd<H>>(&msgs, &dsts)
+            .map_err(anyhow::Error::msg)?;
         Ok(p)
     }
 }
 
-impl FieldDigest for Sha3_384 {
+impl<H> FieldDigest for P384<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
     type Output = Scalar;
 
     fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
         let msgs = [msg];
         let dsts = [dst];
-        let s = NistP384::hash_to_scalar::<ExpandMsgXmd<sha3::Sha3_384>>(&msgs, &dsts)?;
+        let s = NistP384::hash_to_scalar::<ExpandMsgXmd<H>>(&msgs, &dsts)
+            .map_err(anyhow::Error::msg)?;
         Ok(s)
     }
 }
@@ -40,9 +89,114 @@ mod tests {
 
     use self::test::Bencher;
 
+    use group::{Group, GroupEncoding};
+    use p384::{
+        elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest as _},
+        NistP384,
+    };
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
-    use super::{FieldDigest, GroupDigest, Sha3_384};
+    use crate::churp::encode_shareholder;
+
+    use super::{FieldDigest, GroupDigest, Sha384, Sha3_384, Sha512};
+
+    #[test]
+    fn test_hash_to_curve() {
+        // Test vectors from RFC 9380, Section J.2.1 (P384_XMD:SHA-384_SSWU_RO_),
+        // in compressed form, to make sure that the map is the standard one.
+        let dst = b"QUUX-V01-CS02-with-P384_XMD:SHA-384_SSWU_RO_";
+        let test_cases: [(&[u8], [u8; 49]); 2] = [
+            (
+                b"",
+                [
+                    0x02, 0xeb, 0x9f, 0xe1, 0xb4, 0xf4, 0xe1, 0x4e, 0x71, 0x40, 0x80, 0x3c, 0x1d,
+                    0x99, 0xd0, 0xa9, 0x3c, 0xd8, 0x23, 0xd2, 0xb0, 0x24, 0x04, 0x0f, 0x9c, 0x06,
+                    0x7a, 0x8e, 0xca, 0x1f, 0x5a, 0x2e, 0xea, 0xc9, 0xad, 0x60, 0x49, 0x73, 0x52,
+                    0x7a, 0x35, 0x6f, 0x3f, 0xa3, 0xae, 0xff, 0x0e, 0x4d, 0x83,
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    0x02, 0xe0, 0x2f, 0xc1, 0xa5, 0xf4, 0x4a, 0x75, 0x19, 0x41, 0x9d, 0xd3, 0x14,
+                    0xe2, 0x98, 0x63, 0xf3, 0x0d, 0xf5, 0x5a, 0x51, 0x4d, 0xa2, 0xd6, 0x55, 0x77,
+                    0x5a, 0x81, 0xd4, 0x13, 0x00, 0x3c, 0x4d, 0x4e, 0x7f, 0xd5, 0x9a, 0xf0, 0x82,
+                    0x6d, 0xfa, 0xad, 0x42, 0x00, 0xac, 0x6f, 0x60, 0xab, 0xe1,
+                ],
+            ),
+        ];
+
+        for (msg, expected) in test_cases {
+            let p =
+                NistP384::hash_from_bytes::<ExpandMsgXmd<sha2::Sha384>>(&[msg], &[dst]).unwrap();
+            assert_eq!(p.to_bytes().as_slice(), &expected);
+
+            // The suite with SHA-384 is the standard one.
+            let p = Sha384::hash_to_group(msg, dst).unwrap();
+            assert_eq!(p.to_bytes().as_slice(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_digest_choices() {
+        let dst = b"shareholder";
+        let id = [1u8; 32];
+        let xs = [
+            encode_shareholder::<Sha3_384>(&id, dst).unwrap(),
+            encode_shareholder::<Sha384>(&id, dst).unwrap(),
+            encode_shareholder::<Sha512>(&id, dst).unwrap(),
+        ];
+
+        for (i, x) in xs.iter().enumerate() {
+            // Encodings are deterministic and non-zero.
+            assert!(!bool::from(x.is_zero()));
+            for y in &xs[i + 1..] {
+                assert_ne!(x, y);
+            }
+        }
+        assert_eq!(xs[0], encode_shareholder::<Sha3_384>(&id, dst).unwrap());
+        assert_eq!(xs[1], encode_shareholder::<Sha384>(&id, dst).unwrap());
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        // Outputs computed independently using SHA3-384 in place of SHA-384.
+        let dst = b"dst";
+        let test_cases: [(&[u8], [u8; 49]); 2] = [
+            (
+                b"",
+                [
+                    0x02, 0x0d, 0x5f, 0x61, 0x99, 0xf3, 0x3c, 0xd0, 0xd6, 0xf2, 0xfe, 0xa3, 0xf6,
+                    0x2a, 0x47, 0xcd, 0xf4, 0x1a, 0x1f, 0x18, 0x00, 0x77, 0x40, 0x65, 0xba, 0x77,
+                    0x60, 0x70, 0x8d, 0xd0, 0xc7, 0xbb, 0x84, 0xaf, 0x26, 0x7a, 0x85, 0x23, 0x08,
+                    0xbb, 0xa2, 0x59, 0xc7, 0x00, 0x6b, 0x38, 0x20, 0x6c, 0xbd,
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    0x03, 0xd3, 0x27, 0x95, 0xa6, 0x03, 0x13, 0xa8, 0x00, 0xeb, 0x09, 0xa8, 0x66,
+                    0xde, 0xaf, 0xb6, 0xb3, 0x69, 0x62, 0x0f, 0xd2, 0x2d, 0x7a, 0xcd, 0xe5, 0x2b,
+                    0x63, 0xb4, 0x73, 0x7a, 0xcc, 0xce, 0x32, 0xa6, 0xcb, 0x39, 0x6c, 0x17, 0x11,
+                    0xb0, 0x8e, 0xc6, 0xae, 0x14, 0x11, 0x12, 0x02, 0xea, 0x17,
+                ],
+            ),
+        ];
+
+        for (msg, expected) in test_cases {
+            let p = Sha3_384::hash_to_group(msg, dst).unwrap();
+            assert_eq!(p.to_bytes().as_slice(), &expected);
+        }
+
+        // The output is never the identity element.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 32];
+        for _ in 0..100 {
+            rng.fill_bytes(&mut data);
+            let p = Sha3_384::hash_to_group(&data, dst).unwrap();
+            assert!(!bool::from(p.is_identity()));
+        }
+    }
 
     #[bench]
     fn bench_hash_to_field_p384_sha3_384(b: &mut Bencher) {
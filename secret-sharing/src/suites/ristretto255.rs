@@ -0,0 +1,249 @@
+use core::marker::PhantomData;
+
+use anyhow::Result;
+
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use p384::elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
+use sha2::digest::{
+    core_api::BlockSizeUser,
+    typenum::{IsLess, IsLessOrEqual, U256},
+    FixedOutput, HashMarker,
+};
+
+use super::{FieldDigest, GroupDigest, Suite};
+
+/// The Ristretto255 group with the hash function `H` used to encode
+/// arbitrary-length byte strings to elements of the underlying prime field
+/// or group elements.
+///
+/// Byte strings are expanded to 64 uniformly random bytes using
+/// `expand_message_xmd` with `H` (RFC 9380), which are then reduced
+/// modulo the group order or mapped to a group element (RFC 9496).
+/// To retain the security level of the group, the output of `H` should be
+/// at least 256 bits long. Different hash functions yield different,
+/// unrelated encodings, so all parties must agree on the same suite.
+pub struct Ristretto255<H>(PhantomData<fn() -> H>);
+
+/// The Ristretto255 suite with the SHA-512 hash function.
+pub type Ristretto255Sha512 = Ristretto255<sha2::Sha512>;
+
+/// The Ristretto255 suite with the SHA-256 hash function.
+pub type Ristretto255Sha256 = Ristretto255<sha2::Sha256>;
+
+/// The Ristretto255 suite with the SHA3-512 hash function.
+pub type Ristretto255Sha3_512 = Ristretto255<sha3::Sha3_512>;
+
+impl<H> Ristretto255<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    /// Expands the given message to 64 uniformly random bytes using
+    /// the given domain separation tag.
+    fn expand_message(msg: &[u8], dst: &[u8]) -> Result<[u8; 64]> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let mut expander =
+            ExpandMsgXmd::<H>::expand_message(&msgs, &dsts, 64).map_err(anyhow::Error::msg)?;
+
+        let mut bytes = [0u8; 64];
+        expander.fill_bytes(&mut bytes);
+
+        Ok(bytes)
+    }
+}
+
+impl<H> Suite for Ristretto255<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type PrimeField = Scalar;
+    type Group = RistrettoPoint;
+
+    const ORDER_BYTES: &'static [u8] = &[
+        0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x14, 0xde, 0xf9, 0xde, 0xa2, 0xf7, 0x9c, 0xd6, 0x58, 0x12, 0x63, 0x1a, 0x5c, 0xf5,
+        0xd3, 0xed,
+    ];
+}
+
+impl<H> GroupDigest for Ristretto255<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type Output = RistrettoPoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let bytes = Self::expand_message(msg, dst)?;
+        let p = RistrettoPoint::from_uniform_bytes(&bytes);
+        Ok(p)
+    }
+}
+
+impl<H> FieldDigest for Ristretto255<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let bytes = Self::expand_message(msg, dst)?;
+        let s = Scalar::from_bytes_mod_order_wide(&bytes);
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group, GroupEncoding};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::churp::{encode_shareholder, Dealer, HandoffKind, VerifiableSecretShare};
+
+    use super::{
+        FieldDigest, GroupDigest, Ristretto255Sha256, Ristretto255Sha3_512, Ristretto255Sha512,
+    };
+
+    #[test]
+    fn test_expand_message() {
+        // Same domain separation tag and message as the test vectors
+        // from RFC 9380, Section K.3 (expand_message_xmd, SHA-512),
+        // with the output computed independently for 64 bytes.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let bytes = Ristretto255Sha512::expand_message(b"", dst).unwrap();
+        let expected = [
+            0xbb, 0x1e, 0xdd, 0x5e, 0xb9, 0xd2, 0x01, 0x3b, 0xa7, 0x6c, 0x24, 0x41, 0x0c, 0x8f,
+            0x54, 0x23, 0x2f, 0xd2, 0x58, 0xcd, 0xb0, 0x88, 0xd5, 0x4b, 0x1b, 0x39, 0x23, 0xf7,
+            0xde, 0xba, 0x03, 0x5a, 0x10, 0xd9, 0xee, 0xe7, 0x46, 0xed, 0xc2, 0xc6, 0x61, 0x8b,
+            0xa4, 0x88, 0x77, 0xd6, 0xa1, 0x02, 0xac, 0x85, 0x0f, 0x9d, 0xde, 0x8d, 0x78, 0xd9,
+            0x68, 0xab, 0xc9, 0xdc, 0x56, 0x58, 0xd8, 0x51,
+        ];
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_encode_shareholder() {
+        let dst = b"shareholder";
+        let id = [1u8; 32];
+        let x = encode_shareholder::<Ristretto255Sha512>(&id, dst).unwrap();
+        let expected = [
+            0xa4, 0xe6, 0xfd, 0xdf, 0xdf, 0x76, 0x2b, 0xac, 0x92, 0xf3, 0x17, 0x41, 0xcd, 0x47,
+            0x53, 0x9b, 0x3b, 0x09, 0x7d, 0x53, 0xb4, 0x34, 0x9c, 0x92, 0x55, 0x6b, 0x3c, 0x2d,
+            0x74, 0xd5, 0xcc, 0x07,
+        ];
+        assert_eq!(x.to_bytes(), expected);
+
+        // Different domain separation tags yield different encodings.
+        let y = encode_shareholder::<Ristretto255Sha512>(&id, b"other").unwrap();
+        assert_ne!(x, y);
+    }
+
+    #[test]
+    fn test_digest_choices() {
+        let dst = b"shareholder";
+        let id = [1u8; 32];
+        let xs = [
+            encode_shareholder::<Ristretto255Sha512>(&id, dst).unwrap(),
+            encode_shareholder::<Ristretto255Sha256>(&id, dst).unwrap(),
+            encode_shareholder::<Ristretto255Sha3_512>(&id, dst).unwrap(),
+        ];
+
+        for (i, x) in xs.iter().enumerate() {
+            // Encodings are deterministic and non-zero.
+            assert!(!bool::from(x.is_zero()));
+            for y in &xs[i + 1..] {
+                assert_ne!(x, y);
+            }
+        }
+        assert_eq!(
+            xs[1],
+            encode_shareholder::<Ristretto255Sha256>(&id, dst).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        let dst = b"group";
+        let p = Ristretto255Sha512::hash_to_group(b"msg", dst).unwrap();
+        let q = Ristretto255Sha512::hash_to_group(b"msg", dst).unwrap();
+        assert_eq!(p.to_bytes(), q.to_bytes());
+        let q = Ristretto255Sha512::hash_to_group(b"other", dst).unwrap();
+        assert_ne!(p.to_bytes(), q.to_bytes());
+
+        // Outputs computed independently from the expanded message
+        // using the one-way map from RFC 9496, Section 4.3.4.
+        let dst = b"dst";
+        let test_cases: [(&[u8], [u8; 32]); 2] = [
+            (
+                b"",
+                [
+                    0xda, 0x0e, 0x62, 0xa2, 0x60, 0x24, 0xb8, 0x70, 0x40, 0x65, 0x8e, 0x36, 0xf8,
+                    0xa7, 0x9a, 0x4f, 0xa2, 0xf7, 0x7c, 0xf3, 0x1e, 0xce, 0x9c, 0xe3, 0x86, 0xd9,
+                    0xe9, 0x55, 0x23, 0xcf, 0x7a, 0x6b,
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    0x4c, 0x8b, 0xfc, 0x7a, 0x3b, 0xd2, 0xa1, 0x44, 0xe0, 0xcf, 0x02, 0x2c, 0x73,
+                    0x61, 0xef, 0x5e, 0x67, 0x7b, 0xba, 0xe2, 0xd7, 0x74, 0x25, 0xe0, 0x60, 0xd5,
+                    0xb1, 0xe8, 0x7b, 0xe6, 0x3b, 0x7f,
+                ],
+            ),
+        ];
+
+        for (msg, expected) in test_cases {
+            let p = Ristretto255Sha512::hash_to_group(msg, dst).unwrap();
+            assert_eq!(p.to_bytes(), expected);
+        }
+
+        // The output is never the identity element.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 32];
+        for _ in 0..100 {
+            rng.fill_bytes(&mut data);
+            let p = Ristretto255Sha512::hash_to_group(&data, dst).unwrap();
+            assert!(!bool::from(p.is_identity()));
+        }
+    }
+
+    #[test]
+    fn test_dealer() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::<curve25519_dalek::RistrettoPoint>::new(2, &mut rng).unwrap();
+        let x = encode_shareholder::<Ristretto255Sha512>(&[1u8; 32], b"shareholder").unwrap();
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+        let verifiable_share = VerifiableSecretShare::new(share, vm);
+        assert!(verifiable_share.verify(2, false, true).is_ok());
+    }
+
+    #[bench]
+    fn bench_hash_to_field_ristretto255_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Ristretto255Sha512::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_ristretto255_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Ristretto255Sha512::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
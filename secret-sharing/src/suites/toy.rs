@@ -0,0 +1,519 @@
+//! A toy suite over a 64-bit prime field for fast, hand-checkable tests.
+//!
+//! The suite is insecure and must never be used outside of tests.
+
+use core::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use anyhow::Result;
+use group::{
+    ff::{
+        helpers::{sqrt_ratio_generic, sqrt_tonelli_shanks},
+        Field, PrimeField,
+    },
+    Group, GroupEncoding,
+};
+use p384::elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use super::{FieldDigest, GroupDigest, Suite};
+
+/// The Goldilocks prime `2^64 - 2^32 + 1`, i.e. the order of the toy group.
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// The value `(t - 1) / 2`, where `t = (MODULUS - 1) / 2^S` is odd.
+const T_MINUS_ONE_DIV_TWO: u64 = 0x7fff_ffff;
+
+/// Element of the prime field modulo the Goldilocks prime.
+///
+/// Arithmetic is implemented on native integers and is not constant time.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Scalar(u64);
+
+impl Scalar {
+    /// Reduces the given integer modulo the order of the field.
+    const fn reduce(v: u128) -> Self {
+        Self((v % MODULUS as u128) as u64)
+    }
+
+    /// Returns the canonical integer representation of the element.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        Self::reduce(a.0 as u128 + b.0 as u128)
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        Self::reduce(a.0 as u128 + MODULUS as u128 - b.0 as u128)
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        Self::reduce(a.0 as u128 * b.0 as u128)
+    }
+}
+
+/// Element of the toy group, i.e. the additive group of the prime field
+/// generated by three.
+///
+/// Discrete logarithms are trivial, as the element `k * G` is represented
+/// by the integer `3k` modulo the order of the group, which makes
+/// commitments easy to check by hand.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Point(Scalar);
+
+impl Point {
+    /// Creates the group element represented by the given field element.
+    pub fn new(s: Scalar) -> Self {
+        Self(s)
+    }
+
+    /// Returns the field element representing the group element.
+    pub fn value(&self) -> &Scalar {
+        &self.0
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        Self(Scalar::add(&a.0, &b.0))
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        Self(Scalar::sub(&a.0, &b.0))
+    }
+
+    fn mul(a: &Self, b: &Scalar) -> Self {
+        Self(Scalar::mul(&a.0, b))
+    }
+}
+
+/// Implements a binary operator and its assigning variant for owned
+/// and borrowed right-hand sides using the given function.
+macro_rules! impl_binop {
+    ($lhs:ty, $rhs:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $f:path) => {
+        impl $trait<$rhs> for $lhs {
+            type Output = $lhs;
+
+            fn $method(self, rhs: $rhs) -> $lhs {
+                $f(&self, &rhs)
+            }
+        }
+
+        impl<'a> $trait<&'a $rhs> for $lhs {
+            type Output = $lhs;
+
+            fn $method(self, rhs: &'a $rhs) -> $lhs {
+                $f(&self, rhs)
+            }
+        }
+
+        impl $assign_trait<$rhs> for $lhs {
+            fn $assign_method(&mut self, rhs: $rhs) {
+                *self = $f(self, &rhs);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a $rhs> for $lhs {
+            fn $assign_method(&mut self, rhs: &'a $rhs) {
+                *self = $f(self, rhs);
+            }
+        }
+    };
+}
+
+impl_binop!(Scalar, Scalar, Add, add, AddAssign, add_assign, Scalar::add);
+impl_binop!(Scalar, Scalar, Sub, sub, SubAssign, sub_assign, Scalar::sub);
+impl_binop!(Scalar, Scalar, Mul, mul, MulAssign, mul_assign, Scalar::mul);
+impl_binop!(Point, Point, Add, add, AddAssign, add_assign, Point::add);
+impl_binop!(Point, Point, Sub, sub, SubAssign, sub_assign, Point::sub);
+impl_binop!(Point, Scalar, Mul, mul, MulAssign, mul_assign, Point::mul);
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::sub(&Self::ZERO, &self)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Sum for Scalar {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, s| acc + s)
+    }
+}
+
+impl<'a> Sum<&'a Scalar> for Scalar {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, s| acc + s)
+    }
+}
+
+impl Product for Scalar {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, s| acc * s)
+    }
+}
+
+impl<'a> Product<&'a Scalar> for Scalar {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, s| acc * s)
+    }
+}
+
+impl Sum for Point {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, p| acc + p)
+    }
+}
+
+impl<'a> Sum<&'a Point> for Point {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, p| acc + p)
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(u64::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConditionallySelectable for Point {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(Scalar::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scalar({})", self.0)
+    }
+}
+
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Point({})", self.0 .0)
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(v: u64) -> Self {
+        Self::reduce(v as u128)
+    }
+}
+
+impl Field for Scalar {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let hi = rng.next_u64() as u128;
+        let lo = rng.next_u64() as u128;
+        Self::reduce(hi << 64 | lo)
+    }
+
+    fn square(&self) -> Self {
+        Self::mul(self, self)
+    }
+
+    fn double(&self) -> Self {
+        Self::add(self, self)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        CtOption::new(self.pow_vartime([MODULUS - 2]), !self.is_zero())
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        sqrt_ratio_generic(num, div)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        sqrt_tonelli_shanks(self, [T_MINUS_ONE_DIV_TWO])
+    }
+}
+
+impl PrimeField for Scalar {
+    /// The little-endian representation of the canonical integer.
+    type Repr = [u8; 8];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let v = u64::from_le_bytes(repr);
+        CtOption::new(Self(v), Choice::from((v < MODULUS) as u8))
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.0.to_le_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.0 & 1) as u8)
+    }
+
+    const MODULUS: &'static str = "0xffffffff00000001";
+    const NUM_BITS: u32 = 64;
+    const CAPACITY: u32 = 63;
+    const TWO_INV: Self = Self(0x7fff_ffff_8000_0001);
+    const MULTIPLICATIVE_GENERATOR: Self = Self(7);
+    const S: u32 = 32;
+    const ROOT_OF_UNITY: Self = Self(0x1856_29dc_da58_878c);
+    const ROOT_OF_UNITY_INV: Self = Self(0x76b6_b635_b6fc_8719);
+    const DELTA: Self = Self(0xaa5b_2509_f86b_b4d4);
+}
+
+impl Group for Point {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self(Scalar::random(rng))
+    }
+
+    fn identity() -> Self {
+        Self(Scalar::ZERO)
+    }
+
+    fn generator() -> Self {
+        Self(Scalar(3))
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+impl GroupEncoding for Point {
+    /// The representation of the field element representing the group
+    /// element.
+    type Repr = [u8; 8];
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Scalar::from_repr(*bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_repr()
+    }
+}
+
+/// The toy suite.
+///
+/// Byte strings are expanded to 16 uniformly random bytes using
+/// `expand_message_xmd` with SHA-256 (RFC 9380), which are then reduced
+/// modulo the order of the group. Group elements are derived by multiplying
+/// the generator with the resulting field element, so their discrete
+/// logarithms are known.
+pub struct Toy64;
+
+impl Toy64 {
+    /// Expands the given message to 16 uniformly random bytes using
+    /// the given domain separation tag.
+    fn expand_message(msg: &[u8], dst: &[u8]) -> Result<[u8; 16]> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let mut expander = ExpandMsgXmd::<sha2::Sha256>::expand_message(&msgs, &dsts, 16)
+            .map_err(anyhow::Error::msg)?;
+
+        let mut bytes = [0u8; 16];
+        expander.fill_bytes(&mut bytes);
+
+        Ok(bytes)
+    }
+}
+
+impl Suite for Toy64 {
+    type PrimeField = Scalar;
+    type Group = Point;
+
+    const ORDER_BYTES: &'static [u8] = &[0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01];
+}
+
+impl GroupDigest for Toy64 {
+    type Output = Point;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let s = Self::hash_to_field(msg, dst)?;
+        Ok(Point::generator() * s)
+    }
+}
+
+impl FieldDigest for Toy64 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let bytes = Self::expand_message(msg, dst)?;
+        Ok(Scalar::reduce(u128::from_be_bytes(bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{
+        ff::{Field, PrimeField},
+        Group, GroupEncoding,
+    };
+
+    use crate::{
+        churp::{combine_key_shares, Dealer, HandoffKind, Player, Shareholder},
+        poly::BivariatePolynomial,
+    };
+
+    use super::{FieldDigest, GroupDigest, Point, Scalar, Toy64, MODULUS};
+
+    fn scalar(v: u64) -> Scalar {
+        Scalar::from(v)
+    }
+
+    fn point(v: u64) -> Point {
+        Point::new(scalar(v))
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!(scalar(MODULUS), Scalar::ZERO);
+        assert_eq!(-Scalar::ONE, scalar(MODULUS - 1));
+        assert_eq!(scalar(2) - scalar(5), -scalar(3));
+        assert_eq!(scalar(6) * scalar(7), scalar(42));
+        assert_eq!(scalar(MODULUS - 1) * scalar(MODULUS - 1), Scalar::ONE);
+        assert_eq!(scalar(2).invert().unwrap(), Scalar::TWO_INV);
+        assert_eq!(scalar(3).invert().unwrap() * scalar(3), Scalar::ONE);
+        assert!(bool::from(Scalar::ZERO.invert().is_none()));
+
+        let root = scalar(16).sqrt().unwrap();
+        assert!(root == scalar(4) || root == -scalar(4));
+        assert!(bool::from(
+            Scalar::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+
+        // The constants are consistent.
+        let s = 1u64 << Scalar::S;
+        assert_eq!(Scalar::ROOT_OF_UNITY.pow_vartime([s]), Scalar::ONE);
+        assert_ne!(Scalar::ROOT_OF_UNITY.pow_vartime([s / 2]), Scalar::ONE);
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV,
+            Scalar::ONE
+        );
+        assert_eq!(
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime([MODULUS >> Scalar::S]),
+            Scalar::ROOT_OF_UNITY
+        );
+        assert_eq!(
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime([s]),
+            Scalar::DELTA
+        );
+    }
+
+    #[test]
+    fn test_encoding() {
+        let s = scalar(0x0102);
+        assert_eq!(s.to_repr(), [2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Scalar::from_repr(s.to_repr()).unwrap(), s);
+        assert!(bool::from(
+            Scalar::from_repr(MODULUS.to_le_bytes()).is_none()
+        ));
+
+        let p = point(0x0102);
+        assert_eq!(p.to_bytes(), [2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Point::from_bytes(&p.to_bytes()).unwrap(), p);
+        assert!(bool::from(
+            Point::from_bytes(&MODULUS.to_le_bytes()).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_digests() {
+        let s = Toy64::hash_to_field(b"msg", b"dst").unwrap();
+        assert_eq!(s, Toy64::hash_to_field(b"msg", b"dst").unwrap());
+        assert_ne!(s, Toy64::hash_to_field(b"msg", b"other dst").unwrap());
+        assert_eq!(
+            Toy64::hash_to_group(b"msg", b"dst").unwrap(),
+            Point::generator() * s
+        );
+    }
+
+    #[test]
+    fn test_reconstruction() {
+        // B(x,y) = 7 + 2y + y^2 + 3x + 5xy + 4xy^2, so the secret is 7.
+        let bp = BivariatePolynomial::with_coefficients(vec![
+            vec![scalar(7), scalar(2), scalar(1)],
+            vec![scalar(3), scalar(5), scalar(4)],
+        ]);
+        let dealer = Dealer::<Point>::from(bp);
+        let threshold = 1;
+
+        // The verification matrix holds three times the coefficients.
+        let vm = dealer.verification_matrix();
+        let expected = [[21, 6, 3], [9, 15, 12]];
+        for (i, row) in expected.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                assert_eq!(vm.element(i, j), Some(&point(*v)));
+            }
+        }
+
+        // Full shares B(1,y) = 10 + 7y + 5y^2 and B(2,y) = 13 + 12y + 9y^2.
+        let shares = dealer.make_shares(vec![scalar(1), scalar(2)], HandoffKind::DealingPhase);
+        let expected = [[10, 7, 5], [13, 12, 9]];
+        for (share, coefficients) in shares.iter().zip(expected.iter()) {
+            let a: Vec<_> = coefficients.iter().copied().map(scalar).collect();
+            assert_eq!(share.polynomial().coefficients(), &a[..]);
+        }
+
+        // The line through (1,10) and (2,13) meets the y-axis at 2*10 - 13 = 7.
+        let player = Player::new(threshold, HandoffKind::DealingPhase);
+        let secret = player.recover_secret(&shares).unwrap();
+        assert_eq!(secret, scalar(7));
+
+        // Switch points B(1,3) = 76 and B(2,3) = 130 for the shareholder 3,
+        // whose reduced share is B(0,3) = 2*76 - 130 = 22.
+        let shareholders: Vec<_> = shares
+            .into_iter()
+            .map(|share| Shareholder::new(share, vm.clone()))
+            .collect();
+        let points: Vec<_> = shareholders
+            .iter()
+            .map(|sh| sh.switch_point(&scalar(3)))
+            .collect();
+        assert_eq!(points, vec![scalar(76), scalar(130)]);
+        assert!(vm.verify(&scalar(1), &scalar(3), &scalar(76)));
+        assert_eq!(scalar(2) * points[0] - points[1], scalar(22));
+
+        // Key shares 10 * H and 13 * H combine to 7 * H.
+        let hash = point(5);
+        let key_shares: Vec<_> = shareholders
+            .iter()
+            .map(|sh| {
+                let x = *sh.verifiable_share().secret_share().coordinate_x();
+                (x, sh.key_share(hash).unwrap())
+            })
+            .collect();
+        assert_eq!(key_shares[0].1, point(50));
+        assert_eq!(key_shares[1].1, point(65));
+        let key = combine_key_shares::<Point>(&key_shares, threshold as usize).unwrap();
+        assert_eq!(key, point(35));
+    }
+}
@@ -0,0 +1,18 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("declared size too large")]
+    DeclaredSizeTooLarge,
+    #[error("invalid encoding")]
+    InvalidEncoding,
+    #[error("invalid point encoding")]
+    InvalidPointEncoding,
+    #[error("matrix index out of bounds")]
+    MatrixIndexOutOfBounds,
+    #[error("no verification matrices")]
+    NoVerificationMatrices,
+    #[error("verification matrix dimension mismatch: expected {expected:?}, got {actual:?}")]
+    VerificationMatrixDimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+}
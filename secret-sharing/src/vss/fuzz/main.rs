@@ -14,7 +14,7 @@ fn main() {
 }
 
 fn fuzz_verification_matrix_random(data: &[u8]) {
-    VerificationMatrix::<p384::ProjectivePoint>::from_bytes(data);
+    let _ = VerificationMatrix::<p384::ProjectivePoint>::from_bytes(data);
 }
 
 fn fuzz_verification_matrix_from_seed(data: &[u8]) {
@@ -1,11 +1,32 @@
-use std::{cmp::max, ops::Add};
-
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::max,
+    iter::zip,
+    ops::{Add, AddAssign},
+};
+#[cfg(feature = "std")]
+use std::io::Read;
+
+use anyhow::Result;
 use group::{Group, GroupEncoding};
-use subtle::Choice;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{
+    poly::{powers, BivariatePolynomial, Polynomial},
+    suites::FieldDigest,
+};
+
+use super::{msm::msm, Error, VerificationVector};
 
-use crate::poly::{powers, BivariatePolynomial, Polynomial};
+/// The domain separation tag used to derive transcript challenges
+/// from verification matrices.
+const TRANSCRIPT_CHALLENGE_DST: &[u8] = b"oasis-core/secret-sharing: verification matrix challenge";
 
-use super::VerificationVector;
+/// The maximum number of rows and columns that fit into the one-byte
+/// degrees of the byte representation, see [`VerificationMatrix::to_bytes`].
+const MAX_ENCODED_DIMENSION: usize = u8::MAX as usize + 1;
 
 /// Verification matrix for a bivariate polynomial.
 ///
@@ -44,6 +65,157 @@ impl<G> VerificationMatrix<G>
 where
     G: Group + GroupEncoding,
 {
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial.
+    ///
+    /// This is equivalent to converting the polynomial using [`From`].
+    pub fn from_polynomial(bp: &BivariatePolynomial<G::Scalar>) -> Self {
+        Self::from(bp)
+    }
+
+    /// Constructs a verification matrix of the given dimensions with all
+    /// elements set to the identity element of the group, i.e. the matrix
+    /// of the zero bivariate polynomial, which is zero-hole.
+    ///
+    /// Fails if any of the dimensions exceeds those that fit into the byte
+    /// representation, so that the matrix can always be encoded.
+    ///
+    /// Panics if any of the dimensions is zero.
+    pub fn zero_hole(dimensions: (usize, usize)) -> Result<Self> {
+        let (rows, cols) = dimensions;
+        assert!(rows > 0 && cols > 0, "dimensions should be non-zero");
+        Self::check_encodable(dimensions)?;
+
+        let m = vec![vec![G::identity(); cols]; rows];
+        Ok(Self { rows, cols, m })
+    }
+
+    /// Returns `a` if `choice` is zero and `b` if `choice` is one,
+    /// selecting each element in constant time.
+    ///
+    /// Panics if the matrices are of different dimensions.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self
+    where
+        G: ConditionallySelectable,
+    {
+        assert_eq!(a.dimensions(), b.dimensions(), "dimensions should match");
+
+        let m = zip(&a.m, &b.m)
+            .map(|(ai, bi)| {
+                zip(ai, bi)
+                    .map(|(aij, bij)| G::conditional_select(aij, bij, choice))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            rows: a.rows,
+            cols: a.cols,
+            m,
+        }
+    }
+
+    /// Sets the element `M_{0,0}` to the identity element of the group,
+    /// effectively creating a zero-hole verification matrix.
+    ///
+    /// The matrix then commits to the bivariate polynomial with the constant
+    /// term set to zero, see [`BivariatePolynomial::to_zero_hole`].
+    pub fn make_zero_hole(&mut self) {
+        self.m[0][0] = G::identity();
+    }
+
+    /// Updates the element `M_{i,j}` after the coefficient `b_{i,j}` of the
+    /// committed bivariate polynomial changed from the old to the new value,
+    /// by adding `(new - old) * G` to it.
+    ///
+    /// This avoids recomputing the whole matrix when a single coefficient
+    /// is revised. The old coefficient is not verified, so passing a wrong
+    /// value corrupts the matrix.
+    pub fn update_entry(
+        &mut self,
+        row: usize,
+        col: usize,
+        old_coeff: &G::Scalar,
+        new_coeff: &G::Scalar,
+    ) -> Result<()> {
+        let mij = self
+            .m
+            .get_mut(row)
+            .and_then(|mi| mi.get_mut(col))
+            .ok_or(Error::MatrixIndexOutOfBounds)?;
+        *mij += G::generator() * (*new_coeff - old_coeff);
+
+        Ok(())
+    }
+
+    /// Returns the sum of the given verification matrices, i.e. the
+    /// verification matrix of the sum of the committed bivariate polynomials.
+    ///
+    /// Unlike addition, which pads smaller matrices with identity elements,
+    /// all matrices must have the same dimensions. The sum of zero-hole
+    /// matrices is zero-hole as well.
+    pub fn sum(matrices: &[VerificationMatrix<G>]) -> Result<Self> {
+        let (first, rest) = matrices
+            .split_first()
+            .ok_or(Error::NoVerificationMatrices)?;
+
+        let mut sum = first.clone();
+        for vm in rest {
+            if vm.dimensions() != sum.dimensions() {
+                return Err(Error::VerificationMatrixDimensionMismatch {
+                    expected: sum.dimensions(),
+                    actual: vm.dimensions(),
+                }
+                .into());
+            }
+
+            for (si, mi) in sum.m.iter_mut().zip(&vm.m) {
+                for (sij, mij) in si.iter_mut().zip(mi) {
+                    *sij += mij;
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+
+    /// Returns the linear combination `\sum_k c_k * M_k` of the given
+    /// verification matrices, i.e. the verification matrix of the linear
+    /// combination of the committed bivariate polynomials.
+    ///
+    /// All matrices must have the same dimensions. This method is not
+    /// constant time, so the scalars must not be secret.
+    pub fn linear_combination(terms: &[(G::Scalar, &VerificationMatrix<G>)]) -> Result<Self> {
+        let (_, first) = terms.first().ok_or(Error::NoVerificationMatrices)?;
+        let (rows, cols) = first.dimensions();
+
+        for (_, vm) in terms {
+            if vm.dimensions() != (rows, cols) {
+                return Err(Error::VerificationMatrixDimensionMismatch {
+                    expected: (rows, cols),
+                    actual: vm.dimensions(),
+                }
+                .into());
+            }
+        }
+
+        let scalars: Vec<_> = terms.iter().map(|(c, _)| *c).collect();
+        let mut elements = Vec::with_capacity(terms.len());
+        let m = (0..rows)
+            .map(|i| {
+                (0..cols)
+                    .map(|j| {
+                        elements.clear();
+                        elements.extend(terms.iter().map(|(_, vm)| vm.m[i][j]));
+                        msm(&scalars, &elements)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self { rows, cols, m })
+    }
+
     /// Returns the dimensions (number of rows and columns) of the verification
     /// matrix.
     pub fn dimensions(&self) -> (usize, usize) {
@@ -55,6 +227,99 @@ where
         self.m.get(i).and_then(|bi| bi.get(j))
     }
 
+    /// Returns the element in the given row and column of the verification
+    /// matrix, or none if the indices are out of bounds.
+    ///
+    /// This is an alias for [`Self::element`].
+    pub fn get(&self, row: usize, col: usize) -> Option<&G> {
+        self.element(row, col)
+    }
+
+    /// Returns an iterator over the elements of the verification matrix
+    /// together with their row and column indices, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &G)> {
+        self.m
+            .iter()
+            .enumerate()
+            .flat_map(|(i, mi)| mi.iter().enumerate().map(move |(j, mij)| ((i, j), mij)))
+    }
+
+    /// Returns the transpose of the verification matrix, i.e., the verification
+    /// matrix of the bivariate polynomial `B(y,x)`.
+    ///
+    /// The element `M_{0,0}` stays in place, so the transpose of a zero-hole
+    /// matrix is zero-hole as well.
+    pub fn transpose(&self) -> Self {
+        let m = (0..self.cols).map(|j| self.column(j)).collect();
+
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            m,
+        }
+    }
+
+    /// Splits the verification matrix into the rows above the given row
+    /// and the remaining rows, e.g. to gossip a large matrix in chunks.
+    ///
+    /// The element `M_{0,0}` stays in the top chunk, so the top chunk
+    /// of a zero-hole matrix is zero-hole as well.
+    ///
+    /// Both chunks must have at least one row.
+    pub fn split_rows(&self, at: usize) -> Result<(Self, Self)> {
+        if at == 0 || at >= self.rows {
+            return Err(Error::MatrixIndexOutOfBounds.into());
+        }
+
+        let (top, bottom) = self.m.split_at(at);
+        let top = Self {
+            rows: at,
+            cols: self.cols,
+            m: top.to_vec(),
+        };
+        let bottom = Self {
+            rows: self.rows - at,
+            cols: self.cols,
+            m: bottom.to_vec(),
+        };
+
+        Ok((top, bottom))
+    }
+
+    /// Concatenates the rows of the given verification matrices,
+    /// reassembling a matrix split by [`Self::split_rows`].
+    ///
+    /// Both matrices must have the same number of columns, and the combined
+    /// number of rows must fit into the byte representation.
+    pub fn concat_rows(top: &Self, bottom: &Self) -> Result<Self> {
+        if top.cols != bottom.cols {
+            return Err(Error::VerificationMatrixDimensionMismatch {
+                expected: (bottom.rows, top.cols),
+                actual: bottom.dimensions(),
+            }
+            .into());
+        }
+
+        let rows = top
+            .rows
+            .checked_add(bottom.rows)
+            .ok_or(Error::DeclaredSizeTooLarge)?;
+        Self::check_encodable((rows, top.cols))?;
+
+        let m = top.m.iter().chain(&bottom.m).cloned().collect();
+
+        Ok(Self {
+            rows,
+            cols: top.cols,
+            m,
+        })
+    }
+
+    /// Returns the elements of the `j`-th column of the verification matrix.
+    fn column(&self, j: usize) -> Vec<G> {
+        self.m.iter().map(|mi| mi[j]).collect()
+    }
+
     /// Returns true if and only if `M_{0,0}` is the identity element
     /// of the group.
     pub fn is_zero_hole(&self) -> bool {
@@ -64,14 +329,18 @@ where
     /// Verifies whether the underlying bivariate polynomial evaluates
     /// to the given value, i.e., if it holds `B(x,y) == v`.
     pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
-        let mut diff = G::generator().neg() * v;
         let xpows = powers(x, self.rows - 1); // [x^i]
         let ypows = powers(y, self.cols - 1); // [y^j]
-        for (i, xpow) in xpows.into_iter().enumerate() {
-            for (j, ypow) in ypows.iter().enumerate() {
-                diff += self.m[i][j] * (xpow * ypow); // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+        let mut scalars = Vec::with_capacity(self.rows * self.cols);
+        for xpow in xpows {
+            for ypow in ypows.iter() {
+                scalars.push(xpow * ypow); // x^i * y^j
             }
         }
+        let elements: Vec<G> = self.m.iter().flatten().copied().collect();
+
+        // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+        let diff = msm(&scalars, &elements) - G::generator() * v;
 
         diff.is_identity().into()
     }
@@ -83,10 +352,7 @@ where
         let mut v = Vec::with_capacity(self.rows);
         let ypows = powers(y, self.cols - 1); // [y^i]
         for i in 0..self.rows {
-            let mut vi = G::identity();
-            for (j, ypow) in ypows.iter().enumerate() {
-                vi += self.m[i][j] * ypow;
-            }
+            let vi = msm(&ypows, &self.m[i]); // \sum_j y^j * M_{i,j}
             v.push(vi);
         }
 
@@ -100,16 +366,33 @@ where
         let mut v = Vec::with_capacity(self.cols);
         let xpows = powers(x, self.rows - 1); // [x^i]
         for j in 0..self.cols {
-            let mut vj = G::identity();
-            for (i, xpow) in xpows.iter().enumerate() {
-                vj += self.m[i][j] * xpow;
-            }
+            let vj = msm(&xpows, &self.column(j)); // \sum_i x^i * M_{i,j}
             v.push(vj);
         }
 
         VerificationVector::new(v)
     }
 
+    /// Evaluates the verification matrix at the given `x` value using
+    /// Horner's method in the group.
+    ///
+    /// The returned elements `\sum_i x^i * M_{i,j}` commit to the coefficients
+    /// of the univariate polynomial `B(x,y)` in `y`, i.e. they form the same
+    /// vector as [`Self::verification_vector_for_y`], but are computed with
+    /// one scalar multiplication per element instead of multi-scalar
+    /// multiplications.
+    pub fn eval_at(&self, x: &G::Scalar) -> Vec<G> {
+        let mut rows = self.m.iter().rev();
+        let mut v = rows.next().expect("matrix has at least one row").clone();
+        for mi in rows {
+            for (vj, mij) in v.iter_mut().zip(mi) {
+                *vj = *vj * x + mij;
+            }
+        }
+
+        v
+    }
+
     /// Verifies coefficients of the polynomial resulting from the evaluation
     /// of the bivariate polynomial with respect to the indeterminate x against
     /// the verification matrix.
@@ -139,19 +422,25 @@ where
         // Don't short-circuit this loop to avoid revealing which coefficient
         // failed to verify.
         let xpows = powers(x, self.rows - 1); // [x^i]
-        let mut verified = Choice::from(1);
-
-        for j in 0..self.cols {
+        let verify_column = |j: usize| {
             // Verify if the following difference is the identity element (zero)
             // of the group: a_j * G - \sum_{i=0}^{deg_x} x^i * M_{i,j}.
             let aj = polynomial.coefficient(j).expect("size checked above");
-            let mut diff = G::generator() * aj; // a_j * G
-            for (i, xpow) in xpows.iter().enumerate() {
-                diff -= self.m[i][j] * xpow; // x^i * M_{i,j} = b_{i,j} x^i * G
-            }
-
-            verified &= diff.is_identity();
-        }
+            let diff = G::generator() * aj // a_j * G
+                - msm(&xpows, &self.column(j)); // x^i * M_{i,j} = b_{i,j} x^i * G
+
+            diff.is_identity()
+        };
+
+        #[cfg(feature = "parallel")]
+        let verified = (0..self.cols)
+            .into_par_iter()
+            .map(verify_column)
+            .reduce(|| Choice::from(1), |a, b| a & b);
+        #[cfg(not(feature = "parallel"))]
+        let verified = (0..self.cols)
+            .map(verify_column)
+            .fold(Choice::from(1), |a, b| a & b);
 
         verified.into()
     }
@@ -186,62 +475,222 @@ where
         // Don't short-circuit this loop to avoid revealing which coefficient
         // failed to verify.
         let ypows = powers(y, self.cols - 1); // [y^j]
-        let mut verified = Choice::from(1);
-
-        for i in 0..self.rows {
+        let verify_row = |i: usize| {
             // Verify if the following difference is the identity element (zero)
             // of the group: a_i * G - \sum_{j=0}^{deg_y} y^j * M_{i,j}.
             let ai = polynomial.coefficient(i).expect("size checked above");
-            let mut diff = G::generator() * ai; // a_i * G
-            for (j, ypow) in ypows.iter().enumerate() {
-                diff -= self.m[i][j] * ypow; // y^j * M_{i,j} = b_{i,j} y^j * G
-            }
+            let diff = G::generator() * ai // a_i * G
+                - msm(&ypows, &self.m[i]); // y^j * M_{i,j} = b_{i,j} y^j * G
+
+            diff.is_identity()
+        };
+
+        #[cfg(feature = "parallel")]
+        let verified = (0..self.rows)
+            .into_par_iter()
+            .map(verify_row)
+            .reduce(|| Choice::from(1), |a, b| a & b);
+        #[cfg(not(feature = "parallel"))]
+        let verified = (0..self.rows)
+            .map(verify_row)
+            .fold(Choice::from(1), |a, b| a & b);
+
+        verified.into()
+    }
+
+    /// Verifies whether the verification matrix is the commitment
+    /// to the given bivariate polynomial, i.e., if it holds
+    /// `M_{i,j} == b_{i,j} * G` for all elements of the matrix.
+    ///
+    /// This method is not constant time if the dimensions of the matrix
+    /// and the polynomial differ.
+    pub fn verify_commitment(&self, bp: &BivariatePolynomial<G::Scalar>) -> bool {
+        // Short-circuit on the dimensions of the polynomial, not its contents.
+        if bp.deg_x + 1 != self.rows || bp.deg_y + 1 != self.cols {
+            return false;
+        }
 
-            verified &= diff.is_identity();
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to verify.
+        let mut verified = Choice::from(1);
+        for (mi, bi) in self.m.iter().zip(&bp.b) {
+            for (mij, bij) in mi.iter().zip(bi) {
+                let diff = G::generator() * bij - mij;
+                verified &= diff.is_identity();
+            }
         }
 
         verified.into()
     }
 
+    /// Derives a challenge from the verification matrix and the given
+    /// context using the Fiat-Shamir heuristic.
+    ///
+    /// The challenge is the hash of the dimensions of the matrix, encoded
+    /// as big-endian 64-bit integers, and its elements, followed by
+    /// the context. Since the dimensions are absorbed in full, the context
+    /// can't be confused with the elements of a larger matrix. The context
+    /// should bind everything else the challenge is used for, e.g.
+    /// the points verified in a batch.
+    pub fn transcript_challenge<H: FieldDigest>(&self, context: &[u8]) -> Result<H::Output> {
+        let size = self.rows * self.cols * Self::element_byte_size();
+        let mut transcript = Vec::with_capacity(16 + size + context.len());
+        transcript.extend_from_slice(&(self.rows as u64).to_be_bytes());
+        transcript.extend_from_slice(&(self.cols as u64).to_be_bytes());
+        self.write_elements(&mut transcript);
+        transcript.extend_from_slice(context);
+
+        H::hash_to_field(&transcript, TRANSCRIPT_CHALLENGE_DST)
+    }
+
     /// Returns the byte representation of the verification matrix.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let cap = Self::byte_size(self.rows, self.cols);
-        let mut bytes = Vec::with_capacity(cap);
+        let mut bytes = Vec::with_capacity(self.encoded_len());
         let deg_x = (self.rows - 1) as u8;
         let deg_y = (self.cols - 1) as u8;
         bytes.extend([deg_x, deg_y].iter());
-        for mi in &self.m {
-            for mij in mi {
-                bytes.extend_from_slice(mij.to_bytes().as_ref());
-            }
-        }
+        self.write_elements(&mut bytes);
 
         bytes
     }
 
     /// Attempts to create a verification matrix from its byte representation.
     ///
+    /// Fails if the length of the representation doesn't match the encoded
+    /// dimensions or if any element is not a canonical point encoding.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_limit(bytes, (usize::MAX, usize::MAX))
+    }
+
+    /// Attempts to create a verification matrix from its byte representation,
+    /// rejecting representations whose encoded dimensions exceed the given
+    /// maximum dimensions.
+    ///
+    /// The dimensions are checked before anything is decoded, so untrusted
+    /// representations can't make the caller decode more elements than
+    /// it expects.
+    ///
     /// This method is not constant time since the verification matrix doesn't
     /// contain sensitive information.
-    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    pub fn from_bytes_with_limit(bytes: &[u8], max_dimensions: (usize, usize)) -> Result<Self> {
         if bytes.len() < 2 {
-            return None;
+            return Err(Error::InvalidEncoding.into());
         }
 
         let deg_x = bytes[0] as usize;
         let deg_y = bytes[1] as usize;
         let rows = deg_x + 1;
         let cols = deg_y + 1;
-        let expected_len = Self::byte_size(rows, cols);
+        Self::check_dimensions((rows, cols), max_dimensions)?;
+
+        Self::from_element_bytes(rows, cols, &bytes[2..])
+    }
+
+    /// Attempts to read a verification matrix in its byte representation
+    /// from the given reader.
+    ///
+    /// The elements are decoded one by one as they are read, so that
+    /// the byte representation is never buffered as a whole and reading
+    /// stops at the first element that is not a canonical point encoding.
+    /// Bytes following the representation are left in the reader.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::from_reader_with_limit(reader, (usize::MAX, usize::MAX))
+    }
+
+    /// Attempts to read a verification matrix in its byte representation
+    /// from the given reader, rejecting representations whose encoded
+    /// dimensions exceed the given maximum dimensions.
+    ///
+    /// The dimensions are checked right after the header is read, before
+    /// any memory is allocated for the elements.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_limit<R: Read>(
+        reader: &mut R,
+        max_dimensions: (usize, usize),
+    ) -> Result<Self> {
+        let mut header = [0u8; 2];
+        reader
+            .read_exact(&mut header)
+            .map_err(|_| Error::InvalidEncoding)?;
+
+        let rows = header[0] as usize + 1;
+        let cols = header[1] as usize + 1;
+        Self::check_dimensions((rows, cols), max_dimensions)?;
+
+        let mut m = Vec::with_capacity(rows);
+        let mut repr: G::Repr = Default::default();
+
+        for _ in 0..rows {
+            let mut mi = Vec::with_capacity(cols);
+
+            for _ in 0..cols {
+                reader
+                    .read_exact(repr.as_mut())
+                    .map_err(|_| Error::InvalidEncoding)?;
+
+                let mij = match G::from_bytes(&repr).into() {
+                    None => return Err(Error::InvalidPointEncoding.into()),
+                    Some(mij) => mij,
+                };
+
+                mi.push(mij);
+            }
+            m.push(mi);
+        }
+
+        Ok(Self { cols, rows, m })
+    }
 
-        if bytes.len() != expected_len {
-            return None;
+    /// Verifies that the declared dimensions don't exceed the given maximum
+    /// dimensions.
+    pub(crate) fn check_dimensions(
+        (rows, cols): (usize, usize),
+        (max_rows, max_cols): (usize, usize),
+    ) -> Result<()> {
+        if rows > max_rows || cols > max_cols {
+            return Err(Error::DeclaredSizeTooLarge.into());
         }
 
+        Ok(())
+    }
+
+    /// Verifies that the given dimensions fit into the byte representation.
+    fn check_encodable(dimensions: (usize, usize)) -> Result<()> {
+        Self::check_dimensions(dimensions, (MAX_ENCODED_DIMENSION, MAX_ENCODED_DIMENSION))
+    }
+
+    /// Appends the byte representations of the matrix elements, in row-major
+    /// order, to the given vector.
+    pub(crate) fn write_elements(&self, bytes: &mut Vec<u8>) {
+        for mi in &self.m {
+            for mij in mi {
+                bytes.extend_from_slice(mij.to_bytes().as_ref());
+            }
+        }
+    }
+
+    /// Attempts to create a verification matrix of the given dimensions
+    /// from the byte representations of its elements in row-major order.
+    pub(crate) fn from_element_bytes(rows: usize, cols: usize, bytes: &[u8]) -> Result<Self> {
         let element_size = Self::element_byte_size();
+
+        if rows == 0 || cols == 0 || bytes.len() != rows * cols * element_size {
+            return Err(Error::InvalidEncoding.into());
+        }
+
         let mut m = Vec::with_capacity(rows);
 
-        for chunks in bytes[2..].chunks(element_size * cols) {
+        for chunks in bytes.chunks(element_size * cols) {
             let mut mi = Vec::with_capacity(cols);
 
             for chunk in chunks.chunks(element_size) {
@@ -249,7 +698,7 @@ where
                 repr.as_mut().copy_from_slice(chunk);
 
                 let mij = match G::from_bytes(&repr).into() {
-                    None => return None,
+                    None => return Err(Error::InvalidPointEncoding.into()),
                     Some(mij) => mij,
                 };
 
@@ -258,7 +707,7 @@ where
             m.push(mi);
         }
 
-        Some(Self { cols, rows, m })
+        Ok(Self { cols, rows, m })
     }
 
     /// Returns the size of the byte representation of a matrix element.
@@ -271,6 +720,12 @@ where
     pub fn byte_size(rows: usize, cols: usize) -> usize {
         2 + rows * cols * Self::element_byte_size()
     }
+
+    /// Returns the exact size of the byte representation returned by
+    /// [`Self::to_bytes`], so that callers can preallocate buffers.
+    pub fn encoded_len(&self) -> usize {
+        Self::byte_size(self.rows, self.cols)
+    }
 }
 
 impl<G> From<&BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
@@ -282,14 +737,14 @@ where
     fn from(bp: &BivariatePolynomial<G::Scalar>) -> Self {
         let rows = bp.deg_x + 1;
         let cols = bp.deg_y + 1;
-        let mut m = Vec::new();
-        for bi in bp.b.iter() {
-            let mut mi = Vec::new();
-            for bij in bi.iter() {
-                mi.push(G::generator() * bij) // b_{i,j} * G
-            }
-            m.push(mi)
-        }
+        let compute_row = |bi: &Vec<G::Scalar>| {
+            bi.iter().map(|bij| G::generator() * bij).collect() // b_{i,j} * G
+        };
+
+        #[cfg(feature = "parallel")]
+        let m = bp.b.par_iter().map(compute_row).collect();
+        #[cfg(not(feature = "parallel"))]
+        let m = bp.b.iter().map(compute_row).collect();
 
         Self { rows, cols, m }
     }
@@ -306,6 +761,28 @@ where
     }
 }
 
+impl<G> ConstantTimeEq for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Compares the elements of the verification matrices in constant time,
+    /// without short-circuiting on the first differing element.
+    ///
+    /// Matrices of different dimensions are never equal, and this case
+    /// short-circuits on the dimensions only.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.dimensions() != other.dimensions() {
+            return Choice::from(0);
+        }
+
+        self.m
+            .iter()
+            .zip(&other.m)
+            .flat_map(|(mi, oi)| mi.iter().zip(oi))
+            .fold(Choice::from(1), |eq, (a, b)| eq & (*a - b).is_identity())
+    }
+}
+
 impl<G> Add for VerificationMatrix<G>
 where
     G: Group + GroupEncoding,
@@ -352,13 +829,60 @@ where
     }
 }
 
+impl<G> AddAssign for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: VerificationMatrix<G>) {
+        *self += &rhs
+    }
+}
+
+impl<G> AddAssign<&VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Adds the given matrix element-wise, growing to the larger of
+    /// the dimensions of both matrices. The dimensions never exceed those
+    /// of the larger operand, so sums of encodable matrices stay encodable.
+    fn add_assign(&mut self, rhs: &VerificationMatrix<G>) {
+        let rows = max(self.rows, rhs.rows);
+        let cols = max(self.cols, rhs.cols);
+
+        self.m.resize_with(rows, Vec::new);
+        for (i, mi) in self.m.iter_mut().enumerate() {
+            mi.resize(cols, G::identity());
+
+            if let Some(ri) = rhs.m.get(i) {
+                for (mij, rij) in mi.iter_mut().zip(ri) {
+                    *mij += rij;
+                }
+            }
+        }
+
+        self.rows = rows;
+        self.cols = cols;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use group::Group as _;
+    extern crate test;
+
+    use std::iter::zip;
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group as _, GroupEncoding};
     use rand::{rngs::StdRng, SeedableRng};
+    use subtle::ConstantTimeEq;
+
+    use crate::{poly, suites, vss};
 
-    use crate::{poly, vss};
+    use super::Error;
 
+    type Suite = suites::p384::Sha3_384;
     type PrimeField = p384::Scalar;
     type Group = p384::ProjectivePoint;
     type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
@@ -404,6 +928,48 @@ mod tests {
         let _ = VerificationMatrix::from(&bp);
     }
 
+    #[test]
+    fn test_from_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from_polynomial(&bp);
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+        assert!(vm.verify_commitment(&bp));
+    }
+
+    #[test]
+    fn test_zero_hole() {
+        let vm = VerificationMatrix::zero_hole((3, 5)).unwrap();
+        assert_eq!(vm.dimensions(), (3, 5));
+        assert!(vm.is_zero_hole());
+        assert!(vm.iter().all(|(_, mij)| mij == &Group::IDENTITY));
+        assert!(vm.verify_commitment(&BivariatePolynomial::zero(2, 4)));
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let mut vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_zero_hole());
+        vm.make_zero_hole();
+        assert!(vm.is_zero_hole());
+        bp.to_zero_hole();
+        assert!(vm.verify_commitment(&bp));
+
+        // The largest encodable dimensions round trip.
+        let vm = VerificationMatrix::zero_hole((256, 1)).unwrap();
+        let restored = VerificationMatrix::from_bytes(&vm.to_bytes()).unwrap();
+        assert_eq!(restored, vm);
+
+        // Dimensions that don't fit into the byte representation.
+        for dimensions in [(257, 1), (1, 257), (300, 3)] {
+            let res = VerificationMatrix::zero_hole(dimensions);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DeclaredSizeTooLarge.to_string()
+            );
+        }
+    }
+
     #[test]
     fn test_dimensions() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -423,6 +989,218 @@ mod tests {
 
         let vm = VerificationMatrix::from(&bp);
         assert_eq!(&e, vm.element(1, 2).unwrap());
+        assert_eq!(&e, vm.get(1, 2).unwrap());
+
+        // Out of bounds.
+        assert!(vm.get(3, 0).is_none());
+        assert!(vm.get(0, 4).is_none());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let elements: Vec<_> = vm.iter().collect();
+        assert_eq!(elements.len(), 3 * 4);
+        for (k, ((i, j), mij)) in elements.into_iter().enumerate() {
+            assert_eq!((i, j), (k / 4, k % 4));
+            assert_eq!(vm.get(i, j), Some(mij));
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut bp = BivariatePolynomial::zero(1, 2);
+        assert!(bp.set_coefficient(0, 1, PrimeField::ONE));
+        assert!(bp.set_coefficient(1, 2, PrimeField::ONE.double()));
+        let vm = VerificationMatrix::from(&bp);
+
+        let transposed = vm.transpose();
+        assert_eq!(transposed.dimensions(), (3, 2));
+        for (i, mi) in transposed.m.iter().enumerate() {
+            for (j, mij) in mi.iter().enumerate() {
+                match (i, j) {
+                    (1, 0) => assert_eq!(mij, &Group::GENERATOR),
+                    (2, 1) => assert_eq!(mij, &Group::GENERATOR.double()),
+                    _ => assert_eq!(mij, &Group::IDENTITY),
+                }
+            }
+        }
+        assert_eq!(transposed.transpose(), vm);
+
+        // Zero-hole matrices stay zero-hole.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.is_zero_hole());
+        assert!(vm.transpose().is_zero_hole());
+        assert_eq!(vm.transpose().transpose(), vm);
+    }
+
+    #[test]
+    fn test_split_and_concat_rows() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(5, 3, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!(vm.dimensions(), (6, 4));
+
+        // Round trip.
+        let (top, bottom) = vm.split_rows(2).unwrap();
+        assert_eq!(top.dimensions(), (2, 4));
+        assert_eq!(bottom.dimensions(), (4, 4));
+        assert_eq!(top.m, vm.m[..2]);
+        assert_eq!(bottom.m, vm.m[2..]);
+        assert!(top.is_zero_hole());
+
+        let restored = VerificationMatrix::concat_rows(&top, &bottom).unwrap();
+        assert_eq!(restored, vm);
+        assert!(restored.is_zero_hole());
+
+        // Chunks without rows.
+        for at in [0, 6, 7] {
+            let res = vm.split_rows(at);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::MatrixIndexOutOfBounds.to_string()
+            );
+        }
+
+        // Column counts differ.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(3, 2, &mut rng));
+        let res = VerificationMatrix::concat_rows(&top, &other);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (4, 4),
+                actual: (4, 3),
+            }
+            .to_string()
+        );
+
+        // The combined rows must fit into the byte representation.
+        let top = VerificationMatrix::zero_hole((200, 1)).unwrap();
+        let bottom = VerificationMatrix::zero_hole((56, 1)).unwrap();
+        let vm = VerificationMatrix::concat_rows(&top, &bottom).unwrap();
+        assert_eq!(vm.dimensions(), (256, 1));
+        assert_eq!(VerificationMatrix::from_bytes(&vm.to_bytes()).unwrap(), vm);
+
+        let bottom = VerificationMatrix::zero_hole((57, 1)).unwrap();
+        let res = VerificationMatrix::concat_rows(&top, &bottom);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+
+        let res = VerificationMatrix::concat_rows(&top, &top);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp1 = BivariatePolynomial::random(2, 4, &mut rng);
+        let bp2 = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm1 = VerificationMatrix::from(&bp1);
+        let vm2 = VerificationMatrix::from(&bp2);
+        assert_eq!(vm1.dimensions(), (3, 5));
+
+        // Addition.
+        let sum = &vm1 + &vm2;
+        assert_eq!(sum.dimensions(), (3, 5));
+        for ((i, j), sij) in sum.iter() {
+            let bij = bp1.coefficient(i, j).unwrap() + bp2.coefficient(i, j).unwrap();
+            assert_eq!(sij, &(Group::GENERATOR * bij));
+        }
+        let mut sum_assign = vm1.clone();
+        sum_assign += &vm2;
+        assert_eq!(sum_assign, sum);
+        assert_eq!(
+            VerificationMatrix::sum(&[vm1.clone(), vm2.clone()]).unwrap(),
+            sum
+        );
+
+        // Zero-hole detection.
+        assert!(!vm1.is_zero_hole());
+        let mut zh = vm1.clone();
+        zh.make_zero_hole();
+        assert!(zh.is_zero_hole());
+        assert_eq!(zh.dimensions(), (3, 5));
+        assert!(!(&zh + &vm2).is_zero_hole());
+        assert!(VerificationMatrix::zero_hole((3, 5))
+            .unwrap()
+            .is_zero_hole());
+
+        // Transpose.
+        let transposed = vm1.transpose();
+        assert_eq!(transposed.dimensions(), (5, 3));
+        for ((i, j), mij) in vm1.iter() {
+            assert_eq!(transposed.get(j, i), Some(mij));
+        }
+        assert!(transposed.get(3, 0).is_some());
+        assert!(transposed.get(0, 3).is_none());
+        assert!(zh.transpose().is_zero_hole());
+
+        // Evaluation in either dimension matches the polynomial.
+        let x = scalar(3);
+        let y = scalar(7);
+        let v = bp1.eval(&x, &y);
+        assert!(vm1.verify(&x, &y, &v));
+        assert!(transposed.verify(&y, &x, &v));
+        assert!(vm1.verify_x(&x, &bp1.eval_x(&x)));
+        assert!(vm1.verify_y(&y, &bp1.eval_y(&y)));
+        assert!(!vm1.verify_x(&x, &bp1.eval_y(&x)));
+
+        // Matrices of transposed dimensions are padded, not mixed up.
+        let padded = &vm1 + &transposed;
+        assert_eq!(padded.dimensions(), (5, 5));
+        let res = VerificationMatrix::sum(&[vm1.clone(), transposed]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (3, 5),
+                actual: (5, 3),
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Equal matrices.
+        assert!(bool::from(vm.ct_eq(&vm.clone())));
+        assert!(bool::from(vm.ct_eq(&VerificationMatrix::from(&bp))));
+
+        // Matrices differing in a single element.
+        for (i, j) in [(0, 0), (1, 2), (2, 4)] {
+            let mut other = vm.clone();
+            other.update_entry(i, j, &scalar(0), &scalar(1)).unwrap();
+            assert!(!bool::from(vm.ct_eq(&other)));
+            assert!(!bool::from(other.ct_eq(&vm)));
+        }
+
+        // Dimension mismatch.
+        for (deg_x, deg_y) in [(4, 2), (2, 3), (3, 4)] {
+            let other =
+                VerificationMatrix::from(&BivariatePolynomial::random(deg_x, deg_y, &mut rng));
+            assert!(!bool::from(vm.ct_eq(&other)));
+        }
+        assert!(!bool::from(vm.ct_eq(&vm.transpose())));
     }
 
     #[test]
@@ -472,6 +1250,34 @@ mod tests {
         assert!(!vv.is_from(&p));
     }
 
+    #[test]
+    fn test_eval_at() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        for x in [
+            scalar(0),
+            scalar(1),
+            scalar(2),
+            PrimeField::random(&mut rng),
+        ] {
+            let p = bp.eval_x(&x);
+            let v = vm.eval_at(&x);
+            assert_eq!(v.len(), 4);
+            for (j, vj) in v.iter().enumerate() {
+                assert_eq!(*vj, Group::generator() * p.coefficient(j).unwrap());
+            }
+            assert!(vss::VerificationVector::new(v).is_from(&p));
+        }
+
+        // A single row.
+        let bp = BivariatePolynomial::random(0, 2, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let v = vss::VerificationVector::new(vm.eval_at(&scalar(5)));
+        assert_eq!(v, vm.verification_vector_for_y(&scalar(5)));
+    }
+
     #[test]
     fn test_verify_x() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -512,15 +1318,162 @@ mod tests {
         assert!(vm.verify_y(&y2, &p));
     }
 
+    #[test]
+    fn test_verify_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.verify_commitment(&bp));
+
+        // Changing any coefficient breaks the commitment.
+        for i in 0..=bp.deg_x {
+            for j in 0..=bp.deg_y {
+                let mut invalid = bp.clone();
+                let bij = *bp.coefficient(i, j).unwrap() + PrimeField::ONE;
+                invalid.set_coefficient(i, j, bij);
+                assert!(!vm.verify_commitment(&invalid));
+            }
+        }
+
+        // Invalid dimensions.
+        let other = BivariatePolynomial::random(3, 2, &mut rng);
+        assert!(!vm.verify_commitment(&other));
+    }
+
     #[test]
     fn test_serialization() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
         let bp = BivariatePolynomial::random(2, 3, &mut rng);
         let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+        assert_eq!(bytes.len(), VerificationMatrix::byte_size(3, 4));
         let restored =
-            VerificationMatrix::from_bytes(&vm.to_bytes()).expect("deserialization should succeed");
+            VerificationMatrix::from_bytes(&bytes).expect("deserialization should succeed");
+
+        assert_eq!(vm, restored);
+
+        // Truncated or extended buffer.
+        for invalid in [
+            &bytes[..1],
+            &bytes[..bytes.len() - 1],
+            &[bytes.as_slice(), &[0]].concat(),
+        ] {
+            let res = VerificationMatrix::from_bytes(invalid);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidEncoding.to_string()
+            );
+        }
+
+        // Non-canonical point encoding.
+        let mut invalid = bytes.clone();
+        invalid[2..2 + VerificationMatrix::element_byte_size()].fill(255);
+        let res = VerificationMatrix::from_bytes(&invalid);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidPointEncoding.to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+
+        // Trailing bytes are left in the reader.
+        let extended = [bytes.as_slice(), &[1, 2]].concat();
+        let mut reader = extended.as_slice();
+        let restored = VerificationMatrix::from_reader(&mut reader).unwrap();
+        assert_eq!(vm, restored);
+        assert_eq!(reader, &[1, 2]);
+
+        // Truncated stream.
+        for invalid in [&bytes[..1], &bytes[..bytes.len() - 1]] {
+            let mut reader = invalid;
+            let res = VerificationMatrix::from_reader(&mut reader);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidEncoding.to_string()
+            );
+        }
+
+        // Non-canonical point encoding fails before the rest is read.
+        let element_size = VerificationMatrix::element_byte_size();
+        let mut invalid = bytes.clone();
+        invalid[2 + element_size..2 + 2 * element_size].fill(255);
+        let mut reader = invalid.as_slice();
+        let res = VerificationMatrix::from_reader(&mut reader);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidPointEncoding.to_string()
+        );
+        assert_eq!(reader.len(), bytes.len() - 2 - 2 * element_size);
+    }
+
+    #[test]
+    fn test_declared_size_limit() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
 
+        // Within the limit.
+        let restored = VerificationMatrix::from_bytes_with_limit(&bytes, (3, 4)).unwrap();
+        assert_eq!(vm, restored);
+        let mut reader = bytes.as_slice();
+        let restored = VerificationMatrix::from_reader_with_limit(&mut reader, (3, 4)).unwrap();
         assert_eq!(vm, restored);
+
+        // Exceeding the limit in either dimension.
+        for max_dimensions in [(2, 4), (3, 3)] {
+            let res = VerificationMatrix::from_bytes_with_limit(&bytes, max_dimensions);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::DeclaredSizeTooLarge.to_string()
+            );
+        }
+
+        // An endless stream claiming the largest dimensions is rejected
+        // right after the header.
+        let mut reader = std::io::repeat(u8::MAX);
+        let res = VerificationMatrix::from_reader_with_limit(&mut reader, (3, 4));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+    }
+
+    #[test]
+    fn test_transcript_challenge() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+
+        // Same matrix and context.
+        let c = vm.transcript_challenge::<Suite>(b"context").unwrap();
+        assert_eq!(c, vm.transcript_challenge::<Suite>(b"context").unwrap());
+
+        // Different context or matrix.
+        assert_ne!(c, vm.transcript_challenge::<Suite>(b"other").unwrap());
+        assert_ne!(c, vm.transcript_challenge::<Suite>(b"").unwrap());
+        assert_ne!(c, other.transcript_challenge::<Suite>(b"context").unwrap());
+
+        // Matrices too large for the byte representation don't alias
+        // smaller ones.
+        let element = Group::generator().to_bytes().as_slice().to_vec();
+        let wide = VerificationMatrix::from_element_bytes(1, 257, &element.repeat(257)).unwrap();
+        let narrow = VerificationMatrix::from_element_bytes(1, 1, &element).unwrap();
+        let c = wide.transcript_challenge::<Suite>(b"").unwrap();
+        let context = element.repeat(256);
+        assert_ne!(c, narrow.transcript_challenge::<Suite>(&context).unwrap());
     }
 
     #[test]
@@ -535,6 +1488,19 @@ mod tests {
         assert_eq!(size, 2 + 2 * 3 * 49);
     }
 
+    #[test]
+    fn test_encoded_len() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (1, 2), (2, 4), (3, 1)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+            let (rows, cols) = vm.dimensions();
+            assert_eq!(vm.encoded_len(), VerificationMatrix::byte_size(rows, cols));
+            assert_eq!(vm.to_bytes().len(), vm.encoded_len());
+        }
+    }
+
     #[test]
     fn test_add() {
         let c1 = vec![scalars(&[1, 2, 3, 4]), scalars(&[5, 6, 7, 8])];
@@ -562,4 +1528,238 @@ mod tests {
         assert_eq!(sum.cols, 4);
         assert_eq!(sum, vm);
     }
+
+    #[test]
+    fn test_add_assign() {
+        let c1 = vec![scalars(&[1, 2, 3, 4]), scalars(&[5, 6, 7, 8])];
+        let c2 = vec![scalars(&[1, 2]), scalars(&[3, 4]), scalars(&[5, 6])];
+        let bp1 = BivariatePolynomial::with_coefficients(c1);
+        let bp2 = BivariatePolynomial::with_coefficients(c2);
+        let vm1 = VerificationMatrix::from(&bp1);
+        let vm2 = VerificationMatrix::from(&bp2);
+        let expected = &vm1 + &vm2;
+
+        let mut sum = vm1.clone();
+        sum += &vm2;
+        assert_eq!(sum.rows, 3);
+        assert_eq!(sum.cols, 4);
+        assert_eq!(sum, expected);
+
+        let mut sum = vm2.clone();
+        sum += vm1;
+        assert_eq!(sum.rows, 3);
+        assert_eq!(sum.cols, 4);
+        assert_eq!(sum, expected);
+
+        // Sums of the largest encodable matrices stay encodable.
+        let mut sum = VerificationMatrix::zero_hole((256, 1)).unwrap();
+        sum += VerificationMatrix::zero_hole((1, 2)).unwrap();
+        assert_eq!(sum.dimensions(), (256, 2));
+        assert_eq!(sum.to_bytes().len(), sum.encoded_len());
+        assert_eq!(
+            VerificationMatrix::from_bytes(&sum.to_bytes()).unwrap(),
+            sum
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bps: Vec<_> = (0..3)
+            .map(|_| BivariatePolynomial::random(2, 3, &mut rng))
+            .collect();
+        let vms: Vec<_> = bps.iter().map(VerificationMatrix::from).collect();
+
+        // Fold three compatible matrices.
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        for i in 0..3 {
+            for j in 0..4 {
+                let bij = bps.iter().map(|bp| bp.coefficient(i, j).unwrap()).sum();
+                bp.set_coefficient(i, j, bij);
+            }
+        }
+        let sum = VerificationMatrix::sum(&vms).unwrap();
+        assert_eq!(sum, VerificationMatrix::from(&bp));
+        assert_eq!(sum, &(&vms[0] + &vms[1]) + &vms[2]);
+
+        // A single matrix.
+        let sum = VerificationMatrix::sum(&vms[..1]).unwrap();
+        assert_eq!(sum, vms[0]);
+
+        // Zero-hole matrices.
+        let zero_hole: Vec<_> = vms
+            .iter()
+            .cloned()
+            .map(|mut vm| {
+                vm.make_zero_hole();
+                vm
+            })
+            .collect();
+        let sum = VerificationMatrix::sum(&zero_hole).unwrap();
+        assert!(sum.is_zero_hole());
+        let sum = VerificationMatrix::sum(&[zero_hole[0].clone(), zero_hole[0].clone()]).unwrap();
+        assert!(sum.is_zero_hole());
+
+        // Dimension mismatch.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(3, 2, &mut rng));
+        let res = VerificationMatrix::sum(&[vms[0].clone(), vms[1].clone(), other]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (3, 4),
+                actual: (4, 3),
+            }
+            .to_string()
+        );
+
+        // No matrices.
+        let res = VerificationMatrix::sum(&[]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NoVerificationMatrices.to_string()
+        );
+    }
+
+    #[test]
+    fn test_update_entry() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let mut vm = VerificationMatrix::from(&bp);
+
+        // A sequence of updates, revising some coefficients more than once.
+        let updates = [(0, 0), (1, 2), (2, 3), (1, 2), (0, 3)];
+        for (i, j) in updates {
+            let old = *bp.coefficient(i, j).unwrap();
+            let new = PrimeField::random(&mut rng);
+            bp.set_coefficient(i, j, new);
+            vm.update_entry(i, j, &old, &new).unwrap();
+        }
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+        assert!(vm.verify_commitment(&bp));
+
+        // Out of bounds.
+        for (i, j) in [(3, 0), (0, 4), (3, 4)] {
+            let res = vm.update_entry(i, j, &PrimeField::ZERO, &PrimeField::ONE);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::MatrixIndexOutOfBounds.to_string()
+            );
+        }
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+    }
+
+    #[test]
+    fn test_linear_combination() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bps: Vec<_> = (0..3)
+            .map(|_| BivariatePolynomial::random(2, 3, &mut rng))
+            .collect();
+        let vms: Vec<_> = bps.iter().map(VerificationMatrix::from).collect();
+
+        // Lagrange weights reproduce the commitment to the interpolated
+        // polynomial.
+        let xs = scalars(&[1, 2, 3]);
+        let cs = poly::lagrange::coefficients(&xs);
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        for i in 0..3 {
+            for j in 0..4 {
+                let bij = zip(&cs, &bps)
+                    .map(|(c, bp)| *c * bp.coefficient(i, j).unwrap())
+                    .sum();
+                bp.set_coefficient(i, j, bij);
+            }
+        }
+        let terms: Vec<_> = zip(cs, &vms).collect();
+        let vm = VerificationMatrix::linear_combination(&terms).unwrap();
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+        assert!(vm.verify_commitment(&bp));
+
+        // Unit weights yield the sum.
+        let terms: Vec<_> = vms.iter().map(|vm| (PrimeField::ONE, vm)).collect();
+        let vm = VerificationMatrix::linear_combination(&terms).unwrap();
+        assert_eq!(vm, VerificationMatrix::sum(&vms).unwrap());
+
+        // Dimension mismatch.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(3, 2, &mut rng));
+        let terms = [(PrimeField::ONE, &vms[0]), (PrimeField::ONE, &other)];
+        let res = VerificationMatrix::linear_combination(&terms);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixDimensionMismatch {
+                expected: (3, 4),
+                actual: (4, 3),
+            }
+            .to_string()
+        );
+
+        // No matrices.
+        let res = VerificationMatrix::linear_combination(&[]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::NoVerificationMatrices.to_string()
+        );
+    }
+
+    #[test]
+    fn test_matches_naive_computation() {
+        // The results must not depend on whether the parallel feature
+        // is enabled, so CI runs this test with and without the feature.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        for (i, bi) in bp.b.iter().enumerate() {
+            for (j, bij) in bi.iter().enumerate() {
+                assert_eq!(vm.m[i][j], Group::GENERATOR * bij);
+            }
+        }
+
+        let x = PrimeField::random(&mut rng);
+        assert!(vm.verify_x(&x, &bp.eval_x(&x)));
+        assert!(vm.verify_y(&x, &bp.eval_y(&x)));
+        assert!(!vm.verify_x(&x, &bp.eval_x(&x.double())));
+        assert!(!vm.verify_y(&x, &bp.eval_y(&x.double())));
+    }
+
+    fn prepare_bench(deg: u8) -> (BivariatePolynomial, PrimeField) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(deg, deg, &mut rng);
+        let x = PrimeField::random(&mut rng);
+        (bp, x)
+    }
+
+    #[bench]
+    fn bench_from_20_20(b: &mut Bencher) {
+        let (bp, _) = prepare_bench(19);
+        b.iter(|| VerificationMatrix::from(&bp));
+    }
+
+    #[bench]
+    fn bench_verify_x_20_20(b: &mut Bencher) {
+        let (bp, x) = prepare_bench(19);
+        let vm = VerificationMatrix::from(&bp);
+        let p = bp.eval_x(&x);
+        b.iter(|| vm.verify_x(&x, &p));
+    }
+
+    #[bench]
+    #[ignore = "slow, run with `cargo bench -- --ignored`"]
+    fn bench_from_256_256(b: &mut Bencher) {
+        let (bp, _) = prepare_bench(255);
+        b.iter(|| VerificationMatrix::from(&bp));
+    }
+
+    #[bench]
+    #[ignore = "slow, run with `cargo bench -- --ignored`"]
+    fn bench_verify_x_256_256(b: &mut Bencher) {
+        let (bp, x) = prepare_bench(255);
+        let vm = VerificationMatrix::from(&bp);
+        let p = bp.eval_x(&x);
+        b.iter(|| vm.verify_x(&x, &p));
+    }
 }
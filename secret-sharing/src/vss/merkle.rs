@@ -0,0 +1,452 @@
+//! Merkle commitments to verification matrices.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use anyhow::Result;
+use group::{Group, GroupEncoding};
+use sha3::{Digest, Sha3_256};
+
+use super::{Error, VerificationMatrix};
+
+/// The prefix of leaf hashes, which separates them from inner node hashes.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of inner node hashes.
+const NODE_PREFIX: u8 = 0x01;
+
+/// The prefix of root hashes, which bind the tree to the dimensions
+/// of the matrix.
+const ROOT_PREFIX: u8 = 0x02;
+
+/// The size of the header of the Merkle proof byte representation,
+/// consisting of the dimensions, the index and the length of the path.
+const MERKLE_PROOF_HEADER_SIZE: usize = 25;
+
+/// The size of a node hash.
+const NODE_SIZE: usize = 32;
+
+/// A Merkle path from an element of a verification matrix to the root
+/// of the matrix, see [`VerificationMatrix::merkle_root`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The number of rows in the verification matrix.
+    rows: usize,
+    /// The number of columns in the verification matrix.
+    cols: usize,
+    /// The row-major index of the element.
+    index: usize,
+    /// The sibling hashes from the leaf level up to the root.
+    path: Vec<[u8; 32]>,
+}
+
+impl MerkleProof {
+    /// Returns the dimensions of the verification matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the sibling hashes from the leaf level up to the root.
+    pub fn path(&self) -> &[[u8; 32]] {
+        &self.path
+    }
+
+    /// Returns the byte representation of the Merkle proof.
+    ///
+    /// The representation consists of the dimensions of the matrix and
+    /// the index of the element, encoded as big-endian 64-bit integers,
+    /// followed by the length of the path in one byte and the sibling
+    /// hashes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MERKLE_PROOF_HEADER_SIZE + self.path.len() * NODE_SIZE);
+        bytes.extend_from_slice(&(self.rows as u64).to_be_bytes());
+        bytes.extend_from_slice(&(self.cols as u64).to_be_bytes());
+        bytes.extend_from_slice(&(self.index as u64).to_be_bytes());
+        // The path has at most one sibling per bit of the index.
+        bytes.push(self.path.len() as u8);
+        for node in &self.path {
+            bytes.extend_from_slice(node);
+        }
+
+        bytes
+    }
+
+    /// Attempts to create a Merkle proof from its byte representation.
+    ///
+    /// Fails if the dimensions are zero or their product overflows,
+    /// if the index is out of bounds, or if the length of the path doesn't
+    /// match the number of siblings of the element.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < MERKLE_PROOF_HEADER_SIZE {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let read_u64 = |i: usize| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[i..i + 8]);
+            usize::try_from(u64::from_be_bytes(buf)).map_err(|_| Error::DeclaredSizeTooLarge)
+        };
+        let rows = read_u64(0)?;
+        let cols = read_u64(8)?;
+        let index = read_u64(16)?;
+        let path_len = bytes[24] as usize;
+
+        if rows == 0 || cols == 0 {
+            return Err(Error::InvalidEncoding.into());
+        }
+        let len = rows.checked_mul(cols).ok_or(Error::DeclaredSizeTooLarge)?;
+        if index >= len {
+            return Err(Error::MatrixIndexOutOfBounds.into());
+        }
+        if path_len != merkle_path_len(index, len)
+            || bytes.len() != MERKLE_PROOF_HEADER_SIZE + path_len * NODE_SIZE
+        {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        let path = bytes[MERKLE_PROOF_HEADER_SIZE..]
+            .chunks(NODE_SIZE)
+            .map(|chunk| {
+                let mut node = [0u8; NODE_SIZE];
+                node.copy_from_slice(chunk);
+                node
+            })
+            .collect();
+
+        Ok(Self {
+            rows,
+            cols,
+            index,
+            path,
+        })
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Computes the root of the Merkle tree over the canonical encodings
+    /// of the matrix elements in row-major order.
+    ///
+    /// Leaves and inner nodes are hashed with SHA3-256 under distinct
+    /// prefixes, and a node without a sibling is promoted to the next level
+    /// as is. The root additionally binds the dimensions of the matrix,
+    /// so that a light client holding the root can verify single elements
+    /// using [`verify_merkle_proof`] without downloading the matrix.
+    pub fn merkle_root(&self) -> [u8; 32] {
+        let mut level = self.merkle_leaves();
+        while level.len() > 1 {
+            level = merkle_parents(&level);
+        }
+
+        merkle_root(self.rows, self.cols, &level[0])
+    }
+
+    /// Returns the Merkle path of the element at the given row and column.
+    ///
+    /// Fails if the element is out of bounds.
+    pub fn merkle_proof(&self, row: usize, col: usize) -> Result<MerkleProof> {
+        if row >= self.rows || col >= self.cols {
+            return Err(Error::MatrixIndexOutOfBounds.into());
+        }
+
+        let index = row * self.cols + col;
+        let mut path = Vec::new();
+        let mut level = self.merkle_leaves();
+        let mut i = index;
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(i ^ 1) {
+                path.push(*sibling);
+            }
+            level = merkle_parents(&level);
+            i /= 2;
+        }
+
+        Ok(MerkleProof {
+            rows: self.rows,
+            cols: self.cols,
+            index,
+            path,
+        })
+    }
+
+    /// Returns the leaf hashes of the matrix elements in row-major order.
+    fn merkle_leaves(&self) -> Vec<[u8; 32]> {
+        self.m.iter().flatten().map(merkle_leaf).collect()
+    }
+}
+
+/// Verifies that the given element is at the given row and column
+/// of the verification matrix with the given Merkle root.
+pub fn verify_merkle_proof<G>(
+    root: &[u8; 32],
+    row: usize,
+    col: usize,
+    element: &G,
+    proof: &MerkleProof,
+) -> bool
+where
+    G: GroupEncoding,
+{
+    let mut len = match proof.rows.checked_mul(proof.cols) {
+        Some(len) => len,
+        None => return false,
+    };
+    if row >= proof.rows || col >= proof.cols || proof.index != row * proof.cols + col {
+        return false;
+    }
+
+    let mut hash = merkle_leaf(element);
+    let mut siblings = proof.path.iter();
+    let mut i = proof.index;
+    while len > 1 {
+        if i ^ 1 < len {
+            let sibling = match siblings.next() {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            hash = match i % 2 {
+                0 => merkle_node(&hash, sibling),
+                _ => merkle_node(sibling, &hash),
+            };
+        }
+        i /= 2;
+        len = (len + 1) / 2;
+    }
+    if siblings.next().is_some() {
+        return false;
+    }
+
+    merkle_root(proof.rows, proof.cols, &hash) == *root
+}
+
+/// Returns the number of siblings on the path from the leaf at the given
+/// index to the root of a tree with the given number of leaves.
+fn merkle_path_len(mut index: usize, mut len: usize) -> usize {
+    let mut path_len = 0;
+    while len > 1 {
+        if index ^ 1 < len {
+            path_len += 1;
+        }
+        index /= 2;
+        len = (len + 1) / 2;
+    }
+
+    path_len
+}
+
+/// Hashes the canonical encoding of the given element into a leaf.
+fn merkle_leaf<G: GroupEncoding>(element: &G) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update([LEAF_PREFIX])
+        .chain_update(element.to_bytes())
+        .finalize()
+        .into()
+}
+
+/// Hashes the given children into their parent node.
+fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update([NODE_PREFIX])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Hashes the given level of the tree into the next one, promoting
+/// the last node if it has no sibling.
+fn merkle_parents(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_node(left, right),
+            [node] => *node,
+            _ => unreachable!("chunks should have one or two nodes"),
+        })
+        .collect()
+}
+
+/// Binds the root of the tree to the dimensions of the matrix.
+fn merkle_root(rows: usize, cols: usize, node: &[u8; 32]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update([ROOT_PREFIX])
+        .chain_update((rows as u64).to_be_bytes())
+        .chain_update((cols as u64).to_be_bytes())
+        .chain_update(node)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Group as _;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly,
+        suites::{self, p384},
+        vss::{self, Error},
+    };
+
+    use super::{verify_merkle_proof, MerkleProof};
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_merkle_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // Square and odd sizes, including a single element.
+        for (deg_x, deg_y) in [(0, 0), (1, 1), (2, 4), (1, 2)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+            let root = vm.merkle_root();
+            let (rows, cols) = vm.dimensions();
+
+            for i in 0..rows {
+                for j in 0..cols {
+                    let element = vm.element(i, j).unwrap();
+                    let proof = vm.merkle_proof(i, j).unwrap();
+                    assert_eq!(proof.dimensions(), (rows, cols));
+                    assert!(verify_merkle_proof(&root, i, j, element, &proof));
+
+                    // Tampered element.
+                    let tampered = *element + Group::generator();
+                    assert!(!verify_merkle_proof(&root, i, j, &tampered, &proof));
+
+                    // Wrong position.
+                    assert!(!verify_merkle_proof(&root, i, j + 1, element, &proof));
+                }
+            }
+
+            let res = vm.merkle_proof(rows, 0);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::MatrixIndexOutOfBounds.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let root = vm.merkle_root();
+        let element = vm.element(1, 2).unwrap();
+        let proof = vm.merkle_proof(1, 2).unwrap();
+
+        // The root depends on every element.
+        let mut other = vm.clone();
+        other.m[2][4] = Group::random(&mut rng);
+        let other_root = other.merkle_root();
+        assert_ne!(other_root, root);
+        assert!(!verify_merkle_proof(&other_root, 1, 2, element, &proof));
+
+        // The root binds the dimensions, even if the elements are the same.
+        let flat = VerificationMatrix {
+            rows: 1,
+            cols: 15,
+            m: vec![vm.m.concat()],
+        };
+        assert_ne!(flat.merkle_root(), root);
+
+        // Tampered paths.
+        let mut tampered = proof.clone();
+        tampered.path[0][0] ^= 1;
+        assert!(!verify_merkle_proof(&root, 1, 2, element, &tampered));
+        let mut tampered = proof.clone();
+        tampered.path.pop();
+        assert!(!verify_merkle_proof(&root, 1, 2, element, &tampered));
+        let mut tampered = proof.clone();
+        tampered.path.push([0; 32]);
+        assert!(!verify_merkle_proof(&root, 1, 2, element, &tampered));
+
+        // Dimensions whose product overflows.
+        let mut tampered = proof;
+        tampered.rows = usize::MAX;
+        assert!(!verify_merkle_proof(&root, 1, 2, element, &tampered));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let root = vm.merkle_root();
+
+        // Deserialized proofs verify.
+        for i in 0..3 {
+            for j in 0..5 {
+                let element = vm.element(i, j).unwrap();
+                let proof = vm.merkle_proof(i, j).unwrap();
+                let bytes = proof.to_bytes();
+                assert_eq!(bytes.len(), 25 + 32 * proof.path().len());
+
+                let restored = MerkleProof::from_bytes(&bytes).unwrap();
+                assert_eq!(restored, proof);
+                assert!(verify_merkle_proof(&root, i, j, element, &restored));
+            }
+        }
+
+        let proof = vm.merkle_proof(1, 2).unwrap();
+        let bytes = proof.to_bytes();
+
+        // Truncated or extended representations.
+        for len in [0, 24, 25, bytes.len() - 1] {
+            let res = MerkleProof::from_bytes(&bytes[..len]);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                Error::InvalidEncoding.to_string()
+            );
+        }
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(&[0; 32]);
+        extended[24] += 1;
+        let res = MerkleProof::from_bytes(&extended);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Empty matrices.
+        let mut zero = bytes.clone();
+        zero[..8].copy_from_slice(&0u64.to_be_bytes());
+        let res = MerkleProof::from_bytes(&zero);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::InvalidEncoding.to_string()
+        );
+
+        // Index out of bounds.
+        let mut outside = bytes.clone();
+        outside[16..24].copy_from_slice(&15u64.to_be_bytes());
+        let res = MerkleProof::from_bytes(&outside);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::MatrixIndexOutOfBounds.to_string()
+        );
+
+        // Dimensions whose product overflows.
+        let mut overflow = bytes;
+        overflow[..8].copy_from_slice(&u64::MAX.to_be_bytes());
+        overflow[8..16].copy_from_slice(&u64::MAX.to_be_bytes());
+        let res = MerkleProof::from_bytes(&overflow);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::DeclaredSizeTooLarge.to_string()
+        );
+    }
+}
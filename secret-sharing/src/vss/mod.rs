@@ -1,7 +1,11 @@
 //! Verifiable secret sharing.
 
+mod errors;
 mod matrix;
+mod merkle;
+mod msm;
 mod vector;
+mod zero_hole;
 
 // Re-exports.
-pub use self::{matrix::*, vector::*};
+pub use self::{errors::*, matrix::*, merkle::*, vector::*, zero_hole::*};
@@ -0,0 +1,186 @@
+use alloc::{vec, vec::Vec};
+use core::iter::zip;
+
+use group::{ff::PrimeField, Group};
+
+/// The number of terms from which multi-scalar multiplication switches
+/// from naive accumulation to the bucket method.
+const MSM_THRESHOLD: usize = 32;
+
+/// Computes the multi-scalar multiplication `\sum_i s_i * P_i`.
+///
+/// Sums of fewer than 32 terms are accumulated naively, while larger ones
+/// are computed using Pippenger's bucket method, which requires roughly
+/// `n / log(n)` group additions per scalar bit instead of one group
+/// multiplication per term.
+///
+/// This function is not constant time, so the scalars must not be secret.
+pub(crate) fn msm<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    debug_assert_eq!(scalars.len(), points.len());
+
+    if scalars.len() < MSM_THRESHOLD {
+        return naive_msm(scalars, points);
+    }
+
+    pippenger_msm(scalars, points)
+}
+
+/// Computes the multi-scalar multiplication `\sum_i s_i * P_i` by
+/// accumulating the products one by one.
+fn naive_msm<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    zip(scalars, points).map(|(s, p)| *p * s).sum()
+}
+
+/// Computes the multi-scalar multiplication `\sum_i s_i * P_i` using
+/// Pippenger's bucket method.
+///
+/// The scalars are split into windows of `c` bits. For every window,
+/// the points are sorted into buckets by the value of their window,
+/// and the buckets are combined into `\sum_k k * B_k` using running sums.
+/// The windows are then combined from the most to the least significant,
+/// doubling the accumulator `c` times in between.
+fn pippenger_msm<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    let c = window_size(scalars.len());
+    let windows = (G::Scalar::NUM_BITS as usize).div_ceil(c);
+    let reprs: Vec<_> = scalars.iter().map(le_repr).collect();
+
+    let mut acc = G::identity();
+    let mut buckets = vec![G::identity(); (1 << c) - 1];
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        buckets.fill(G::identity());
+        for (repr, p) in zip(&reprs, points) {
+            let k = window(repr.as_ref(), w * c, c);
+            if k > 0 {
+                buckets[k - 1] += p;
+            }
+        }
+
+        // Compute \sum_k k * B_k = \sum_k \sum_{l>=k} B_l.
+        let mut running = G::identity();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            acc += running;
+        }
+    }
+
+    acc
+}
+
+/// Returns the window size in bits for the given number of terms.
+fn window_size(n: usize) -> usize {
+    let log_n = (usize::BITS - n.leading_zeros()) as usize;
+    log_n.saturating_sub(2).max(2)
+}
+
+/// Returns the little-endian representation of the given scalar.
+///
+/// The endianness of the representation of a prime field element is
+/// implementation-specific, so it is determined from the representation
+/// of one.
+fn le_repr<F: PrimeField>(s: &F) -> F::Repr {
+    let mut repr = s.to_repr();
+    if F::ONE.to_repr().as_ref()[0] != 1 {
+        repr.as_mut().reverse();
+    }
+    repr
+}
+
+/// Returns `c` bits of the given little-endian bytes, starting at bit `start`.
+fn window(bytes: &[u8], start: usize, c: usize) -> usize {
+    let mut k = 0;
+    for i in (start..start + c).rev() {
+        let bit = bytes.get(i / 8).map_or(0, |b| (b >> (i % 8)) & 1);
+        k = (k << 1) | bit as usize;
+    }
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{le_repr, msm, naive_msm, pippenger_msm, window};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    fn random_terms(n: usize) -> (Vec<PrimeField>, Vec<Group>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let scalars = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+        let points = (0..n).map(|_| Group::random(&mut rng)).collect();
+        (scalars, points)
+    }
+
+    #[test]
+    fn test_le_repr() {
+        let repr = le_repr(&PrimeField::from_u64(0x0102));
+        let bytes: &[u8] = repr.as_ref();
+        assert_eq!(bytes[0], 0x02);
+        assert_eq!(bytes[1], 0x01);
+        assert!(bytes[2..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_window() {
+        let bytes = [0b1010_0110, 0b0000_0011];
+        assert_eq!(window(&bytes, 0, 4), 0b0110);
+        assert_eq!(window(&bytes, 4, 4), 0b1010);
+        assert_eq!(window(&bytes, 6, 4), 0b1110);
+        assert_eq!(window(&bytes, 14, 4), 0); // Past the end.
+    }
+
+    #[test]
+    fn test_msm() {
+        for n in [0, 1, 2, 31, 32, 33, 70] {
+            let (scalars, points) = random_terms(n);
+            let expected = naive_msm(&scalars, &points);
+            assert_eq!(msm(&scalars, &points), expected);
+            assert_eq!(pippenger_msm(&scalars, &points), expected);
+        }
+
+        // Edge cases.
+        let (mut scalars, points) = random_terms(40);
+        scalars[0] = PrimeField::ZERO;
+        scalars[1] = PrimeField::ONE;
+        scalars[2] = PrimeField::ZERO - PrimeField::ONE;
+        let expected = naive_msm(&scalars, &points);
+        assert_eq!(pippenger_msm(&scalars, &points), expected);
+    }
+
+    fn bench_msm(b: &mut Bencher, n: usize, naive: bool) {
+        let (scalars, points) = random_terms(n);
+        b.iter(|| match naive {
+            true => naive_msm(&scalars, &points),
+            false => pippenger_msm(&scalars, &points),
+        });
+    }
+
+    #[bench]
+    fn bench_naive_msm_64(b: &mut Bencher) {
+        bench_msm(b, 64, true)
+    }
+
+    #[bench]
+    fn bench_pippenger_msm_64(b: &mut Bencher) {
+        bench_msm(b, 64, false)
+    }
+
+    #[bench]
+    fn bench_naive_msm_256(b: &mut Bencher) {
+        bench_msm(b, 256, true)
+    }
+
+    #[bench]
+    fn bench_pippenger_msm_256(b: &mut Bencher) {
+        bench_msm(b, 256, false)
+    }
+}
@@ -1,7 +1,17 @@
-use group::Group;
+use alloc::{vec, vec::Vec};
+use core::iter;
+#[cfg(not(feature = "parallel"))]
+use core::iter::zip;
+
+use group::{ff::Field, Group};
+use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use subtle::Choice;
 
-use crate::poly::{powers, Polynomial};
+use crate::poly::{powers, CheckedRng, Polynomial};
+
+use super::msm::msm;
 
 /// Verification vector for a univariate polynomial.
 ///
@@ -37,6 +47,14 @@ where
         Self { v }
     }
 
+    /// Constructs a new verification vector from the given univariate
+    /// polynomial.
+    ///
+    /// This is equivalent to converting the polynomial using [`From`].
+    pub fn from_polynomial(p: &Polynomial<G::Scalar>) -> Self {
+        Self::from(p)
+    }
+
     /// Verifies if the verification vector belongs to the given univariate
     /// polynomial.
     ///
@@ -60,15 +78,93 @@ where
         verified.into()
     }
 
+    /// Evaluates the underlying univariate polynomial in the exponent,
+    /// i.e., returns the commitment `P(x) * G` to its value at the given
+    /// point.
+    pub fn eval(&self, x: &G::Scalar) -> G {
+        let xpows = powers(x, self.v.len() - 1); // [x^i]
+        msm(&xpows, &self.v) // x^i * V_i = a_i x^i * G
+    }
+
     /// Verifies if the underlying univariate polynomial evaluates
     /// to the given value, i.e., if it holds `P(x) == v`.
     pub fn verify(&self, x: &G::Scalar, v: &G::Scalar) -> bool {
-        let mut diff = G::generator().neg() * v;
-        let xpows = powers(x, self.v.len() - 1); // [x^i]
-        for (i, xpow) in xpows.into_iter().enumerate() {
-            diff += self.v[i] * xpow; // x^i * V_i = a_i x^i * G
+        let diff = self.eval(x) - G::generator() * v;
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies if the underlying univariate polynomial evaluates
+    /// to the given values, i.e., if it holds `P(x_k) == v_k` for all
+    /// given points `(x_k, v_k)`.
+    ///
+    /// The points are verified at once by checking a random linear
+    /// combination of them, which requires only one group multiplication
+    /// per element of the vector, regardless of the number of points.
+    /// If the method returns false, at least one point is invalid
+    /// or the random number generator failed. If it returns true,
+    /// all points are valid, except with negligible probability.
+    pub fn verify_batch(&self, points: &[(G::Scalar, G::Scalar)], rng: &mut impl RngCore) -> bool {
+        let mut rng = CheckedRng::new(rng);
+        let rs: Vec<_> = iter::repeat_with(|| G::Scalar::random(&mut rng))
+            .take(points.len())
+            .collect();
+        if rng.failed() {
+            return false;
         }
+        self.verify_linear_combination(points, rs.into_iter())
+    }
+
+    /// Verifies if the underlying univariate polynomial evaluates
+    /// to the given values, i.e., if it holds `P(x_k) == v_k` for all
+    /// given points `(x_k, v_k)`.
+    ///
+    /// The points are verified at once by checking their linear combination
+    /// with the powers `c, c^2, ...` of the given challenge instead of random
+    /// coefficients. The challenge must be derived from all the points,
+    /// e.g. using [`VerificationMatrix::transcript_challenge`], as otherwise
+    /// invalid points could be crafted to cancel each other out.
+    ///
+    /// [`VerificationMatrix::transcript_challenge`]: super::VerificationMatrix::transcript_challenge
+    pub fn verify_batch_with_challenge(
+        &self,
+        points: &[(G::Scalar, G::Scalar)],
+        c: &G::Scalar,
+    ) -> bool {
+        let rs = iter::successors(Some(*c), |r| Some(*r * c));
+        self.verify_linear_combination(points, rs)
+    }
 
+    /// Verifies the linear combination of the given points with
+    /// the given coefficients.
+    fn verify_linear_combination(
+        &self,
+        points: &[(G::Scalar, G::Scalar)],
+        rs: impl Iterator<Item = G::Scalar>,
+    ) -> bool {
+        // Compute the linear combination of the points:
+        // \sum_k r_k * v_k and \sum_k r_k * x_k^i for all i.
+        let mut v = G::Scalar::ZERO;
+        let mut cs = vec![G::Scalar::ZERO; self.v.len()];
+        for ((x, vk), r) in points.iter().zip(rs) {
+            v += r * vk;
+
+            let mut rxpow = r;
+            for ci in cs.iter_mut() {
+                *ci += rxpow; // r_k * x_k^i
+                rxpow *= x;
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        let sum: G = (&self.v, cs)
+            .into_par_iter()
+            .map(|(vi, ci)| *vi * ci) // r_k * x_k^i * V_i
+            .sum();
+        #[cfg(not(feature = "parallel"))]
+        let sum: G = zip(&self.v, cs).map(|(vi, ci)| *vi * ci).sum(); // r_k * x_k^i * V_i
+
+        let diff = sum - G::generator() * v;
         diff.is_identity().into()
     }
 }
@@ -102,6 +198,10 @@ where
 
 #[cfg(test)]
 mod tests {
+    use core::num::NonZeroU32;
+
+    use rand::{rngs::StdRng, Error, RngCore, SeedableRng};
+
     use crate::{poly::Polynomial, vss::VerificationVector};
 
     fn scalar(value: i64) -> p384::Scalar {
@@ -123,6 +223,9 @@ mod tests {
         let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&vec![1, 2, 3]));
         let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
         assert_eq!(vv.v.len(), 3);
+
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from_polynomial(&p);
+        assert!(vv.is_from(&p));
     }
 
     #[test]
@@ -134,6 +237,16 @@ mod tests {
         assert!(!vv.is_from(&q));
     }
 
+    #[test]
+    fn test_eval() {
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+
+        for x in scalars(&[0, 1, 2, 3]) {
+            assert_eq!(vv.eval(&x), p384::ProjectivePoint::GENERATOR * p.eval(&x));
+        }
+    }
+
     #[test]
     fn test_verify() {
         let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&vec![1, 2, 3]));
@@ -145,4 +258,70 @@ mod tests {
         assert!(vv.verify(&x2, &s));
         assert!(!vv.verify(&x3, &s));
     }
+
+    /// A generator that always fails.
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            panic!("not implemented")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            panic!("not implemented")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            panic!("not implemented")
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            Err(NonZeroU32::new(Error::CUSTOM_START).unwrap().into())
+        }
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+        let mut points: Vec<_> = scalars(&[1, 2, 3, 4, 5])
+            .into_iter()
+            .map(|x| (x, p.eval(&x)))
+            .collect();
+
+        // Valid points.
+        assert!(vv.verify_batch(&points, &mut rng));
+        assert!(vv.verify_batch(&[], &mut rng));
+
+        // Invalid point.
+        points[2].1 += scalar(1);
+        for _ in 0..10 {
+            assert!(!vv.verify_batch(&points, &mut rng));
+        }
+
+        // Failing generator.
+        points[2].1 -= scalar(1);
+        let mut rng = FailingRng;
+        assert!(!vv.verify_batch(&points, &mut rng));
+    }
+
+    #[test]
+    fn test_verify_batch_with_challenge() {
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+        let mut points: Vec<_> = scalars(&[1, 2, 3, 4, 5])
+            .into_iter()
+            .map(|x| (x, p.eval(&x)))
+            .collect();
+        let c = scalar(7);
+
+        // Valid points.
+        assert!(vv.verify_batch_with_challenge(&points, &c));
+        assert!(vv.verify_batch_with_challenge(&[], &c));
+
+        // Invalid point.
+        points[2].1 += scalar(1);
+        assert!(!vv.verify_batch_with_challenge(&points, &c));
+    }
 }
@@ -0,0 +1,193 @@
+//! Zero-hole openings of verification matrices.
+
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+
+use crate::suites::FieldDigest;
+
+use super::VerificationMatrix;
+
+/// The domain separation tag used to digest the elements of verification
+/// matrices other than the hole.
+const ZERO_HOLE_REMAINDER_DST: &[u8] = b"oasis-core/secret-sharing: zero-hole remainder";
+
+/// The domain separation tag used to derive zero-hole commitments
+/// to verification matrices.
+const ZERO_HOLE_COMMITMENT_DST: &[u8] = b"oasis-core/secret-sharing: zero-hole commitment";
+
+/// Opening of the hole entry `M_{0,0}` of a verification matrix against
+/// its zero-hole commitment, see [`VerificationMatrix::zero_hole_commitment`].
+///
+/// The commitment is the hash of the hole entry and of the digest of all
+/// other elements, so the opening consists of just these two values,
+/// regardless of the dimensions of the matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroHoleProof<G: Group> {
+    /// The hole entry `M_{0,0}`.
+    hole: G,
+    /// The digest of the dimensions and of all other elements.
+    remainder: G::Scalar,
+}
+
+impl<G> ZeroHoleProof<G>
+where
+    G: Group,
+{
+    /// Returns the opened hole entry.
+    pub fn hole(&self) -> &G {
+        &self.hole
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Computes the zero-hole commitment to the verification matrix.
+    ///
+    /// The commitment binds the whole matrix, so a verifier that obtained
+    /// it from a trusted source can check that the matrix is a zero-hole
+    /// matrix from a [`ZeroHoleProof`] alone, see [`verify_zero_hole`].
+    pub fn zero_hole_commitment<H>(&self) -> Result<G::Scalar>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+    {
+        let remainder = self.zero_hole_remainder::<H>()?;
+        zero_hole_commitment::<G, H>(&self.m[0][0], &remainder)
+    }
+
+    /// Opens the hole entry `M_{0,0}` of the verification matrix against
+    /// its zero-hole commitment.
+    ///
+    /// The proof is produced for any matrix, but verifies only if
+    /// the hole entry is the identity element of the group.
+    pub fn prove_zero_hole<H>(&self) -> Result<ZeroHoleProof<G>>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+    {
+        let remainder = self.zero_hole_remainder::<H>()?;
+
+        Ok(ZeroHoleProof {
+            hole: self.m[0][0],
+            remainder,
+        })
+    }
+
+    /// Digests the dimensions and all elements of the verification matrix
+    /// other than the hole entry, in row-major order.
+    fn zero_hole_remainder<H>(&self) -> Result<G::Scalar>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+    {
+        let cap = Self::byte_size(self.rows, self.cols) - Self::element_byte_size();
+        let mut transcript = Vec::with_capacity(cap);
+        transcript.extend_from_slice(&[(self.rows - 1) as u8, (self.cols - 1) as u8]);
+        for mij in self.m.iter().flatten().skip(1) {
+            transcript.extend_from_slice(mij.to_bytes().as_ref());
+        }
+
+        H::hash_to_field(&transcript, ZERO_HOLE_REMAINDER_DST)
+    }
+}
+
+/// Verifies that the matrix bound by the given zero-hole commitment
+/// is a zero-hole matrix, i.e. that the proof opens the commitment
+/// and the opened hole entry is the identity element of the group.
+pub fn verify_zero_hole<G, H>(proof: &ZeroHoleProof<G>, commitment: &G::Scalar) -> bool
+where
+    G: Group + GroupEncoding,
+    H: FieldDigest<Output = G::Scalar>,
+{
+    if !bool::from(proof.hole.is_identity()) {
+        return false;
+    }
+
+    match zero_hole_commitment::<G, H>(&proof.hole, &proof.remainder) {
+        Ok(expected) => expected == *commitment,
+        Err(_) => false,
+    }
+}
+
+/// Computes the zero-hole commitment from the hole entry and the digest
+/// of the remaining elements.
+fn zero_hole_commitment<G, H>(hole: &G, remainder: &G::Scalar) -> Result<G::Scalar>
+where
+    G: Group + GroupEncoding,
+    H: FieldDigest<Output = G::Scalar>,
+{
+    let mut transcript = Vec::new();
+    transcript.extend_from_slice(hole.to_bytes().as_ref());
+    transcript.extend_from_slice(remainder.to_repr().as_ref());
+
+    H::hash_to_field(&transcript, ZERO_HOLE_COMMITMENT_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Group as _;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly,
+        suites::{self, p384},
+        vss,
+    };
+
+    use super::verify_zero_hole;
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_zero_hole_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.is_zero_hole());
+
+        let commitment = vm.zero_hole_commitment::<Suite>().unwrap();
+        let proof = vm.prove_zero_hole::<Suite>().unwrap();
+        assert!(bool::from(proof.hole().is_identity()));
+        assert!(verify_zero_hole::<Group, Suite>(&proof, &commitment));
+
+        // The commitment binds the rest of the matrix.
+        let mut other = vm.clone();
+        other.m[1][2] = Group::random(&mut rng);
+        let other_commitment = other.zero_hole_commitment::<Suite>().unwrap();
+        assert_ne!(other_commitment, commitment);
+        assert!(!verify_zero_hole::<Group, Suite>(&proof, &other_commitment));
+
+        // The commitment binds the dimensions.
+        let zh = VerificationMatrix::zero_hole((3, 4)).unwrap();
+        let proof = zh.prove_zero_hole::<Suite>().unwrap();
+        let commitment = zh.zero_hole_commitment::<Suite>().unwrap();
+        assert!(verify_zero_hole::<Group, Suite>(&proof, &commitment));
+        let zh = VerificationMatrix::zero_hole((4, 3)).unwrap();
+        let other_commitment = zh.zero_hole_commitment::<Suite>().unwrap();
+        assert!(!verify_zero_hole::<Group, Suite>(&proof, &other_commitment));
+    }
+
+    #[test]
+    fn test_non_zero_hole_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_zero_hole());
+
+        // Honest openings of non-zero-hole matrices are rejected.
+        let commitment = vm.zero_hole_commitment::<Suite>().unwrap();
+        let proof = vm.prove_zero_hole::<Suite>().unwrap();
+        assert!(!verify_zero_hole::<Group, Suite>(&proof, &commitment));
+
+        // Forged openings with the identity as the hole are rejected too.
+        let mut forged = proof;
+        forged.hole = Group::identity();
+        assert!(!verify_zero_hole::<Group, Suite>(&forged, &commitment));
+    }
+}
@@ -0,0 +1,65 @@
+//! Verifies that secret shares are zeroized when dropped.
+//!
+//! The test installs a global allocator that inspects freed memory, so it
+//! lives in its own binary to keep the unit tests on the system allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    ptr, slice,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering::SeqCst},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use secret_sharing::{
+    churp::{self, HandoffKind},
+    suites::{self, p384},
+};
+
+type Suite = p384::Sha3_384;
+type Group = <Suite as suites::Suite>::Group;
+type PrimeField = <Suite as suites::Suite>::PrimeField;
+type Dealer = churp::Dealer<Group>;
+
+/// Allocator which records whether the watched allocation was zeroized
+/// before it was freed.
+struct WatchingAllocator;
+
+static WATCHED: AtomicPtr<u8> = AtomicPtr::new(ptr::null_mut());
+static WATCHED_ZEROIZED: AtomicBool = AtomicBool::new(false);
+
+unsafe impl GlobalAlloc for WatchingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let watched = WATCHED.compare_exchange(ptr, ptr::null_mut(), SeqCst, SeqCst);
+        if watched.is_ok() {
+            // SAFETY: The memory is still allocated and valid for reads.
+            let bytes = slice::from_raw_parts(ptr, layout.size());
+            WATCHED_ZEROIZED.store(bytes.iter().all(|&b| b == 0), SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: WatchingAllocator = WatchingAllocator;
+
+#[test]
+fn test_zeroize_on_drop() {
+    let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+    let dealer = Dealer::new(2, &mut rng).unwrap();
+    let x = PrimeField::from_u64(1);
+    let share = dealer.make_share(x, HandoffKind::DealingPhase);
+    let coefficients = share.polynomial().coefficients();
+    assert!(coefficients.iter().any(|ai| !bool::from(ai.is_zero())));
+
+    // The zero element of the prime field is represented by zero bytes.
+    WATCHED.store(coefficients.as_ptr() as *mut u8, SeqCst);
+    drop(share);
+
+    assert!(WATCHED.load(SeqCst).is_null());
+    assert!(WATCHED_ZEROIZED.load(SeqCst));
+}